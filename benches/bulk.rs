@@ -0,0 +1,33 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use fastrand::Rng;
+use tomb::items::{BulkRoller, RngRoller, D6};
+use tomb::traits::Roll;
+
+fn bulk(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bulk");
+    let size = 100_000;
+    let mut buffer = vec![0; size];
+    group.throughput(Throughput::Elements(size as u64));
+
+    let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+    group.bench_with_input(BenchmarkId::new("RngRoller", size), &size, |b, _| {
+        b.iter(|| {
+            roller.roll_values_into(&D6::new(), &mut buffer);
+            black_box(&buffer);
+        })
+    });
+
+    let mut roller = BulkRoller::with_seed(7194422452970863838);
+    group.bench_with_input(BenchmarkId::new("BulkRoller", size), &size, |b, _| {
+        b.iter(|| {
+            roller.roll_values_into(&D6::new(), &mut buffer);
+            black_box(&buffer);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bulk);
+criterion_main!(benches);
@@ -0,0 +1,85 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use fastrand::Rng;
+use tomb::items::{NopRoller, RngRoller, SliceDie, D20, D6, D66};
+use tomb::traits::{Roll, RollMut, Rotate, RotateFast};
+
+fn rollers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rollers");
+    let nop = NopRoller;
+    group.bench_function("NopRoller/roll", |b| {
+        b.iter(|| black_box(nop.roll(&D20::new())));
+    });
+    let rng = RngRoller::from(Rng::with_seed(7194422452970863838));
+    group.bench_function("RngRoller/roll", |b| {
+        b.iter(|| black_box(rng.roll(&D20::new())));
+    });
+    group.bench_function("RngRoller/roll_mut", |b| {
+        let mut d20 = D20::new();
+        b.iter(|| {
+            rng.roll_mut(&mut d20);
+            black_box(&d20);
+        });
+    });
+    group.finish();
+}
+
+fn dice(c: &mut Criterion) {
+    const GRADES: [char; 5] = ['A', 'B', 'C', 'D', 'F'];
+
+    let mut group = c.benchmark_group("rotate");
+    group.bench_function("NumericDie", |b| {
+        b.iter(|| black_box(D6::new().rotate(black_box(1000))));
+    });
+    group.bench_function("SliceDie", |b| {
+        b.iter(|| black_box(SliceDie::new(&GRADES).rotate(black_box(1000))));
+    });
+    group.bench_function("CompositeDie", |b| {
+        b.iter(|| black_box(D66::new().rotate(black_box(1000))));
+    });
+    group.bench_function("RotateFast", |b| {
+        b.iter(|| black_box(D6::new().rotate_fast(black_box(1000))));
+    });
+    group.finish();
+}
+
+fn batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch");
+    for size in [100, 10_000] {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        group.bench_with_input(BenchmarkId::new("naive", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut values = Vec::new();
+                for _ in 0..size {
+                    values.push(roller.roll(&D6::new()).value());
+                }
+                black_box(values)
+            })
+        });
+
+        let mut buffer = vec![0; size];
+        group.bench_with_input(BenchmarkId::new("roll_values_into", size), &size, |b, _| {
+            b.iter(|| {
+                roller.roll_values_into(&D6::new(), &mut buffer);
+                black_box(&buffer);
+            })
+        });
+    }
+    group.finish();
+}
+
+fn pool(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pool");
+    let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+    group.bench_function("roll_n", |b| {
+        b.iter(|| black_box(roller.roll_n(&D6::new(), 4)));
+    });
+    group.bench_function("roll_array", |b| {
+        b.iter(|| black_box(roller.roll_array::<_, 4>(&D6::new())));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, rollers, dice, batch, pool);
+criterion_main!(benches);
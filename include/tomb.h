@@ -0,0 +1,136 @@
+/* Generated with cbindgen from src/ffi.rs (see cbindgen.toml); do not edit manually. */
+
+#ifndef TOMB_H
+#define TOMB_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * An opaque handle to a die with any number of sides.
+ */
+typedef struct TombDie TombDie;
+
+/**
+ * An opaque handle to the distribution of a dice expression.
+ */
+typedef struct TombDistribution TombDistribution;
+
+/**
+ * An opaque handle to a roller.
+ */
+typedef struct TombRoller TombRoller;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Creates a roller seeded from a random source.
+ */
+TombRoller *tomb_roller_new(void);
+
+/**
+ * Creates a roller with the given seed, which always produces the same results.
+ */
+TombRoller *tomb_roller_new_seeded(uint64_t seed);
+
+/**
+ * Releases a roller.
+ */
+void tomb_roller_free(TombRoller *roller);
+
+/**
+ * Rolls `count` dice with `sides` sides, and returns the sum, or `0` if the roller is null or
+ * `sides` is `0`.
+ */
+int64_t tomb_roll(const TombRoller *roller, uint32_t count, uint32_t sides);
+
+/**
+ * Rolls dice notation (i.e. `4d6kh3+2`), writing the total to `total`.
+ *
+ * Returns whether the notation was rolled, which is `false` (and `total` is unchanged) if any
+ * pointer is null, or the notation is not valid UTF-8 or valid notation.
+ */
+bool tomb_roll_notation(const TombRoller *roller, const char *notation, int64_t *total);
+
+/**
+ * Creates a die with the given number of sides, showing `1`, or null if `sides` is `0`.
+ */
+TombDie *tomb_die_new(uint32_t sides);
+
+/**
+ * Releases a die.
+ */
+void tomb_die_free(TombDie *die);
+
+/**
+ * Returns the number of sides of a die, or `0` if the die is null.
+ */
+uint32_t tomb_die_sides(const TombDie *die);
+
+/**
+ * Returns the value a die is showing, or `0` if the die is null.
+ */
+uint32_t tomb_die_value(const TombDie *die);
+
+/**
+ * Rolls a die in place, and returns the value it is now showing, or `0` if either is null.
+ */
+uint32_t tomb_die_roll(TombDie *die, const TombRoller *roller);
+
+/**
+ * Creates the distribution of `count` dice with `sides` sides, i.e. `3d6`, or null if `sides` is
+ * `0`, or there are more than `1000` dice or `10000` sides (the limits of dice notation).
+ */
+TombDistribution *tomb_distribution_dice(uint32_t count, uint32_t sides);
+
+/**
+ * Creates the distribution of two distributions added together, i.e. `1d20 + 1d4`, or null if
+ * either is null, or the sum would have more than `10000000` totals.
+ */
+TombDistribution *tomb_distribution_add(const TombDistribution *a, const TombDistribution *b);
+
+/**
+ * Creates the distribution of a distribution plus a constant, i.e. `1d20 + 5`, or null if the
+ * distribution is null.
+ */
+TombDistribution *tomb_distribution_add_constant(const TombDistribution *dist, int64_t constant);
+
+/**
+ * Releases a distribution.
+ */
+void tomb_distribution_free(TombDistribution *dist);
+
+/**
+ * Returns the lowest possible total, or `0` if the distribution is null.
+ */
+int64_t tomb_distribution_min(const TombDistribution *dist);
+
+/**
+ * Returns the highest possible total, or `0` if the distribution is null.
+ */
+int64_t tomb_distribution_max(const TombDistribution *dist);
+
+/**
+ * Returns the expected total, or `NaN` if the distribution is null.
+ */
+double tomb_distribution_mean(const TombDistribution *dist);
+
+/**
+ * Returns the probability of exactly the given total, or `NaN` if the distribution is null.
+ */
+double tomb_distribution_p(const TombDistribution *dist, int64_t total);
+
+/**
+ * Returns the probability of at least the given total, or `NaN` if the distribution is null.
+ */
+double tomb_distribution_at_least(const TombDistribution *dist, int64_t total);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* TOMB_H */
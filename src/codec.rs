@@ -0,0 +1,97 @@
+//! Contains a compact binary encoding of dice, pools, roll logs, and statistics.
+//!
+//! Any type that supports `serde` (with the `serde` feature) can be encoded, using [`bincode`],
+//! which is far smaller and faster than a text format such as JSON. For example, a D20 is encoded
+//! as a single byte.
+//!
+//! # Examples
+//!
+//! ```
+//! use tomb::codec;
+//! use tomb::items::{D20, D6};
+//!
+//! let pool = [D6::from(2), D6::from(5), D6::from(6)];
+//! let bytes = codec::to_bytes(&pool).unwrap();
+//! assert_eq!(bytes, [2, 5, 6]);
+//!
+//! let decoded: [D6; 3] = codec::from_bytes(&bytes).unwrap();
+//! assert_eq!(decoded, pool);
+//!
+//! // Invalid dice states are rejected.
+//! assert!(codec::from_bytes::<D20>(&[21]).is_err());
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// An error that occurred encoding or decoding.
+pub type Error = bincode::Error;
+
+/// Encodes a value into bytes.
+pub fn to_bytes<T>(value: &T) -> Result<Vec<u8>, Error>
+where
+    T: Serialize + ?Sized,
+{
+    bincode::serialize(value)
+}
+
+/// Decodes a value from bytes.
+pub fn from_bytes<'a, T>(bytes: &'a [u8]) -> Result<T, Error>
+where
+    T: Deserialize<'a>,
+{
+    bincode::deserialize(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+    use crate::items::{RngRoller, D20, D66};
+    use crate::mechanics::Check;
+    use crate::stats::{Accumulator, Distribution};
+    use crate::traits::{Attempts, Roll};
+
+    #[test]
+    fn codec_check() {
+        let check = Check::new(D20::from(12), -3);
+        let bytes = to_bytes(&check).unwrap();
+
+        assert_eq!(bytes.len(), 5);
+        assert_eq!(from_bytes::<Check<D20>>(&bytes).unwrap(), check);
+    }
+
+    #[test]
+    fn codec_composite_die() {
+        let die = D66::with_faces(3, 5);
+
+        assert_eq!(to_bytes(&die).unwrap(), [3, 5]);
+        assert!(from_bytes::<D66>(&[3, 7]).is_err());
+    }
+
+    #[test]
+    fn codec_roll_log() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let attempts = roller.roll_until(&D20::new(), 1000, |d| d.value() == 20);
+        let bytes = to_bytes(&attempts).unwrap();
+
+        // A length prefix, one byte per roll, and whether the predicate was satisfied.
+        assert_eq!(bytes.len(), 8 + attempts.len() + 1);
+        assert_eq!(from_bytes::<Attempts<D20>>(&bytes).unwrap(), attempts);
+    }
+
+    #[test]
+    fn codec_statistics() {
+        let dist = Distribution::dice(2, 6) + 1;
+        let stats: Accumulator = [3, 4, 5].into_iter().collect();
+
+        assert_eq!(
+            from_bytes::<Distribution>(&to_bytes(&dist).unwrap()).unwrap(),
+            dist
+        );
+        assert_eq!(
+            from_bytes::<Accumulator>(&to_bytes(&stats).unwrap()).unwrap(),
+            stats
+        );
+    }
+}
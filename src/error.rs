@@ -0,0 +1,103 @@
+use std::fmt::Display;
+
+/// An error returned by the non-panicking (fallible) constructors in this crate.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::D6;
+/// use tomb::Error;
+///
+/// assert_eq!(
+///     D6::try_from_value(7),
+///     Err(Error::OutOfRange {
+///         value: 7,
+///         min: 1,
+///         max: 6
+///     }),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// A value, i.e. the face or position of a die, was outside of `min..=max`.
+    OutOfRange {
+        /// The value provided.
+        value: usize,
+
+        /// The smallest value allowed.
+        min: usize,
+
+        /// The largest value allowed.
+        max: usize,
+    },
+
+    /// A value, i.e. the side of a die, was not found.
+    NotFound,
+
+    /// Dice notation, i.e. `4d6kh3+2`, could not be parsed.
+    InvalidNotation {
+        /// The byte offset of the first unexpected character, or the length if incomplete.
+        position: usize,
+    },
+
+    /// A value, i.e. a random table, refers back to itself, so it could never be resolved.
+    Cycle,
+
+    /// A probability, i.e. the bias of a coin, was not within `0.0..=1.0`.
+    InvalidProbability,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutOfRange { value, min, max } => {
+                write!(f, "{value} is out of range {min}..={max}")
+            }
+            Self::NotFound => f.write_str("value not found"),
+            Self::InvalidNotation { position } => {
+                write!(f, "invalid dice notation at position {position}")
+            }
+            Self::Cycle => f.write_str("cyclic reference"),
+            Self::InvalidProbability => f.write_str("probability is out of range 0.0..=1.0"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_display() {
+        let error = Error::OutOfRange {
+            value: 0,
+            min: 1,
+            max: 20,
+        };
+
+        assert_eq!(error.to_string(), "0 is out of range 1..=20");
+    }
+
+    #[test]
+    fn error_display_notation() {
+        let error = Error::InvalidNotation { position: 3 };
+
+        assert_eq!(error.to_string(), "invalid dice notation at position 3");
+    }
+
+    #[test]
+    fn error_display_cycle() {
+        assert_eq!(Error::Cycle.to_string(), "cyclic reference");
+    }
+
+    #[test]
+    fn error_display_probability() {
+        assert_eq!(
+            Error::InvalidProbability.to_string(),
+            "probability is out of range 0.0..=1.0"
+        );
+    }
+}
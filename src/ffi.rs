@@ -0,0 +1,483 @@
+//! Contains a C ABI, so engines written in other languages can use `tomb` as a dice backend.
+//!
+//! Every object is an opaque handle that is created by a `tomb_*_new` function, and must be
+//! released by the matching `tomb_*_free` function. A C header is provided in `include/tomb.h`.
+//!
+//! No function unwinds (panics) across the C ABI, and no function dereferences a null handle.
+//! Instead, invalid input (i.e. a null handle, or a die with no sides) returns a sentinel that is
+//! documented by each function: null for handles, `0` for integers, and `NaN` for probabilities.
+//! Allocations are bounded, so input can't abort the process by exhausting memory either.
+//!
+//! # Building and linking
+//!
+//! Building with the `ffi` feature produces a shared library (`libtomb.so`, `libtomb.dylib`, or
+//! `tomb.dll`) and a static library (`libtomb.a` or `tomb.lib`) in `target/release`:
+//!
+//! ```sh
+//! cargo build --release --features ffi
+//!
+//! # Link dynamically (the library must be on the loader path at runtime, i.e. LD_LIBRARY_PATH).
+//! cc main.c -I include -L target/release -ltomb -o main
+//!
+//! # Or link statically, along with the system libraries the Rust standard library uses.
+//! cc main.c -I include target/release/libtomb.a -lpthread -ldl -lm -o main
+//! ```
+//!
+//! From C#, load the shared library with `[DllImport("tomb")]`, declaring handles as `IntPtr`.
+//!
+//! # Examples
+//!
+//! ```c
+//! #include "tomb.h"
+//!
+//! TombRoller *roller = tomb_roller_new_seeded(42);
+//! TombDie *d20 = tomb_die_new(20);
+//! uint32_t value = tomb_die_roll(d20, roller);
+//!
+//! int64_t total;
+//! if (tomb_roll_notation(roller, "4d6kh3+2", &total)) {
+//!     /* ... */
+//! }
+//!
+//! TombDistribution *dist = tomb_distribution_dice(3, 6);
+//! double mean = tomb_distribution_mean(dist);
+//!
+//! tomb_distribution_free(dist);
+//! tomb_die_free(d20);
+//! tomb_roller_free(roller);
+//! ```
+
+use std::ffi::{c_char, CStr};
+use std::panic::{self, AssertUnwindSafe};
+
+use fastrand::Rng;
+
+use crate::items::RngRoller;
+use crate::mechanics::Notation;
+use crate::stats::Distribution;
+use crate::traits::SideSelector;
+
+/// The most totals a distribution can have, at least as many as the largest notation can roll.
+const MAX_TOTALS: u64 = (Notation::MAX_COUNT * Notation::MAX_SIDES) as u64;
+
+/// An opaque handle to a roller.
+pub struct TombRoller(RngRoller);
+
+/// An opaque handle to a die with any number of sides.
+pub struct TombDie {
+    sides: u32,
+    value: u32,
+}
+
+/// An opaque handle to the distribution of a dice expression.
+pub struct TombDistribution(Distribution);
+
+/// Runs `f`, returning `default` if it panics, instead of unwinding across the C ABI.
+fn guard<T>(default: T, f: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(default)
+}
+
+/// Moves a distribution to the heap, returning a handle to it.
+fn distribution(dist: Distribution) -> *mut TombDistribution {
+    Box::into_raw(Box::new(TombDistribution(dist)))
+}
+
+/// Creates a roller seeded from a random source.
+#[no_mangle]
+pub extern "C" fn tomb_roller_new() -> *mut TombRoller {
+    Box::into_raw(Box::new(TombRoller(RngRoller::new())))
+}
+
+/// Creates a roller with the given seed, which always produces the same results.
+#[no_mangle]
+pub extern "C" fn tomb_roller_new_seeded(seed: u64) -> *mut TombRoller {
+    Box::into_raw(Box::new(TombRoller(RngRoller::from(Rng::with_seed(seed)))))
+}
+
+/// Releases a roller.
+///
+/// # Safety
+///
+/// The roller must have been created by `tomb_roller_new*` and not already released, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_roller_free(roller: *mut TombRoller) {
+    if !roller.is_null() {
+        drop(Box::from_raw(roller));
+    }
+}
+
+/// Rolls `count` dice with `sides` sides, and returns the sum, or `0` if the roller is null or
+/// `sides` is `0`.
+///
+/// # Safety
+///
+/// The roller must be a valid handle, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_roll(roller: *const TombRoller, count: u32, sides: u32) -> i64 {
+    let Some(roller) = roller.as_ref() else {
+        return 0;
+    };
+    if sides == 0 {
+        return 0;
+    }
+    guard(0, || {
+        (0..count)
+            .map(|_| roller.0.select(sides as usize) as i64 + 1)
+            .sum()
+    })
+}
+
+/// Rolls dice notation (i.e. `4d6kh3+2`), writing the total to `total`.
+///
+/// Returns whether the notation was rolled, which is `false` (and `total` is unchanged) if any
+/// pointer is null, or the notation is not valid UTF-8 or valid notation.
+///
+/// # Safety
+///
+/// The roller must be a valid handle, `notation` must be a null-terminated string, and `total`
+/// must be valid for writes, or each may be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_roll_notation(
+    roller: *const TombRoller,
+    notation: *const c_char,
+    total: *mut i64,
+) -> bool {
+    let (Some(roller), false, false) = (roller.as_ref(), notation.is_null(), total.is_null())
+    else {
+        return false;
+    };
+    let Ok(notation) = CStr::from_ptr(notation).to_str() else {
+        return false;
+    };
+    let Ok(notation) = Notation::parse(notation) else {
+        return false;
+    };
+    match guard(None, || Some(notation.roll(&roller.0).total())) {
+        Some(result) => {
+            *total = result;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Creates a die with the given number of sides, showing `1`, or null if `sides` is `0`.
+#[no_mangle]
+pub extern "C" fn tomb_die_new(sides: u32) -> *mut TombDie {
+    if sides == 0 {
+        return std::ptr::null_mut();
+    }
+    Box::into_raw(Box::new(TombDie { sides, value: 1 }))
+}
+
+/// Releases a die.
+///
+/// # Safety
+///
+/// The die must have been created by `tomb_die_new` and not already released, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_die_free(die: *mut TombDie) {
+    if !die.is_null() {
+        drop(Box::from_raw(die));
+    }
+}
+
+/// Returns the number of sides of a die, or `0` if the die is null.
+///
+/// # Safety
+///
+/// The die must be a valid handle, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_die_sides(die: *const TombDie) -> u32 {
+    die.as_ref().map_or(0, |die| die.sides)
+}
+
+/// Returns the value a die is showing, or `0` if the die is null.
+///
+/// # Safety
+///
+/// The die must be a valid handle, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_die_value(die: *const TombDie) -> u32 {
+    die.as_ref().map_or(0, |die| die.value)
+}
+
+/// Rolls a die in place, and returns the value it is now showing, or `0` if either is null.
+///
+/// # Safety
+///
+/// The die and roller must be valid handles, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_die_roll(die: *mut TombDie, roller: *const TombRoller) -> u32 {
+    let (Some(die), Some(roller)) = (die.as_mut(), roller.as_ref()) else {
+        return 0;
+    };
+    guard(0, || {
+        die.value = roller.0.select(die.sides as usize) as u32 + 1;
+        die.value
+    })
+}
+
+/// Creates the distribution of `count` dice with `sides` sides, i.e. `3d6`, or null if `sides` is
+/// `0`, or there are more than `1000` dice or `10000` sides (the limits of dice notation).
+#[no_mangle]
+pub extern "C" fn tomb_distribution_dice(count: u32, sides: u32) -> *mut TombDistribution {
+    if sides == 0 || count as usize > Notation::MAX_COUNT || sides as usize > Notation::MAX_SIDES {
+        return std::ptr::null_mut();
+    }
+    guard(std::ptr::null_mut(), || {
+        distribution(Distribution::dice(count as usize, sides as usize))
+    })
+}
+
+/// Creates the distribution of two distributions added together, i.e. `1d20 + 1d4`, or null if
+/// either is null, or the sum would have more than `10000000` totals.
+///
+/// # Safety
+///
+/// Both distributions must be valid handles, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_distribution_add(
+    a: *const TombDistribution,
+    b: *const TombDistribution,
+) -> *mut TombDistribution {
+    let (Some(a), Some(b)) = (a.as_ref(), b.as_ref()) else {
+        return std::ptr::null_mut();
+    };
+    let totals = |dist: &Distribution| dist.max().abs_diff(dist.min());
+    if totals(&a.0).saturating_add(totals(&b.0)) >= MAX_TOTALS {
+        return std::ptr::null_mut();
+    }
+    guard(std::ptr::null_mut(), || {
+        distribution(a.0.clone() + b.0.clone())
+    })
+}
+
+/// Creates the distribution of a distribution plus a constant, i.e. `1d20 + 5`, or null if the
+/// distribution is null.
+///
+/// # Safety
+///
+/// The distribution must be a valid handle, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_distribution_add_constant(
+    dist: *const TombDistribution,
+    constant: i64,
+) -> *mut TombDistribution {
+    let Some(dist) = dist.as_ref() else {
+        return std::ptr::null_mut();
+    };
+    guard(std::ptr::null_mut(), || {
+        distribution(dist.0.clone() + constant)
+    })
+}
+
+/// Releases a distribution.
+///
+/// # Safety
+///
+/// The distribution must have been created by `tomb_distribution_*` and not already released,
+/// or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_distribution_free(dist: *mut TombDistribution) {
+    if !dist.is_null() {
+        drop(Box::from_raw(dist));
+    }
+}
+
+/// Returns the lowest possible total, or `0` if the distribution is null.
+///
+/// # Safety
+///
+/// The distribution must be a valid handle, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_distribution_min(dist: *const TombDistribution) -> i64 {
+    dist.as_ref().map_or(0, |dist| dist.0.min())
+}
+
+/// Returns the highest possible total, or `0` if the distribution is null.
+///
+/// # Safety
+///
+/// The distribution must be a valid handle, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_distribution_max(dist: *const TombDistribution) -> i64 {
+    dist.as_ref().map_or(0, |dist| dist.0.max())
+}
+
+/// Returns the expected total, or `NaN` if the distribution is null.
+///
+/// # Safety
+///
+/// The distribution must be a valid handle, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_distribution_mean(dist: *const TombDistribution) -> f64 {
+    dist.as_ref().map_or(f64::NAN, |dist| dist.0.mean())
+}
+
+/// Returns the probability of exactly the given total, or `NaN` if the distribution is null.
+///
+/// # Safety
+///
+/// The distribution must be a valid handle, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_distribution_p(dist: *const TombDistribution, total: i64) -> f64 {
+    dist.as_ref().map_or(f64::NAN, |dist| dist.0.p(total))
+}
+
+/// Returns the probability of at least the given total, or `NaN` if the distribution is null.
+///
+/// # Safety
+///
+/// The distribution must be a valid handle, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn tomb_distribution_at_least(
+    dist: *const TombDistribution,
+    total: i64,
+) -> f64 {
+    dist.as_ref()
+        .map_or(f64::NAN, |dist| dist.0.at_least(total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ffi_roller_and_die() {
+        unsafe {
+            let roller = tomb_roller_new_seeded(7194422452970863838);
+            let die = tomb_die_new(20);
+
+            assert_eq!(tomb_die_sides(die), 20);
+            assert_eq!(tomb_die_value(die), 1);
+
+            let value = tomb_die_roll(die, roller);
+            assert!((1..=20).contains(&value));
+            assert_eq!(tomb_die_value(die), value);
+
+            let sum = tomb_roll(roller, 3, 6);
+            assert!((3..=18).contains(&sum));
+            assert_eq!(tomb_roll(roller, 3, 0), 0);
+
+            tomb_die_free(die);
+            tomb_roller_free(roller);
+        }
+    }
+
+    #[test]
+    fn ffi_die_no_sides() {
+        assert!(tomb_die_new(0).is_null());
+        unsafe {
+            tomb_die_free(std::ptr::null_mut());
+            tomb_roller_free(std::ptr::null_mut());
+            tomb_distribution_free(std::ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn ffi_null_handles() {
+        use std::ptr::{null, null_mut};
+
+        unsafe {
+            assert_eq!(tomb_roll(null(), 3, 6), 0);
+            assert_eq!(tomb_die_sides(null()), 0);
+            assert_eq!(tomb_die_value(null()), 0);
+            assert_eq!(tomb_die_roll(null_mut(), null()), 0);
+            assert!(tomb_distribution_add(null(), null()).is_null());
+            assert!(tomb_distribution_add_constant(null(), 1).is_null());
+            assert_eq!(tomb_distribution_min(null()), 0);
+            assert_eq!(tomb_distribution_max(null()), 0);
+            assert!(tomb_distribution_mean(null()).is_nan());
+            assert!(tomb_distribution_p(null(), 1).is_nan());
+            assert!(tomb_distribution_at_least(null(), 1).is_nan());
+        }
+    }
+
+    #[test]
+    fn ffi_distribution_no_sides() {
+        assert!(tomb_distribution_dice(3, 0).is_null());
+    }
+
+    #[test]
+    fn ffi_distribution_too_large() {
+        assert!(tomb_distribution_dice(u32::MAX, u32::MAX).is_null());
+        assert!(tomb_distribution_dice(1001, 6).is_null());
+        assert!(tomb_distribution_dice(1, 10_001).is_null());
+
+        let half = distribution(Distribution::die(MAX_TOTALS as usize / 2 + 1));
+        let one = tomb_distribution_dice(1, 1);
+        unsafe {
+            assert!(tomb_distribution_add(half, half).is_null());
+
+            let sum = tomb_distribution_add(half, one);
+            assert_eq!(tomb_distribution_max(sum), MAX_TOTALS as i64 / 2 + 2);
+
+            for dist in [half, one, sum] {
+                tomb_distribution_free(dist);
+            }
+        }
+    }
+
+    #[test]
+    fn ffi_guard_catches_panics() {
+        assert_eq!(guard(0, || 1), 1);
+        assert_eq!(guard(0, || panic!("unwinding")), 0);
+    }
+
+    #[test]
+    fn ffi_roll_notation() {
+        unsafe {
+            let roller = tomb_roller_new_seeded(7194422452970863838);
+            let mut total = -1;
+
+            assert!(tomb_roll_notation(roller, c"1d1+2".as_ptr(), &mut total));
+            assert_eq!(total, 3);
+
+            assert!(!tomb_roll_notation(roller, c"1d".as_ptr(), &mut total));
+            assert!(!tomb_roll_notation(roller, std::ptr::null(), &mut total));
+            assert!(!tomb_roll_notation(
+                std::ptr::null(),
+                c"1d6".as_ptr(),
+                &mut total
+            ));
+            assert!(!tomb_roll_notation(
+                roller,
+                c"1d6".as_ptr(),
+                std::ptr::null_mut()
+            ));
+            assert_eq!(total, 3);
+
+            tomb_roller_free(roller);
+        }
+    }
+
+    #[test]
+    fn ffi_roller_unseeded() {
+        unsafe {
+            let roller = tomb_roller_new();
+            assert!((1..=4).contains(&tomb_roll(roller, 1, 4)));
+            tomb_roller_free(roller);
+        }
+    }
+
+    #[test]
+    fn ffi_distribution() {
+        unsafe {
+            let d20 = tomb_distribution_dice(1, 20);
+            let d4 = tomb_distribution_dice(1, 4);
+            let sum = tomb_distribution_add(d20, d4);
+            let plus = tomb_distribution_add_constant(sum, 5);
+
+            assert_eq!(tomb_distribution_min(plus), 7);
+            assert_eq!(tomb_distribution_max(plus), 29);
+            assert!((tomb_distribution_mean(plus) - 18.0).abs() < 1e-12);
+            assert!((tomb_distribution_p(d20, 20) - 0.05).abs() < 1e-12);
+            assert!((tomb_distribution_at_least(d20, 16) - 0.25).abs() < 1e-12);
+
+            for dist in [d20, d4, sum, plus] {
+                tomb_distribution_free(dist);
+            }
+        }
+    }
+}
@@ -0,0 +1,532 @@
+//! Contains geometry metadata for the polyhedra that make up a standard set of dice.
+//!
+//! Each [`Solid`] knows its face, vertex, and edge counts, and which faces share an edge (and in
+//! which order around each face), using a standard numbering where faces are numbered from `1`
+//! and (except on a tetrahedron) opposite faces add up to one more than the number of faces, so
+//! renderers and physics layers can look the layout up instead of hardcoding it.
+//!
+//! # Examples
+//!
+//! ```
+//! use tomb::geometry::Solid;
+//! use tomb::items::D20;
+//!
+//! let solid = Solid::of::<D20>().unwrap();
+//! assert_eq!(solid.name(), "icosahedron");
+//! assert_eq!((solid.faces(), solid.vertices(), solid.edges()), (20, 12, 30));
+//! assert_eq!(solid.adjacent(1), &[2, 5, 6]);
+//! assert_eq!(solid.opposite(1), Some(20));
+//! ```
+//!
+//! The layout is also used by [`tumble`], to animate a roll with faces that could plausibly follow
+//! one another.
+
+use crate::traits::{Polyhedral, Positional, SideSelector};
+
+/// A convex polyhedron, and a standard numbering of its faces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Solid {
+    name: &'static str,
+    vertices: usize,
+    edges: usize,
+    opposites: bool,
+    adjacency: &'static [&'static [usize]],
+    around: &'static [&'static [usize]],
+}
+
+impl Solid {
+    /// A tetrahedron, the shape of a D4, where every face is adjacent to every other face.
+    pub const TETRAHEDRON: Self = Self {
+        name: "tetrahedron",
+        vertices: 4,
+        edges: 6,
+        opposites: false,
+        adjacency: &[&[2, 3, 4], &[1, 3, 4], &[1, 2, 4], &[1, 2, 3]],
+        around: &[&[2, 4, 3], &[1, 3, 4], &[1, 4, 2], &[1, 2, 3]],
+    };
+
+    /// A cube, the shape of a D6.
+    pub const CUBE: Self = Self {
+        name: "cube",
+        vertices: 8,
+        edges: 12,
+        opposites: true,
+        adjacency: &[
+            &[2, 3, 4, 5],
+            &[1, 3, 4, 6],
+            &[1, 2, 5, 6],
+            &[1, 2, 5, 6],
+            &[1, 3, 4, 6],
+            &[2, 3, 4, 5],
+        ],
+        around: &[
+            &[2, 4, 5, 3],
+            &[1, 3, 6, 4],
+            &[1, 5, 6, 2],
+            &[1, 2, 6, 5],
+            &[1, 4, 6, 3],
+            &[2, 3, 5, 4],
+        ],
+    };
+
+    /// An octahedron, the shape of a D8.
+    pub const OCTAHEDRON: Self = Self {
+        name: "octahedron",
+        vertices: 6,
+        edges: 12,
+        opposites: true,
+        adjacency: &[
+            &[5, 6, 7],
+            &[5, 6, 8],
+            &[5, 7, 8],
+            &[6, 7, 8],
+            &[1, 2, 3],
+            &[1, 2, 4],
+            &[1, 3, 4],
+            &[2, 3, 4],
+        ],
+        around: &[
+            &[5, 7, 6],
+            &[5, 6, 8],
+            &[5, 8, 7],
+            &[6, 7, 8],
+            &[1, 2, 3],
+            &[1, 4, 2],
+            &[1, 3, 4],
+            &[2, 4, 3],
+        ],
+    };
+
+    /// A pentagonal trapezohedron, the shape of a D10.
+    pub const PENTAGONAL_TRAPEZOHEDRON: Self = Self {
+        name: "pentagonal trapezohedron",
+        vertices: 12,
+        edges: 20,
+        opposites: true,
+        adjacency: &[
+            &[2, 5, 7, 8],
+            &[1, 3, 6, 7],
+            &[2, 4, 6, 10],
+            &[3, 5, 9, 10],
+            &[1, 4, 8, 9],
+            &[2, 3, 7, 10],
+            &[1, 2, 6, 8],
+            &[1, 5, 7, 9],
+            &[4, 5, 8, 10],
+            &[3, 4, 6, 9],
+        ],
+        around: &[
+            &[2, 7, 8, 5],
+            &[1, 3, 6, 7],
+            &[2, 4, 10, 6],
+            &[3, 5, 9, 10],
+            &[1, 8, 9, 4],
+            &[2, 3, 10, 7],
+            &[1, 2, 6, 8],
+            &[1, 7, 9, 5],
+            &[4, 5, 8, 10],
+            &[3, 4, 9, 6],
+        ],
+    };
+
+    /// A dodecahedron, the shape of a D12.
+    pub const DODECAHEDRON: Self = Self {
+        name: "dodecahedron",
+        vertices: 20,
+        edges: 30,
+        opposites: true,
+        adjacency: &[
+            &[2, 3, 4, 5, 6],
+            &[1, 3, 6, 8, 9],
+            &[1, 2, 4, 7, 8],
+            &[1, 3, 5, 7, 11],
+            &[1, 4, 6, 10, 11],
+            &[1, 2, 5, 9, 10],
+            &[3, 4, 8, 11, 12],
+            &[2, 3, 7, 9, 12],
+            &[2, 6, 8, 10, 12],
+            &[5, 6, 9, 11, 12],
+            &[4, 5, 7, 10, 12],
+            &[7, 8, 9, 10, 11],
+        ],
+        around: &[
+            &[2, 6, 5, 4, 3],
+            &[1, 3, 8, 9, 6],
+            &[1, 4, 7, 8, 2],
+            &[1, 5, 11, 7, 3],
+            &[1, 6, 10, 11, 4],
+            &[1, 2, 9, 10, 5],
+            &[3, 4, 11, 12, 8],
+            &[2, 3, 7, 12, 9],
+            &[2, 8, 12, 10, 6],
+            &[5, 6, 9, 12, 11],
+            &[4, 5, 10, 12, 7],
+            &[7, 11, 10, 9, 8],
+        ],
+    };
+
+    /// An icosahedron, the shape of a D20.
+    pub const ICOSAHEDRON: Self = Self {
+        name: "icosahedron",
+        vertices: 12,
+        edges: 30,
+        opposites: true,
+        adjacency: &[
+            &[2, 5, 6],
+            &[1, 3, 7],
+            &[2, 4, 8],
+            &[3, 5, 9],
+            &[1, 4, 10],
+            &[1, 12, 13],
+            &[2, 11, 12],
+            &[3, 11, 15],
+            &[4, 14, 15],
+            &[5, 13, 14],
+            &[7, 8, 16],
+            &[6, 7, 17],
+            &[6, 10, 18],
+            &[9, 10, 19],
+            &[8, 9, 20],
+            &[11, 17, 20],
+            &[12, 16, 18],
+            &[13, 17, 19],
+            &[14, 18, 20],
+            &[15, 16, 19],
+        ],
+        around: &[
+            &[2, 6, 5],
+            &[1, 3, 7],
+            &[2, 4, 8],
+            &[3, 5, 9],
+            &[1, 10, 4],
+            &[1, 12, 13],
+            &[2, 11, 12],
+            &[3, 15, 11],
+            &[4, 14, 15],
+            &[5, 13, 14],
+            &[7, 8, 16],
+            &[6, 7, 17],
+            &[6, 18, 10],
+            &[9, 10, 19],
+            &[8, 9, 20],
+            &[11, 20, 17],
+            &[12, 16, 18],
+            &[13, 17, 19],
+            &[14, 18, 20],
+            &[15, 19, 16],
+        ],
+    };
+
+    /// Every known solid, ordered by number of faces.
+    pub const ALL: [Self; 6] = [
+        Self::TETRAHEDRON,
+        Self::CUBE,
+        Self::OCTAHEDRON,
+        Self::PENTAGONAL_TRAPEZOHEDRON,
+        Self::DODECAHEDRON,
+        Self::ICOSAHEDRON,
+    ];
+
+    /// Returns the solid with the given number of faces, if one is known.
+    pub fn with_faces(faces: usize) -> Option<Self> {
+        Self::ALL.into_iter().find(|solid| solid.faces() == faces)
+    }
+
+    /// Returns the solid that is the shape of the given kind of die, if one is known.
+    pub fn of<D: Polyhedral>() -> Option<Self> {
+        Self::with_faces(D::sides())
+    }
+
+    /// Returns the name of the solid, in lowercase.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the number of faces.
+    pub const fn faces(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Returns the number of vertices.
+    pub const fn vertices(&self) -> usize {
+        self.vertices
+    }
+
+    /// Returns the number of edges.
+    pub const fn edges(&self) -> usize {
+        self.edges
+    }
+
+    /// Returns the number of edges around each face.
+    pub const fn edges_per_face(&self) -> usize {
+        self.adjacency[0].len()
+    }
+
+    /// Returns the faces that share an edge with the given face, in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// If the face is not within `1..=self.faces()`.
+    pub fn adjacent(&self, face: usize) -> &'static [usize] {
+        assert!((1..=self.faces()).contains(&face));
+        self.adjacency[face - 1]
+    }
+
+    /// Returns the faces that share an edge with the given face, clockwise as seen from outside
+    /// the solid, starting from the lowest.
+    ///
+    /// Every solid is wound the same way as a cube where faces `1`, `2` and `3` meet
+    /// counterclockwise around a corner, so a D6 has `[2, 4, 5, 3]` around face `1`.
+    ///
+    /// # Panics
+    ///
+    /// If the face is not within `1..=self.faces()`.
+    pub fn around(&self, face: usize) -> &'static [usize] {
+        assert!((1..=self.faces()).contains(&face));
+        self.around[face - 1]
+    }
+
+    /// Returns whether the two faces share an edge.
+    ///
+    /// # Panics
+    ///
+    /// If either face is not within `1..=self.faces()`.
+    pub fn is_adjacent(&self, a: usize, b: usize) -> bool {
+        assert!((1..=self.faces()).contains(&b));
+        self.adjacent(a).contains(&b)
+    }
+
+    /// Returns the face on the opposite side of the given face, or `None` on a tetrahedron.
+    ///
+    /// # Panics
+    ///
+    /// If the face is not within `1..=self.faces()`.
+    pub fn opposite(&self, face: usize) -> Option<usize> {
+        assert!((1..=self.faces()).contains(&face));
+        self.opposites.then(|| self.faces() + 1 - face)
+    }
+}
+
+/// Returns a plausible sequence of faces for a die to tumble through, ending on a known result.
+///
+/// Intended for animating a roll, where the result is decided upfront (i.e. by a
+/// [`crate::traits::Roll`]) and only presented by the animation. Each face shares an edge with the face before it (starting
+/// from `from`) using the layout of the die's [`Solid`], and the selector picks between the faces
+/// that can still end on `to` in the remaining number of tumbles. If `from` can't reach `to` in
+/// exactly `tumbles`, the first tumble lands on any face that can, as if the die was thrown.
+///
+/// Dice without a known solid (i.e. a D3) may tumble from any face to any other face.
+///
+/// Every face is a clone of `to` showing a different position, and the last face is always `to`,
+/// unless `tumbles` is `0`, in which case no faces are returned.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::geometry::{tumble, Solid};
+/// use tomb::items::{RngRoller, D6};
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let faces = tumble(&D6::from(1), &D6::from(6), 4, &roller);
+/// let faces: Vec<_> = faces.iter().map(|d| d.value() as usize).collect();
+///
+/// assert_eq!(faces.last(), Some(&6));
+/// assert!(Solid::CUBE.is_adjacent(1, faces[0]));
+/// assert!(faces.windows(2).all(|w| Solid::CUBE.is_adjacent(w[0], w[1])));
+/// ```
+pub fn tumble<D, S>(from: &D, to: &D, tumbles: usize, selector: &S) -> Vec<D>
+where
+    D: Positional + Clone,
+    S: SideSelector,
+{
+    let sides = D::sides();
+    let neighbors = |position| neighbors(sides, position);
+
+    // Whether each position can end on `to` in exactly as many tumbles as the index.
+    let mut reaches = vec![vec![false; sides]];
+    reaches[0][to.position()] = true;
+    for remaining in 1..tumbles {
+        let previous = &reaches[remaining - 1];
+        let next = (0..sides)
+            .map(|p| neighbors(p).into_iter().any(|n| previous[n]))
+            .collect();
+        reaches.push(next);
+    }
+
+    let mut position = from.position();
+    let mut faces = Vec::with_capacity(tumbles);
+    for reach in reaches.iter().rev().take(tumbles) {
+        let mut options: Vec<usize> = neighbors(position)
+            .into_iter()
+            .filter(|&n| reach[n])
+            .collect();
+        if options.is_empty() {
+            options = (0..sides).filter(|&p| reach[p]).collect();
+        }
+        position = options[selector.select(options.len())];
+        let mut face = to.clone();
+        face.set_position(position);
+        faces.push(face);
+    }
+    faces
+}
+
+/// Returns the positions that share an edge with the given position, on a die with that many sides.
+///
+/// Dice without a known solid are treated as if every face shares an edge with every other face.
+pub(crate) fn neighbors(sides: usize, position: usize) -> Vec<usize> {
+    match Solid::with_faces(sides) {
+        Some(solid) => solid.adjacent(position + 1).iter().map(|f| f - 1).collect(),
+        None if sides == 1 => vec![0],
+        None => (0..sides).filter(|&p| p != position).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::items::{RngRoller, D12, D20, D3, D6};
+    use fastrand::Rng;
+
+    #[test]
+    fn solid_of() {
+        assert_eq!(Solid::of::<D6>(), Some(Solid::CUBE));
+        assert_eq!(Solid::of::<D12>(), Some(Solid::DODECAHEDRON));
+        assert_eq!(Solid::of::<D3>(), None);
+    }
+
+    #[test]
+    fn solid_euler_characteristic() {
+        for solid in Solid::ALL {
+            assert_eq!(
+                solid.vertices() + solid.faces(),
+                solid.edges() + 2,
+                "{}",
+                solid.name()
+            );
+            assert_eq!(
+                solid.faces() * solid.edges_per_face(),
+                solid.edges() * 2,
+                "{}",
+                solid.name()
+            );
+        }
+    }
+
+    #[test]
+    fn solid_adjacency_is_symmetric() {
+        for solid in Solid::ALL {
+            for a in 1..=solid.faces() {
+                assert_eq!(solid.adjacent(a).len(), solid.edges_per_face());
+                for &b in solid.adjacent(a) {
+                    assert!(solid.is_adjacent(b, a), "{}: {a} {b}", solid.name());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solid_opposites_are_not_adjacent() {
+        for solid in Solid::ALL {
+            for face in 1..=solid.faces() {
+                if let Some(opposite) = solid.opposite(face) {
+                    assert!(!solid.is_adjacent(face, opposite), "{}", solid.name());
+
+                    // Neighbors of opposite faces are opposite each other.
+                    let mut mirrored: Vec<_> = solid
+                        .adjacent(face)
+                        .iter()
+                        .map(|&f| solid.opposite(f).unwrap())
+                        .collect();
+                    mirrored.sort_unstable();
+                    assert_eq!(mirrored, solid.adjacent(opposite), "{}", solid.name());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solid_around_is_adjacent() {
+        for solid in Solid::ALL {
+            for face in 1..=solid.faces() {
+                let mut around = solid.around(face).to_vec();
+                assert_eq!(around[0], solid.adjacent(face)[0], "{}", solid.name());
+                around.sort_unstable();
+                assert_eq!(around, solid.adjacent(face), "{}", solid.name());
+            }
+        }
+    }
+
+    #[test]
+    fn solid_around_is_consistently_wound() {
+        for solid in Solid::ALL {
+            // Walking from face to face, always turning to the next face clockwise, circles a
+            // vertex, so a consistent winding visits every vertex exactly once.
+            let mut visited = HashSet::new();
+            let mut corners = 0;
+            for a in 1..=solid.faces() {
+                for &b in solid.around(a) {
+                    if visited.contains(&(a, b)) {
+                        continue;
+                    }
+                    corners += 1;
+                    let (mut from, mut to) = (a, b);
+                    while visited.insert((from, to)) {
+                        let around = solid.around(to);
+                        let index = around.iter().position(|&f| f == from).unwrap();
+                        (from, to) = (to, around[(index + 1) % around.len()]);
+                    }
+                }
+            }
+            assert_eq!(corners, solid.vertices(), "{}", solid.name());
+        }
+    }
+
+    #[test]
+    fn solid_tetrahedron_has_no_opposites() {
+        assert_eq!(Solid::TETRAHEDRON.opposite(1), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn solid_adjacent_out_of_range() {
+        Solid::CUBE.adjacent(0);
+    }
+
+    #[test]
+    fn tumble_ends_on_result() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        for tumbles in 1..10 {
+            let faces = tumble(&D20::from(1), &D20::from(20), tumbles, &roller);
+
+            assert_eq!(faces.len(), tumbles);
+            assert_eq!(faces.last(), Some(&D20::from(20)));
+            for pair in faces.windows(2) {
+                assert!(Solid::ICOSAHEDRON
+                    .is_adjacent(pair[0].value() as usize, pair[1].value() as usize));
+            }
+        }
+    }
+
+    #[test]
+    fn tumble_thrown_when_unreachable() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let faces = tumble(&D6::from(1), &D6::from(6), 1, &roller);
+
+        assert_eq!(faces, vec![D6::from(6)]);
+        assert!(tumble(&D6::from(1), &D6::from(6), 0, &roller).is_empty());
+    }
+
+    #[test]
+    fn tumble_unknown_solid() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let faces = tumble(&D3::from(1), &D3::from(2), 8, &roller);
+
+        assert_eq!(faces.last(), Some(&D3::from(2)));
+        assert_ne!(faces[0], D3::from(1));
+        assert!(faces.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+}
@@ -26,11 +26,33 @@
 //! assert_eq!(rd.value(), 3);
 //! ```
 
+#[cfg(feature = "simd")]
+mod bulk;
+mod cards;
+mod clock;
+mod coin;
+mod criticals;
 mod dice;
 mod roller;
+mod sampler;
+mod timer;
+mod tower;
+mod tracker;
+mod wheel;
 
+#[cfg(feature = "simd")]
+pub use bulk::*;
+pub use cards::*;
+pub use clock::*;
+pub use coin::*;
+pub use criticals::*;
 pub use dice::*;
 pub use roller::*;
+pub use sampler::*;
+pub use timer::*;
+pub use tower::*;
+pub use tracker::*;
+pub use wheel::*;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,390 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::items::NumericDie;
+use crate::stats::Histogram;
+use crate::traits::{Numeric, Positional};
+
+/// How many independent generators are advanced together.
+const LANES: usize = 8;
+
+/// How many rolls are generated from each block, using both 32-bit halves of each output.
+const BLOCK: usize = LANES * 2;
+
+/// How many rolls or trials use each stream when work is split, regardless of how many threads.
+const STREAM: usize = 1 << 16;
+
+/// The polynomial that advances a xoshiro256 state by `2^128` outputs.
+const JUMP: [u64; 4] = [
+    0x180e_c6d3_3cfd_0aba,
+    0xd5a6_1266_f0c9_392c,
+    0xa958_2618_e03f_c9aa,
+    0x39ab_dc45_29b1_661c,
+];
+
+/// The polynomial that advances a xoshiro256 state by `2^192` outputs.
+const LONG_JUMP: [u64; 4] = [
+    0x76e1_5d3e_fefd_cbbf,
+    0xc500_4e44_1c52_2fb3,
+    0x7771_0069_854e_e241,
+    0x3910_9bb0_2acb_e635,
+];
+
+/// Rolls large batches of dice at once, for Monte Carlo simulations and other bulk workloads.
+///
+/// Internally, eight independent [xoshiro256++] generators (lanes) are advanced together in a way
+/// that compilers can vectorize into SIMD instructions, and every output is split into two rolls.
+/// Each lane starts `2^128` outputs apart from the previous one, so lanes never overlap.
+///
+/// Faces are chosen with a multiply-shift, which has a negligible bias of at most `sides / 2^32`.
+///
+/// Unlike [`crate::items::RngRoller`], this is not a [`crate::traits::Roll`], as it trades the
+/// flexibility of rolling any single element for throughput when rolling many at once.
+///
+/// [xoshiro256++]: https://prng.di.unimi.it/
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{BulkRoller, D6};
+///
+/// let mut roller = BulkRoller::with_seed(7194422452970863838);
+/// let mut values = [0u8; 10];
+/// roller.roll_values_into(&D6::new(), &mut values);
+///
+/// assert_eq!(values, [6, 5, 1, 6, 3, 1, 2, 3, 1, 3]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BulkRoller {
+    state: [[u64; LANES]; 4],
+}
+
+impl BulkRoller {
+    /// Creates a roller where every lane is derived from the given seed.
+    pub fn with_seed(seed: u64) -> Self {
+        let mut seed = seed;
+        let mut lane = [0; 4].map(|_| splitmix64(&mut seed));
+        let mut state = [[0; LANES]; 4];
+        for l in 0..LANES {
+            for (word, value) in state.iter_mut().zip(lane) {
+                word[l] = value;
+            }
+            jump(&mut lane, JUMP);
+        }
+        Self { state }
+    }
+
+    /// Rolls every die in `out`, changing each to a random face.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::items::{BulkRoller, D20};
+    ///
+    /// let mut roller = BulkRoller::with_seed(7194422452970863838);
+    /// let mut dice = vec![D20::new(); 1000];
+    /// roller.roll_into(&mut dice);
+    ///
+    /// assert_eq!(dice.iter().filter(|d| d.value() == 20).count(), 48);
+    /// ```
+    pub fn roll_into<D>(&mut self, out: &mut [D])
+    where
+        D: Positional,
+    {
+        for chunk in out.chunks_mut(BLOCK) {
+            let positions = self.next_positions(D::sides());
+            for (die, position) in chunk.iter_mut().zip(positions) {
+                die.set_position(position as usize);
+            }
+        }
+    }
+
+    /// Rolls the kind of die once for every element of `out`, overwriting each with the value.
+    pub fn roll_values_into<T, const MAXIMUM: usize>(
+        &mut self,
+        _die: &NumericDie<T, MAXIMUM>,
+        out: &mut [T],
+    ) where
+        T: Numeric,
+    {
+        let minimum = T::MINIMUM.as_usize();
+        for chunk in out.chunks_mut(BLOCK) {
+            let positions = self.next_positions(MAXIMUM);
+            for (value, position) in chunk.iter_mut().zip(positions) {
+                *value = T::from_usize(position as usize + minimum);
+            }
+        }
+    }
+
+    /// Advances every lane by `2^192` outputs, i.e. to start a stream that never overlaps this one.
+    pub fn long_jump(&mut self) {
+        for l in 0..LANES {
+            let mut lane = self.state.map(|word| word[l]);
+            jump(&mut lane, LONG_JUMP);
+            for (word, value) in self.state.iter_mut().zip(lane) {
+                word[l] = value;
+            }
+        }
+    }
+
+    /// Runs a Monte Carlo simulation, tallying the result of every trial.
+    ///
+    /// Trials are split into fixed-size groups, each using its own [long-jumped] stream, so the
+    /// result is the same as [`BulkRoller::par_simulate`]. This roller is not advanced, so calling
+    /// again with the same arguments produces the same result.
+    ///
+    /// [long-jumped]: BulkRoller::long_jump
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::items::{BulkRoller, D6};
+    ///
+    /// let roller = BulkRoller::with_seed(7194422452970863838);
+    ///
+    /// // 3d6
+    /// let histogram = roller.simulate(10_000, |roller| {
+    ///     let mut values = [0u8; 3];
+    ///     roller.roll_values_into(&D6::new(), &mut values);
+    ///     values.iter().map(|&v| i64::from(v)).sum()
+    /// });
+    ///
+    /// assert_eq!(histogram.total(), 10_000);
+    /// assert_eq!(histogram.count(18), 50);
+    /// ```
+    pub fn simulate<F>(&self, trials: usize, mut trial: F) -> Histogram
+    where
+        F: FnMut(&mut BulkRoller) -> i64,
+    {
+        let mut histogram = Histogram::new();
+        for (mut roller, trials) in self.streams(trials) {
+            histogram.extend((0..trials).map(|_| trial(&mut roller)));
+        }
+        histogram
+    }
+
+    /// Returns a stream for every fixed-size group of `len` items, and the size of each group.
+    fn streams(&self, len: usize) -> Vec<(Self, usize)> {
+        let mut roller = self.clone();
+        (0..len)
+            .step_by(STREAM)
+            .map(|start| {
+                roller.long_jump();
+                (roller.clone(), STREAM.min(len - start))
+            })
+            .collect()
+    }
+
+    /// Returns a block of random positions, each between `0..sides`.
+    fn next_positions(&mut self, sides: usize) -> [u32; BLOCK] {
+        assert!(sides > 0 && sides <= u32::MAX as usize);
+        let sides = sides as u64;
+        let outputs = self.next_block();
+        let mut positions = [0; BLOCK];
+        for (i, output) in outputs.into_iter().enumerate() {
+            positions[i] = (((output & 0xffff_ffff) * sides) >> 32) as u32;
+            positions[i + LANES] = (((output >> 32) * sides) >> 32) as u32;
+        }
+        positions
+    }
+
+    /// Advances every lane once, returning the output of each lane.
+    fn next_block(&mut self) -> [u64; LANES] {
+        let [s0, s1, s2, s3] = &mut self.state;
+        let mut outputs = [0; LANES];
+        for l in 0..LANES {
+            outputs[l] = s0[l]
+                .wrapping_add(s3[l])
+                .rotate_left(23)
+                .wrapping_add(s0[l]);
+            let t = s1[l] << 17;
+            s2[l] ^= s0[l];
+            s3[l] ^= s1[l];
+            s1[l] ^= s2[l];
+            s0[l] ^= s3[l];
+            s2[l] ^= t;
+            s3[l] = s3[l].rotate_left(45);
+        }
+        outputs
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl BulkRoller {
+    /// Runs a Monte Carlo simulation across threads, tallying the result of every trial.
+    ///
+    /// The result is the same as [`BulkRoller::simulate`], regardless of how many threads are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::items::{BulkRoller, D6};
+    ///
+    /// let roller = BulkRoller::with_seed(7194422452970863838);
+    /// let trial = |roller: &mut BulkRoller| {
+    ///     let mut values = [0u8; 3];
+    ///     roller.roll_values_into(&D6::new(), &mut values);
+    ///     values.iter().map(|&v| i64::from(v)).sum()
+    /// };
+    ///
+    /// assert_eq!(roller.par_simulate(200_000, trial), roller.simulate(200_000, trial));
+    /// ```
+    pub fn par_simulate<F>(&self, trials: usize, trial: F) -> Histogram
+    where
+        F: Fn(&mut BulkRoller) -> i64 + Sync,
+    {
+        self.streams(trials)
+            .into_par_iter()
+            .map(|(mut roller, trials)| (0..trials).map(|_| trial(&mut roller)).collect())
+            .reduce(Histogram::new, |mut a, b| {
+                a.merge(&b);
+                a
+            })
+    }
+
+    /// Rolls the kind of die once for every element of `out` across threads.
+    ///
+    /// Like [`BulkRoller::simulate`], elements are split into fixed-size groups, each using its own
+    /// stream, so the result is reproducible regardless of how many threads are used, and this
+    /// roller is not advanced.
+    pub fn par_roll_values_into<T, const MAXIMUM: usize>(
+        &self,
+        die: &NumericDie<T, MAXIMUM>,
+        out: &mut [T],
+    ) where
+        T: Numeric + Send + Sync,
+    {
+        let streams = self.streams(out.len());
+        out.par_chunks_mut(STREAM)
+            .zip(streams)
+            .for_each(|(chunk, (mut roller, _))| roller.roll_values_into(die, chunk));
+    }
+}
+
+/// Returns the next output of a [splitmix64](https://prng.di.unimi.it/splitmix64.c) generator.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Advances a single xoshiro256 state by the number of outputs described by the polynomial.
+fn jump(state: &mut [u64; 4], polynomial: [u64; 4]) {
+    let mut jumped = [0; 4];
+    for word in polynomial {
+        for bit in 0..64 {
+            if word & (1 << bit) != 0 {
+                for (j, s) in jumped.iter_mut().zip(*state) {
+                    *j ^= s;
+                }
+            }
+            let t = state[1] << 17;
+            state[2] ^= state[0];
+            state[3] ^= state[1];
+            state[1] ^= state[2];
+            state[0] ^= state[3];
+            state[2] ^= t;
+            state[3] = state[3].rotate_left(45);
+        }
+    }
+    *state = jumped;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{D6, D8};
+
+    #[test]
+    fn bulk_matches_reference_output() {
+        let mut roller = BulkRoller {
+            state: [[1; LANES], [2; LANES], [3; LANES], [4; LANES]],
+        };
+
+        assert_eq!(roller.next_block(), [41943041; LANES]);
+    }
+
+    #[test]
+    fn bulk_lanes_differ() {
+        let mut roller = BulkRoller::with_seed(1);
+        let block = roller.next_block();
+
+        for (i, a) in block.iter().enumerate() {
+            assert!(!block[i + 1..].contains(a));
+        }
+    }
+
+    #[test]
+    fn bulk_is_reproducible() {
+        let mut a = [0u8; 100];
+        let mut b = [0u8; 100];
+        BulkRoller::with_seed(42).roll_values_into(&D6::new(), &mut a);
+        BulkRoller::with_seed(42).roll_values_into(&D6::new(), &mut b);
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn bulk_roll_values_is_uniform() {
+        let mut roller = BulkRoller::with_seed(7194422452970863838);
+        let mut values = vec![0u8; 60_000];
+        roller.roll_values_into(&D6::new(), &mut values);
+
+        for face in 1..=6 {
+            let count = values.iter().filter(|&&v| v == face).count();
+            assert!((9_000..11_000).contains(&count), "{face}: {count}");
+        }
+    }
+
+    #[test]
+    fn bulk_roll_into() {
+        let mut roller = BulkRoller::with_seed(7194422452970863838);
+        let mut dice = vec![D8::new(); 37];
+        roller.roll_into(&mut dice);
+
+        assert!(dice.iter().all(|d| (1..=8).contains(&d.value())));
+        assert!(dice.iter().any(|d| d.value() == 8));
+    }
+
+    #[test]
+    fn bulk_long_jump() {
+        let mut roller = BulkRoller::with_seed(1);
+        let before = roller.clone();
+        roller.long_jump();
+
+        assert_ne!(roller, before);
+        assert_eq!(before.streams(STREAM * 2 + 1).len(), 3);
+        assert_eq!(before.streams(STREAM * 2 + 1)[2].1, 1);
+        assert_eq!(before.streams(0).len(), 0);
+    }
+
+    #[test]
+    fn bulk_simulate_is_reproducible() {
+        let roller = BulkRoller::with_seed(3);
+        let trial = |roller: &mut BulkRoller| {
+            let mut values = [0u8; 1];
+            roller.roll_values_into(&D6::new(), &mut values);
+            i64::from(values[0])
+        };
+
+        assert_eq!(roller.simulate(STREAM + 7, trial).total(), STREAM + 7);
+        assert_eq!(roller.simulate(100, trial), roller.simulate(100, trial));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn bulk_par_roll_values_into() {
+        let roller = BulkRoller::with_seed(3);
+        let mut a = vec![0u8; STREAM * 3 + 5];
+        let mut b = vec![0u8; STREAM * 3 + 5];
+        roller.par_roll_values_into(&D6::new(), &mut a);
+        for (chunk, (mut roller, _)) in b.chunks_mut(STREAM).zip(roller.streams(STREAM * 3 + 5)) {
+            roller.roll_values_into(&D6::new(), chunk);
+        }
+
+        assert_eq!(a, b);
+        assert!(a.iter().all(|v| (1..=6).contains(v)));
+    }
+}
@@ -0,0 +1,14 @@
+//! Contains decks of cards, and other collections drawn from without replacement.
+
+mod bag;
+mod deck;
+mod domino;
+mod hand;
+mod standard;
+mod tarot;
+pub use bag::*;
+pub use deck::*;
+pub use domino::*;
+pub use hand::*;
+pub use standard::*;
+pub use tarot::*;
@@ -0,0 +1,173 @@
+use crate::items::roller::roll_index;
+use crate::traits::Roll;
+
+/// A bag of tiles (or chits, tokens, ...) that are drawn blindly, with or without replacement.
+///
+/// Unlike a [`Deck`][crate::items::Deck], the contents of a bag have no order.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{Bag, RngRoller};
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let mut bag = Bag::new(['A', 'A', 'B', 'C']);
+///
+/// let tile = bag.draw(&roller).unwrap();
+/// assert_eq!(bag.len(), 3);
+///
+/// bag.put(tile);
+/// assert_eq!(bag.count(&'A'), 2);
+///
+/// let _ = bag.draw_with_replacement(&roller).unwrap();
+/// assert_eq!(bag.len(), 4);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bag<T> {
+    contents: Vec<T>,
+}
+
+impl<T> Bag<T> {
+    /// Creates a bag with the given contents.
+    pub fn new<I>(contents: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            contents: contents.into_iter().collect(),
+        }
+    }
+
+    /// Returns how many items are in the bag.
+    pub fn len(&self) -> usize {
+        self.contents.len()
+    }
+
+    /// Returns whether the bag is empty.
+    pub fn is_empty(&self) -> bool {
+        self.contents.is_empty()
+    }
+
+    /// Returns every item remaining in the bag, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.contents.iter()
+    }
+
+    /// Puts an item into the bag.
+    pub fn put(&mut self, item: T) {
+        self.contents.push(item);
+    }
+
+    /// Removes and returns a random item from the bag, if any.
+    pub fn draw<R>(&mut self, roller: &R) -> Option<T>
+    where
+        R: Roll,
+    {
+        if self.contents.is_empty() {
+            return None;
+        }
+        let index = roll_index(roller, self.contents.len());
+        Some(self.contents.swap_remove(index))
+    }
+
+    /// Removes and returns up to `count` random items from the bag, in drawn order.
+    pub fn draw_n<R>(&mut self, roller: &R, count: usize) -> Vec<T>
+    where
+        R: Roll,
+    {
+        (0..count).map_while(|_| self.draw(roller)).collect()
+    }
+
+    /// Returns a random item from the bag, if any, as if it was drawn and put back.
+    pub fn draw_with_replacement<R>(&self, roller: &R) -> Option<&T>
+    where
+        R: Roll,
+    {
+        if self.contents.is_empty() {
+            return None;
+        }
+        self.contents.get(roll_index(roller, self.contents.len()))
+    }
+}
+
+impl<T> Bag<T>
+where
+    T: PartialEq,
+{
+    /// Returns how many of the given item are in the bag.
+    pub fn count(&self, item: &T) -> usize {
+        self.contents.iter().filter(|i| *i == item).count()
+    }
+}
+
+impl<T> Default for Bag<T> {
+    fn default() -> Self {
+        Self::new([])
+    }
+}
+
+impl<T> Extend<T> for Bag<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.contents.extend(iter);
+    }
+}
+
+impl<T> FromIterator<T> for Bag<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+    use crate::items::RngRoller;
+
+    #[test]
+    fn bag_default() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let mut bag: Bag<u8> = Default::default();
+
+        assert!(bag.is_empty());
+        assert_eq!(bag.draw(&roller), None);
+        assert_eq!(bag.draw_with_replacement(&roller), None);
+    }
+
+    #[test]
+    fn bag_draw_without_replacement() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let mut bag: Bag<_> = (1..=5).collect();
+
+        let mut drawn = bag.draw_n(&roller, 10);
+        assert!(bag.is_empty());
+
+        drawn.sort();
+        assert_eq!(drawn, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn bag_draw_with_replacement() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let bag = Bag::new([1, 2, 3]);
+
+        for _ in 0..10 {
+            assert!(bag.draw_with_replacement(&roller).is_some());
+        }
+        assert_eq!(bag.len(), 3);
+    }
+
+    #[test]
+    fn bag_put_and_count() {
+        let mut bag = Bag::new(["skull"]);
+        bag.put("skull");
+        bag.extend(["tentacle"]);
+
+        assert_eq!(bag.count(&"skull"), 2);
+        assert_eq!(bag.count(&"elder sign"), 0);
+        assert_eq!(bag.iter().count(), 3);
+    }
+}
@@ -0,0 +1,264 @@
+use crate::items::roller::roll_index;
+use crate::traits::Roll;
+
+/// A deck of cards (or tiles, tokens, ...), with a draw pile and a discard pile.
+///
+/// Cards are drawn from the top of the draw pile, and discarded cards are kept aside until the
+/// deck is reshuffled. Any [`Roll`] implementation is used as the source of entropy to shuffle.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{Deck, RngRoller};
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let mut deck = Deck::new(1..=10);
+/// deck.shuffle(&roller);
+///
+/// let card = deck.draw().unwrap();
+/// assert_eq!(deck.len(), 9);
+///
+/// deck.discard(card);
+/// assert_eq!(deck.discarded(), &[card]);
+///
+/// deck.reshuffle(&roller);
+/// assert_eq!(deck.len(), 10);
+/// assert!(deck.discarded().is_empty());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Deck<T> {
+    // The top of the draw pile is the last element.
+    cards: Vec<T>,
+    discarded: Vec<T>,
+}
+
+impl<T> Deck<T> {
+    /// Creates a deck with the given cards, where the first card is the top of the draw pile.
+    pub fn new<I>(cards: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut cards: Vec<T> = cards.into_iter().collect();
+        cards.reverse();
+        Self {
+            cards,
+            discarded: Vec::new(),
+        }
+    }
+
+    /// Returns how many cards are left in the draw pile.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns whether the draw pile is empty.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Returns the cards in the draw pile, from the top to the bottom.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cards.iter().rev()
+    }
+
+    /// Returns mutable references to the cards in the draw pile, from the top to the bottom.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.cards.iter_mut().rev()
+    }
+
+    /// Returns the cards in the discard pile, in the order they were discarded.
+    pub fn discarded(&self) -> &[T] {
+        &self.discarded
+    }
+
+    /// Returns the top card of the draw pile without drawing it, if any.
+    pub fn peek(&self) -> Option<&T> {
+        self.cards.last()
+    }
+
+    /// Removes and returns the top card of the draw pile, if any.
+    pub fn draw(&mut self) -> Option<T> {
+        self.cards.pop()
+    }
+
+    /// Removes and returns up to `count` cards from the top of the draw pile, in drawn order.
+    pub fn draw_n(&mut self, count: usize) -> Vec<T> {
+        let at = self.cards.len().saturating_sub(count);
+        let mut drawn = self.cards.split_off(at);
+        drawn.reverse();
+        drawn
+    }
+
+    /// Places a card on the discard pile.
+    pub fn discard(&mut self, card: T) {
+        self.discarded.push(card);
+    }
+
+    /// Places a card on top of the draw pile.
+    pub fn put_top(&mut self, card: T) {
+        self.cards.push(card);
+    }
+
+    /// Places a card on the bottom of the draw pile.
+    pub fn put_bottom(&mut self, card: T) {
+        self.cards.insert(0, card);
+    }
+
+    /// Shuffles the draw pile, leaving the discard pile as-is.
+    pub fn shuffle<R>(&mut self, roller: &R)
+    where
+        R: Roll,
+    {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(cards = self.cards.len(), "shuffle");
+
+        // Fisher-Yates.
+        for i in (1..self.cards.len()).rev() {
+            let j = roll_index(roller, i + 1);
+            self.cards.swap(i, j);
+        }
+    }
+
+    /// Returns every card in the discard pile to the draw pile, and shuffles the draw pile.
+    pub fn reshuffle<R>(&mut self, roller: &R)
+    where
+        R: Roll,
+    {
+        self.cards.append(&mut self.discarded);
+        self.shuffle(roller);
+    }
+
+    /// Shuffles the discard pile, and places it underneath the remaining draw pile.
+    ///
+    /// Unlike [`Deck::reshuffle`], the order of the remaining draw pile is preserved.
+    pub fn reshuffle_discarded<R>(&mut self, roller: &R)
+    where
+        R: Roll,
+    {
+        let mut discarded = Deck {
+            cards: std::mem::take(&mut self.discarded),
+            discarded: Vec::new(),
+        };
+        discarded.shuffle(roller);
+        discarded.cards.append(&mut self.cards);
+        self.cards = discarded.cards;
+    }
+}
+
+impl<T> Default for Deck<T> {
+    fn default() -> Self {
+        Self::new([])
+    }
+}
+
+impl<T> FromIterator<T> for Deck<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+    use crate::items::{NopRoller, RngRoller, SequenceRoller};
+
+    #[test]
+    fn deck_draw_order() {
+        let mut deck = Deck::new([1, 2, 3]);
+
+        assert_eq!(deck.peek(), Some(&1));
+        assert_eq!(deck.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(deck.draw(), Some(1));
+        assert_eq!(deck.draw_n(5), vec![2, 3]);
+        assert_eq!(deck.draw(), None);
+        assert!(deck.is_empty());
+    }
+
+    #[test]
+    fn deck_iter_mut() {
+        let mut deck = Deck::new([1, 2, 3]);
+        deck.iter_mut().for_each(|c| *c *= 10);
+
+        assert_eq!(deck.draw(), Some(10));
+    }
+
+    #[test]
+    fn deck_put() {
+        let mut deck: Deck<_> = [2].into_iter().collect();
+        deck.put_top(1);
+        deck.put_bottom(3);
+
+        assert_eq!(deck.draw_n(3), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn deck_default() {
+        let deck: Deck<u8> = Default::default();
+
+        assert!(deck.is_empty());
+        assert!(deck.discarded().is_empty());
+    }
+
+    #[test]
+    fn deck_shuffle_keeps_cards() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let mut deck = Deck::new(0..52);
+        deck.shuffle(&roller);
+
+        let mut cards: Vec<_> = deck.iter().copied().collect();
+        assert_ne!(cards, (0..52).collect::<Vec<_>>());
+
+        cards.sort();
+        assert_eq!(cards, (0..52).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn deck_shuffle_nop() {
+        let mut deck = Deck::new([1, 2, 3]);
+        deck.shuffle(&NopRoller);
+
+        assert_eq!(deck.len(), 3);
+    }
+
+    #[test]
+    fn deck_shuffle_stuck_roller() {
+        let mut deck = Deck::new([1u8, 2, 3]);
+        deck.shuffle(&SequenceRoller::new([15]));
+
+        assert_eq!(deck.len(), 3);
+    }
+
+    #[test]
+    fn deck_reshuffle() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let mut deck = Deck::new(0..10);
+        for card in deck.draw_n(4) {
+            deck.discard(card);
+        }
+        deck.reshuffle(&roller);
+
+        assert_eq!(deck.len(), 10);
+        assert!(deck.discarded().is_empty());
+    }
+
+    #[test]
+    fn deck_reshuffle_discarded() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let mut deck = Deck::new(0..10);
+        for card in deck.draw_n(4) {
+            deck.discard(card);
+        }
+        deck.reshuffle_discarded(&roller);
+
+        let cards: Vec<_> = deck.iter().copied().collect();
+        assert_eq!(cards[..6], [4, 5, 6, 7, 8, 9]);
+
+        let mut bottom = cards[6..].to_vec();
+        bottom.sort();
+        assert_eq!(bottom, vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,196 @@
+use std::fmt::Display;
+
+use super::Deck;
+
+/// A domino tile, with a number of pips on each end.
+///
+/// A domino has no orientation, so `[2|5]` and `[5|2]` are the same tile.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::Domino;
+///
+/// let domino = Domino::new(5, 2);
+///
+/// assert_eq!(domino, Domino::new(2, 5));
+/// assert_eq!(domino.pips(), 7);
+/// assert_eq!(domino.other_end(5), Some(2));
+/// assert_eq!(domino.to_string(), "[2|5]");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "DominoRepr")
+)]
+pub struct Domino {
+    low: u8,
+    high: u8,
+}
+
+impl Domino {
+    /// Creates a domino with the given ends, in any order.
+    pub const fn new(a: u8, b: u8) -> Self {
+        if a <= b {
+            Self { low: a, high: b }
+        } else {
+            Self { low: b, high: a }
+        }
+    }
+
+    /// Returns the end with fewer (or equal) pips.
+    pub const fn low(&self) -> u8 {
+        self.low
+    }
+
+    /// Returns the end with more (or equal) pips.
+    pub const fn high(&self) -> u8 {
+        self.high
+    }
+
+    /// Returns the total number of pips on both ends.
+    pub const fn pips(&self) -> u16 {
+        self.low as u16 + self.high as u16
+    }
+
+    /// Returns whether both ends have the same number of pips.
+    pub const fn is_double(&self) -> bool {
+        self.low == self.high
+    }
+
+    /// Returns whether either end has the given number of pips.
+    pub const fn matches(&self, pips: u8) -> bool {
+        self.low == pips || self.high == pips
+    }
+
+    /// Returns the opposite end to an end with the given number of pips, if either matches.
+    pub const fn other_end(&self, pips: u8) -> Option<u8> {
+        if self.low == pips {
+            Some(self.high)
+        } else if self.high == pips {
+            Some(self.low)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Domino {
+    /// Formats the domino as its ends, i.e. `[2|5]`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}|{}]", self.low, self.high)
+    }
+}
+
+/// The serialized form of a [`Domino`], which is validated when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct DominoRepr {
+    low: u8,
+    high: u8,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DominoRepr> for Domino {
+    type Error = crate::Error;
+
+    /// Creates a domino from the serialized ends, failing if the low end has more pips.
+    fn try_from(repr: DominoRepr) -> Result<Self, Self::Error> {
+        if repr.low <= repr.high {
+            Ok(Self::new(repr.low, repr.high))
+        } else {
+            Err(crate::Error::OutOfRange {
+                value: repr.low.into(),
+                min: 0,
+                max: repr.high.into(),
+            })
+        }
+    }
+}
+
+/// The pool of (face down) dominoes that players draw from.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{Boneyard, Hand, RngRoller};
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let mut boneyard = Boneyard::double_six();
+/// assert_eq!(boneyard.len(), 28);
+///
+/// boneyard.shuffle(&roller);
+/// let mut hand = Hand::new();
+/// hand.draw_from(&mut boneyard, 7);
+/// assert_eq!(boneyard.len(), 21);
+/// ```
+pub type Boneyard = Deck<Domino>;
+
+impl Deck<Domino> {
+    /// Creates an (unshuffled) set of every domino from `[0|0]` to `[maximum|maximum]`.
+    pub fn double(maximum: u8) -> Self {
+        (0..=maximum)
+            .flat_map(|low| (low..=maximum).map(move |high| Domino::new(low, high)))
+            .collect()
+    }
+
+    /// Creates an (unshuffled) standard set of 28 dominoes.
+    pub fn double_six() -> Self {
+        Self::double(6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn domino_normalized() {
+        let domino = Domino::new(6, 1);
+
+        assert_eq!(domino.low(), 1);
+        assert_eq!(domino.high(), 6);
+        assert!(!domino.is_double());
+        assert!(Domino::new(3, 3).is_double());
+    }
+
+    #[test]
+    fn domino_matches() {
+        let domino = Domino::new(1, 4);
+
+        assert!(domino.matches(1));
+        assert!(domino.matches(4));
+        assert!(!domino.matches(2));
+        assert_eq!(domino.other_end(1), Some(4));
+        assert_eq!(domino.other_end(2), None);
+        assert_eq!(Domino::new(3, 3).other_end(3), Some(3));
+    }
+
+    #[test]
+    fn boneyard_double_six() {
+        let boneyard = Boneyard::double_six();
+
+        assert_eq!(boneyard.peek(), Some(&Domino::new(0, 0)));
+        assert_eq!(boneyard.iter().filter(|d| d.is_double()).count(), 7);
+        assert_eq!(boneyard.iter().map(|d| d.pips()).sum::<u16>(), 168);
+    }
+
+    #[test]
+    fn boneyard_double_n() {
+        assert_eq!(Boneyard::double(0).len(), 1);
+        assert_eq!(Boneyard::double(9).len(), 55);
+        assert_eq!(Boneyard::double(12).len(), 91);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn domino_serde() {
+        let domino = Domino::new(2, 5);
+        let json = serde_json::to_string(&domino).unwrap();
+
+        assert_eq!(serde_json::from_str::<Domino>(&json).unwrap(), domino);
+        assert!(serde_json::from_str::<Domino>(r#"{"low":5,"high":2}"#).is_err());
+    }
+}
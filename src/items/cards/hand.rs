@@ -0,0 +1,255 @@
+use std::cmp::Ordering;
+
+use super::Deck;
+
+/// A player's hand of cards (or tiles, tokens, ...), each of which is either hidden or revealed.
+///
+/// Cards are kept in the order they were added, until sorted.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{Deck, Hand};
+///
+/// let mut deck = Deck::new([7, 3, 9, 1]);
+/// let mut hand = Hand::new();
+/// hand.draw_from(&mut deck, 3);
+/// hand.sort();
+/// assert_eq!(hand.cards().collect::<Vec<_>>(), [&3, &7, &9]);
+///
+/// hand.reveal(0);
+/// assert_eq!(hand.revealed().collect::<Vec<_>>(), [&3]);
+///
+/// assert_eq!(hand.play(1), 7);
+/// hand.discard_to(0, &mut deck);
+/// assert_eq!(deck.discarded(), &[3]);
+/// assert_eq!(hand.len(), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hand<T> {
+    cards: Vec<Held<T>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Held<T> {
+    card: T,
+    revealed: bool,
+}
+
+impl<T> Hand<T> {
+    /// Creates an empty hand.
+    pub const fn new() -> Self {
+        Self { cards: Vec::new() }
+    }
+
+    /// Returns how many cards are in the hand.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns whether the hand is empty.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Returns the card at the given index, if any.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.cards.get(index).map(|held| &held.card)
+    }
+
+    /// Returns every card in the hand, in order.
+    pub fn cards(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cards.iter().map(|held| &held.card)
+    }
+
+    /// Returns every revealed card in the hand, in order.
+    pub fn revealed(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cards
+            .iter()
+            .filter(|held| held.revealed)
+            .map(|held| &held.card)
+    }
+
+    /// Adds a (hidden) card to the end of the hand.
+    pub fn add(&mut self, card: T) {
+        self.cards.push(Held {
+            card,
+            revealed: false,
+        });
+    }
+
+    /// Draws up to `count` cards from the deck into the hand, returning how many were drawn.
+    pub fn draw_from(&mut self, deck: &mut Deck<T>, count: usize) -> usize {
+        let drawn = deck.draw_n(count);
+        let len = drawn.len();
+        drawn.into_iter().for_each(|card| self.add(card));
+        len
+    }
+
+    /// Returns whether the card at the given index is revealed.
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn is_revealed(&self, index: usize) -> bool {
+        self.cards[index].revealed
+    }
+
+    /// Reveals the card at the given index.
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn reveal(&mut self, index: usize) {
+        self.cards[index].revealed = true;
+    }
+
+    /// Reveals every card in the hand.
+    pub fn reveal_all(&mut self) {
+        self.cards.iter_mut().for_each(|held| held.revealed = true);
+    }
+
+    /// Hides the card at the given index.
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn hide(&mut self, index: usize) {
+        self.cards[index].revealed = false;
+    }
+
+    /// Removes and returns the card at the given index.
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn play(&mut self, index: usize) -> T {
+        self.cards.remove(index).card
+    }
+
+    /// Removes the card at the given index, and places it on the discard pile of the deck.
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn discard_to(&mut self, index: usize, deck: &mut Deck<T>) {
+        deck.discard(self.play(index));
+    }
+
+    /// Sorts the cards in the hand using the comparator, keeping whether each card is revealed.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.cards.sort_by(|a, b| compare(&a.card, &b.card));
+    }
+}
+
+impl<T> Hand<T>
+where
+    T: Ord,
+{
+    /// Sorts the cards in the hand, keeping whether each card is revealed.
+    pub fn sort(&mut self) {
+        self.sort_by(T::cmp);
+    }
+}
+
+impl<T> Default for Hand<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for Hand<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut hand = Self::new();
+        iter.into_iter().for_each(|card| hand.add(card));
+        hand
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{PlayingCard, Rank, Suit};
+
+    #[test]
+    fn hand_default() {
+        let hand: Hand<u8> = Default::default();
+
+        assert!(hand.is_empty());
+        assert_eq!(hand.get(0), None);
+    }
+
+    #[test]
+    fn hand_draw_from() {
+        let mut deck = Deck::new([1, 2]);
+        let mut hand = Hand::new();
+
+        assert_eq!(hand.draw_from(&mut deck, 3), 2);
+        assert_eq!(hand.cards().collect::<Vec<_>>(), [&1, &2]);
+        assert!(deck.is_empty());
+    }
+
+    #[test]
+    fn hand_reveal_and_hide() {
+        let mut hand: Hand<_> = [1, 2, 3].into_iter().collect();
+        hand.reveal(1);
+
+        assert!(!hand.is_revealed(0));
+        assert!(hand.is_revealed(1));
+
+        hand.reveal_all();
+        hand.hide(2);
+        assert_eq!(hand.revealed().collect::<Vec<_>>(), [&1, &2]);
+    }
+
+    #[test]
+    fn hand_sort_keeps_revealed() {
+        let mut hand: Hand<_> = [3, 1, 2].into_iter().collect();
+        hand.reveal(0);
+        hand.sort();
+
+        assert_eq!(hand.cards().collect::<Vec<_>>(), [&1, &2, &3]);
+        assert!(hand.is_revealed(2));
+    }
+
+    #[test]
+    fn hand_sort_by() {
+        let mut hand: Hand<_> = [
+            PlayingCard::new(Rank::Ace, Suit::Spades),
+            PlayingCard::new(Rank::King, Suit::Clubs),
+        ]
+        .into_iter()
+        .collect();
+        hand.sort_by(PlayingCard::cmp_by_suit);
+
+        assert_eq!(
+            hand.get(0),
+            Some(&PlayingCard::new(Rank::King, Suit::Clubs))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn hand_play_out_of_bounds() {
+        Hand::<u8>::new().play(0);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn hand_serde() {
+        let mut hand: Hand<_> = [5, 6].into_iter().collect();
+        hand.reveal(1);
+
+        let json = serde_json::to_string(&hand).unwrap();
+        assert_eq!(
+            json,
+            r#"{"cards":[{"card":5,"revealed":false},{"card":6,"revealed":true}]}"#
+        );
+        assert_eq!(serde_json::from_str::<Hand<u8>>(&json).unwrap(), hand);
+    }
+}
@@ -0,0 +1,315 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+use super::Deck;
+
+/// The suit of a standard playing card.
+///
+/// Suits are ordered as in _bridge_: clubs, diamonds, hearts, spades.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Suit {
+    /// ♣
+    Clubs,
+
+    /// ♦
+    Diamonds,
+
+    /// ♥
+    Hearts,
+
+    /// ♠
+    Spades,
+}
+
+impl Suit {
+    /// Every suit, in order.
+    pub const ALL: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
+    /// Returns whether the suit is red (diamonds or hearts).
+    pub const fn is_red(&self) -> bool {
+        matches!(self, Suit::Diamonds | Suit::Hearts)
+    }
+
+    /// Returns whether the suit is black (clubs or spades).
+    pub const fn is_black(&self) -> bool {
+        !self.is_red()
+    }
+
+    /// Returns the symbol of the suit, i.e. `'♠'`.
+    pub const fn symbol(&self) -> char {
+        match self {
+            Suit::Clubs => '♣',
+            Suit::Diamonds => '♦',
+            Suit::Hearts => '♥',
+            Suit::Spades => '♠',
+        }
+    }
+}
+
+/// The rank of a standard playing card.
+///
+/// Ranks are ordered with the ace low; see [`Rank::ace_high`] for games where the ace is high.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rank {
+    Ace = 1,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+}
+
+impl Rank {
+    /// Every rank, in (ace low) order.
+    pub const ALL: [Rank; 13] = [
+        Rank::Ace,
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+    ];
+
+    /// Returns the value of the rank, with the ace low, i.e. `1..=13`.
+    pub const fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the value of the rank, with the ace high, i.e. `2..=14`.
+    pub const fn ace_high(&self) -> u8 {
+        match self {
+            Rank::Ace => 14,
+            rank => rank.value(),
+        }
+    }
+
+    /// Returns whether the rank is a jack, queen, or king.
+    pub const fn is_face(&self) -> bool {
+        matches!(self, Rank::Jack | Rank::Queen | Rank::King)
+    }
+
+    const fn symbol(&self) -> &'static str {
+        match self {
+            Rank::Ace => "A",
+            Rank::Two => "2",
+            Rank::Three => "3",
+            Rank::Four => "4",
+            Rank::Five => "5",
+            Rank::Six => "6",
+            Rank::Seven => "7",
+            Rank::Eight => "8",
+            Rank::Nine => "9",
+            Rank::Ten => "10",
+            Rank::Jack => "J",
+            Rank::Queen => "Q",
+            Rank::King => "K",
+        }
+    }
+}
+
+/// A card in a standard 52-card deck, or a joker.
+///
+/// Cards are ordered by rank (ace low), then by suit, and jokers are higher than any other card.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{PlayingCard, Rank, Suit};
+///
+/// let ace = PlayingCard::new(Rank::Ace, Suit::Spades);
+/// let king = PlayingCard::new(Rank::King, Suit::Hearts);
+///
+/// assert!(ace < king);
+/// assert!(ace.cmp_ace_high(&king).is_gt());
+/// assert_eq!(ace.to_string(), "A♠");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlayingCard {
+    /// A card with a rank and a suit.
+    Suited(Rank, Suit),
+
+    /// A joker, which has no rank or suit.
+    Joker,
+}
+
+impl PlayingCard {
+    /// Creates a card with the given rank and suit.
+    pub const fn new(rank: Rank, suit: Suit) -> Self {
+        PlayingCard::Suited(rank, suit)
+    }
+
+    /// Returns the rank of the card, or `None` if a joker.
+    pub const fn rank(&self) -> Option<Rank> {
+        match self {
+            PlayingCard::Suited(rank, _) => Some(*rank),
+            PlayingCard::Joker => None,
+        }
+    }
+
+    /// Returns the suit of the card, or `None` if a joker.
+    pub const fn suit(&self) -> Option<Suit> {
+        match self {
+            PlayingCard::Suited(_, suit) => Some(*suit),
+            PlayingCard::Joker => None,
+        }
+    }
+
+    /// Returns whether the card is a joker.
+    pub const fn is_joker(&self) -> bool {
+        matches!(self, PlayingCard::Joker)
+    }
+
+    /// Compares cards as [`Ord`] does, except that the ace is higher than the king.
+    pub fn cmp_ace_high(&self, other: &Self) -> Ordering {
+        let key = |card: &Self| card.rank().map(|rank| (rank.ace_high(), card.suit()));
+        match (key(self), key(other)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => self.cmp(other),
+        }
+    }
+
+    /// Compares cards by suit first, then by rank (ace low), as when sorting a hand by suit.
+    pub fn cmp_by_suit(&self, other: &Self) -> Ordering {
+        let key = |card: &Self| (card.is_joker(), card.suit(), card.rank());
+        key(self).cmp(&key(other))
+    }
+}
+
+impl Display for PlayingCard {
+    /// Formats the card as its rank and suit symbol, i.e. `10♥`, or `Joker`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlayingCard::Suited(rank, suit) => write!(f, "{}{}", rank.symbol(), suit.symbol()),
+            PlayingCard::Joker => f.write_str("Joker"),
+        }
+    }
+}
+
+/// A deck of standard playing cards.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{RngRoller, StandardDeck};
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let mut deck = StandardDeck::standard().with_jokers(2);
+/// assert_eq!(deck.len(), 54);
+///
+/// deck.shuffle(&roller);
+/// let hand = deck.draw_n(5);
+/// assert_eq!(deck.len(), 49);
+/// ```
+pub type StandardDeck = Deck<PlayingCard>;
+
+impl Deck<PlayingCard> {
+    /// Creates an (unshuffled) 52-card deck, ordered by suit and then by rank.
+    pub fn standard() -> Self {
+        Suit::ALL
+            .into_iter()
+            .flat_map(|suit| Rank::ALL.map(|rank| PlayingCard::new(rank, suit)))
+            .collect()
+    }
+
+    /// Returns the deck with `count` jokers added to the bottom of the draw pile.
+    #[must_use]
+    pub fn with_jokers(mut self, count: usize) -> Self {
+        for _ in 0..count {
+            self.put_bottom(PlayingCard::Joker);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suit_colors() {
+        assert!(Suit::Hearts.is_red());
+        assert!(Suit::Diamonds.is_red());
+        assert!(Suit::Clubs.is_black());
+        assert!(Suit::Spades.is_black());
+    }
+
+    #[test]
+    fn rank_values() {
+        assert_eq!(Rank::Ace.value(), 1);
+        assert_eq!(Rank::Ace.ace_high(), 14);
+        assert_eq!(Rank::King.value(), 13);
+        assert_eq!(Rank::King.ace_high(), 13);
+        assert!(Rank::Queen.is_face());
+        assert!(!Rank::Ten.is_face());
+    }
+
+    #[test]
+    fn playing_card_ordering() {
+        let two = PlayingCard::new(Rank::Two, Suit::Spades);
+        let ace = PlayingCard::new(Rank::Ace, Suit::Clubs);
+        let ace_spades = PlayingCard::new(Rank::Ace, Suit::Spades);
+
+        assert!(ace < two);
+        assert!(ace < ace_spades);
+        assert!(two < PlayingCard::Joker);
+        assert!(ace.cmp_ace_high(&two).is_gt());
+        assert!(ace.cmp_ace_high(&ace_spades).is_lt());
+        assert!(ace.cmp_ace_high(&PlayingCard::Joker).is_lt());
+        assert!(two.cmp_by_suit(&ace).is_gt());
+        assert!(two.cmp_by_suit(&PlayingCard::Joker).is_lt());
+    }
+
+    #[test]
+    fn playing_card_joker() {
+        assert!(PlayingCard::Joker.is_joker());
+        assert_eq!(PlayingCard::Joker.rank(), None);
+        assert_eq!(PlayingCard::Joker.suit(), None);
+        assert_eq!(PlayingCard::Joker.to_string(), "Joker");
+    }
+
+    #[test]
+    fn playing_card_display() {
+        assert_eq!(PlayingCard::new(Rank::Ten, Suit::Hearts).to_string(), "10♥");
+        assert_eq!(PlayingCard::new(Rank::Queen, Suit::Clubs).to_string(), "Q♣");
+    }
+
+    #[test]
+    fn standard_deck() {
+        let mut deck = StandardDeck::standard();
+
+        assert_eq!(deck.len(), 52);
+        assert_eq!(deck.peek(), Some(&PlayingCard::new(Rank::Ace, Suit::Clubs)));
+
+        let mut cards = deck.draw_n(52);
+        cards.sort();
+        cards.dedup();
+        assert_eq!(cards.len(), 52);
+    }
+
+    #[test]
+    fn standard_deck_with_jokers() {
+        let deck = StandardDeck::standard().with_jokers(2);
+
+        assert_eq!(deck.len(), 54);
+        assert_eq!(deck.iter().filter(|c| c.is_joker()).count(), 2);
+        assert_eq!(deck.iter().last(), Some(&PlayingCard::Joker));
+    }
+}
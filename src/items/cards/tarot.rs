@@ -0,0 +1,420 @@
+use std::fmt::Display;
+
+use super::Deck;
+use crate::items::roller::roll_index;
+use crate::traits::Roll;
+
+/// One of the 22 trump cards of a tarot deck, numbered `0` (the fool) to `21` (the world).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MajorArcana {
+    Fool,
+    Magician,
+    HighPriestess,
+    Empress,
+    Emperor,
+    Hierophant,
+    Lovers,
+    Chariot,
+    Strength,
+    Hermit,
+    WheelOfFortune,
+    Justice,
+    HangedMan,
+    Death,
+    Temperance,
+    Devil,
+    Tower,
+    Star,
+    Moon,
+    Sun,
+    Judgement,
+    World,
+}
+
+impl MajorArcana {
+    /// Every major arcana, in order.
+    pub const ALL: [MajorArcana; 22] = [
+        MajorArcana::Fool,
+        MajorArcana::Magician,
+        MajorArcana::HighPriestess,
+        MajorArcana::Empress,
+        MajorArcana::Emperor,
+        MajorArcana::Hierophant,
+        MajorArcana::Lovers,
+        MajorArcana::Chariot,
+        MajorArcana::Strength,
+        MajorArcana::Hermit,
+        MajorArcana::WheelOfFortune,
+        MajorArcana::Justice,
+        MajorArcana::HangedMan,
+        MajorArcana::Death,
+        MajorArcana::Temperance,
+        MajorArcana::Devil,
+        MajorArcana::Tower,
+        MajorArcana::Star,
+        MajorArcana::Moon,
+        MajorArcana::Sun,
+        MajorArcana::Judgement,
+        MajorArcana::World,
+    ];
+
+    /// Returns the number of the card, i.e. `0` for the fool.
+    pub const fn number(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the name of the card, i.e. `"The Fool"`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            MajorArcana::Fool => "The Fool",
+            MajorArcana::Magician => "The Magician",
+            MajorArcana::HighPriestess => "The High Priestess",
+            MajorArcana::Empress => "The Empress",
+            MajorArcana::Emperor => "The Emperor",
+            MajorArcana::Hierophant => "The Hierophant",
+            MajorArcana::Lovers => "The Lovers",
+            MajorArcana::Chariot => "The Chariot",
+            MajorArcana::Strength => "Strength",
+            MajorArcana::Hermit => "The Hermit",
+            MajorArcana::WheelOfFortune => "Wheel of Fortune",
+            MajorArcana::Justice => "Justice",
+            MajorArcana::HangedMan => "The Hanged Man",
+            MajorArcana::Death => "Death",
+            MajorArcana::Temperance => "Temperance",
+            MajorArcana::Devil => "The Devil",
+            MajorArcana::Tower => "The Tower",
+            MajorArcana::Star => "The Star",
+            MajorArcana::Moon => "The Moon",
+            MajorArcana::Sun => "The Sun",
+            MajorArcana::Judgement => "Judgement",
+            MajorArcana::World => "The World",
+        }
+    }
+}
+
+/// The suit of a minor arcana tarot card.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TarotSuit {
+    Wands,
+    Cups,
+    Swords,
+    Pentacles,
+}
+
+impl TarotSuit {
+    /// Every suit, in order.
+    pub const ALL: [TarotSuit; 4] = [
+        TarotSuit::Wands,
+        TarotSuit::Cups,
+        TarotSuit::Swords,
+        TarotSuit::Pentacles,
+    ];
+}
+
+/// The rank of a minor arcana tarot card, from the ace to the four court cards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TarotRank {
+    Ace = 1,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Page,
+    Knight,
+    Queen,
+    King,
+}
+
+impl TarotRank {
+    /// Every rank, in order.
+    pub const ALL: [TarotRank; 14] = [
+        TarotRank::Ace,
+        TarotRank::Two,
+        TarotRank::Three,
+        TarotRank::Four,
+        TarotRank::Five,
+        TarotRank::Six,
+        TarotRank::Seven,
+        TarotRank::Eight,
+        TarotRank::Nine,
+        TarotRank::Ten,
+        TarotRank::Page,
+        TarotRank::Knight,
+        TarotRank::Queen,
+        TarotRank::King,
+    ];
+
+    /// Returns the value of the rank, i.e. `1..=14`.
+    pub const fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns whether the rank is a court card (page, knight, queen, or king).
+    pub const fn is_court(&self) -> bool {
+        self.value() > TarotRank::Ten.value()
+    }
+}
+
+/// The face of a tarot card, regardless of orientation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Arcana {
+    /// A trump card.
+    Major(MajorArcana),
+
+    /// A suited card.
+    Minor(TarotRank, TarotSuit),
+}
+
+impl Display for Arcana {
+    /// Formats the card as its name, i.e. `The Fool` or `Knight of Cups`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Arcana::Major(major) => f.write_str(major.name()),
+            Arcana::Minor(rank, suit) => write!(f, "{rank:?} of {suit:?}"),
+        }
+    }
+}
+
+/// A tarot card, which may be drawn upright or reversed.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{MajorArcana, TarotCard};
+///
+/// let card = TarotCard::major(MajorArcana::Tower).reversed();
+///
+/// assert!(card.is_reversed());
+/// assert_eq!(card.to_string(), "The Tower (reversed)");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TarotCard {
+    arcana: Arcana,
+    reversed: bool,
+}
+
+impl TarotCard {
+    /// Creates an upright major arcana card.
+    pub const fn major(major: MajorArcana) -> Self {
+        Self {
+            arcana: Arcana::Major(major),
+            reversed: false,
+        }
+    }
+
+    /// Creates an upright minor arcana card.
+    pub const fn minor(rank: TarotRank, suit: TarotSuit) -> Self {
+        Self {
+            arcana: Arcana::Minor(rank, suit),
+            reversed: false,
+        }
+    }
+
+    /// Returns the face of the card.
+    pub const fn arcana(&self) -> Arcana {
+        self.arcana
+    }
+
+    /// Returns whether the card is reversed (upside-down).
+    pub const fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Returns the card turned the other way around.
+    #[must_use]
+    pub const fn reversed(self) -> Self {
+        Self {
+            arcana: self.arcana,
+            reversed: !self.reversed,
+        }
+    }
+}
+
+impl Display for TarotCard {
+    /// Formats the card as its name, followed by ` (reversed)` if reversed.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arcana)?;
+        if self.reversed {
+            f.write_str(" (reversed)")?;
+        }
+        Ok(())
+    }
+}
+
+/// A common layout of tarot cards, where each drawn card has a named position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Spread {
+    /// A single card.
+    Single,
+
+    /// Three cards: past, present, and future.
+    ThreeCard,
+
+    /// The ten-card _Celtic Cross_.
+    CelticCross,
+}
+
+impl Spread {
+    /// Returns the name of each position of the spread, in drawn order.
+    pub const fn positions(&self) -> &'static [&'static str] {
+        match self {
+            Spread::Single => &["Card"],
+            Spread::ThreeCard => &["Past", "Present", "Future"],
+            Spread::CelticCross => &[
+                "Present",
+                "Challenge",
+                "Past",
+                "Future",
+                "Above",
+                "Below",
+                "Advice",
+                "External Influences",
+                "Hopes and Fears",
+                "Outcome",
+            ],
+        }
+    }
+
+    /// Returns how many cards are drawn for the spread.
+    pub const fn len(&self) -> usize {
+        self.positions().len()
+    }
+
+    /// Returns whether the spread has no cards; always `false`.
+    pub const fn is_empty(&self) -> bool {
+        self.positions().is_empty()
+    }
+}
+
+/// A 78-card tarot deck.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{RngRoller, Spread, TarotDeck};
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let mut deck = TarotDeck::tarot();
+/// deck.shuffle_reversible(&roller);
+///
+/// let reading = deck.draw_spread(Spread::ThreeCard).unwrap();
+/// assert_eq!(reading[0].0, "Past");
+/// assert_eq!(deck.len(), 75);
+/// ```
+pub type TarotDeck = Deck<TarotCard>;
+
+impl Deck<TarotCard> {
+    /// Creates an (unshuffled) 78-card deck of upright cards, major arcana first.
+    pub fn tarot() -> Self {
+        let major = MajorArcana::ALL.map(TarotCard::major);
+        let minor = TarotSuit::ALL
+            .into_iter()
+            .flat_map(|suit| TarotRank::ALL.map(|rank| TarotCard::minor(rank, suit)));
+        major.into_iter().chain(minor).collect()
+    }
+
+    /// Shuffles the draw pile, and turns each card upright or reversed with equal odds.
+    pub fn shuffle_reversible<R>(&mut self, roller: &R)
+    where
+        R: Roll,
+    {
+        self.shuffle(roller);
+        for card in self.iter_mut() {
+            card.reversed = roll_index(roller, 2) == 1;
+        }
+    }
+
+    /// Draws a card for each position of the spread, or `None` if there are not enough cards.
+    pub fn draw_spread(&mut self, spread: Spread) -> Option<Vec<(&'static str, TarotCard)>> {
+        if self.len() < spread.len() {
+            return None;
+        }
+        let cards = self.draw_n(spread.len());
+        Some(spread.positions().iter().copied().zip(cards).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+    use crate::items::RngRoller;
+
+    #[test]
+    fn major_arcana_numbers() {
+        assert_eq!(MajorArcana::Fool.number(), 0);
+        assert_eq!(MajorArcana::World.number(), 21);
+        assert_eq!(MajorArcana::ALL.len(), 22);
+    }
+
+    #[test]
+    fn tarot_rank_court() {
+        assert!(!TarotRank::Ten.is_court());
+        assert!(TarotRank::Page.is_court());
+        assert_eq!(TarotRank::King.value(), 14);
+    }
+
+    #[test]
+    fn tarot_card_display() {
+        let card = TarotCard::minor(TarotRank::Knight, TarotSuit::Cups);
+
+        assert_eq!(card.to_string(), "Knight of Cups");
+        assert_eq!(card.reversed().to_string(), "Knight of Cups (reversed)");
+        assert_eq!(card.reversed().reversed(), card);
+    }
+
+    #[test]
+    fn tarot_deck() {
+        let mut deck = TarotDeck::tarot();
+
+        assert_eq!(deck.len(), 78);
+        assert_eq!(deck.peek(), Some(&TarotCard::major(MajorArcana::Fool)));
+        assert!(deck.iter().all(|c| !c.is_reversed()));
+
+        let mut cards = deck.draw_n(78);
+        cards.sort();
+        cards.dedup();
+        assert_eq!(cards.len(), 78);
+    }
+
+    #[test]
+    fn tarot_deck_shuffle_reversible() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let mut deck = TarotDeck::tarot();
+        deck.shuffle_reversible(&roller);
+
+        let reversed = deck.iter().filter(|c| c.is_reversed()).count();
+        assert!(reversed > 0 && reversed < 78);
+    }
+
+    #[test]
+    fn tarot_deck_draw_spread() {
+        let mut deck = TarotDeck::tarot();
+        let spread = deck.draw_spread(Spread::CelticCross).unwrap();
+
+        assert_eq!(spread.len(), 10);
+        assert_eq!(spread[0], ("Present", TarotCard::major(MajorArcana::Fool)));
+        assert_eq!(deck.len(), 68);
+    }
+
+    #[test]
+    fn tarot_deck_draw_spread_not_enough() {
+        let mut deck: TarotDeck = [TarotCard::major(MajorArcana::Sun)].into_iter().collect();
+
+        assert_eq!(deck.draw_spread(Spread::ThreeCard), None);
+        assert_eq!(deck.len(), 1);
+        assert!(!Spread::Single.is_empty());
+    }
+}
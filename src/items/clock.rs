@@ -0,0 +1,221 @@
+use crate::traits::{Step, StepMut};
+
+/// What happens when a [`Clock`] is ticked past its last segment.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// The clock stays full, and extra ticks are lost.
+    #[default]
+    Saturate,
+
+    /// The clock completes, and extra ticks start filling it again from empty.
+    Wrap,
+}
+
+/// A progress clock, a circle divided into segments that are filled in as a situation progresses.
+///
+/// Popularized by _Blades in the Dark_, where clocks typically have 4, 6, or 8 segments.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::Clock;
+/// use tomb::traits::StepMut;
+///
+/// let mut clock = Clock::new(4);
+/// clock.tick(3);
+/// assert_eq!(clock.filled(), 3);
+///
+/// clock.next_mut();
+/// assert!(clock.is_full());
+///
+/// clock.back_mut();
+/// assert_eq!(clock.filled(), 3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Clock {
+    segments: u8,
+    filled: u8,
+    overflow: Overflow,
+}
+
+impl Clock {
+    /// Creates an empty clock with the given number of segments, which saturates when full.
+    ///
+    /// # Panics
+    ///
+    /// If `segments` is `0`.
+    pub const fn new(segments: u8) -> Self {
+        assert!(segments > 0);
+        Self {
+            segments,
+            filled: 0,
+            overflow: Overflow::Saturate,
+        }
+    }
+
+    /// Returns the clock with the given overflow behavior.
+    pub const fn with_overflow(self, overflow: Overflow) -> Self {
+        Self {
+            segments: self.segments,
+            filled: self.filled,
+            overflow,
+        }
+    }
+
+    /// Returns how many segments the clock has.
+    pub const fn segments(&self) -> u8 {
+        self.segments
+    }
+
+    /// Returns how many segments are filled.
+    pub const fn filled(&self) -> u8 {
+        self.filled
+    }
+
+    /// Returns the overflow behavior.
+    pub const fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    /// Returns whether every segment is filled.
+    pub const fn is_full(&self) -> bool {
+        self.filled == self.segments
+    }
+
+    /// Returns whether no segments are filled.
+    pub const fn is_empty(&self) -> bool {
+        self.filled == 0
+    }
+
+    /// Fills the given number of segments, returning how many times the clock was completed.
+    ///
+    /// With [`Overflow::Saturate`], the clock is completed at most once, and stays full.
+    pub fn tick(&mut self, segments: u8) -> usize {
+        let total = self.filled as usize + segments as usize;
+        let size = self.segments as usize;
+        match self.overflow {
+            Overflow::Saturate => {
+                let completed = usize::from(!self.is_full() && total >= size);
+                self.filled = total.min(size) as u8;
+                completed
+            }
+            Overflow::Wrap => {
+                // A clock that is exactly full is completed, but is not yet wrapped.
+                let completed = total / size - usize::from(self.is_full());
+                self.filled = match total % size {
+                    0 if total > 0 => self.segments,
+                    filled => filled as u8,
+                };
+                completed
+            }
+        }
+    }
+
+    /// Clears the given number of filled segments, stopping at empty.
+    pub fn clear(&mut self, segments: u8) {
+        self.filled = self.filled.saturating_sub(segments);
+    }
+
+    /// Clears every segment.
+    pub fn reset(&mut self) {
+        self.filled = 0;
+    }
+}
+
+impl Step for Clock {
+    /// Returns the clock with one more segment filled.
+    fn next(&self) -> Self {
+        let mut next = *self;
+        next.next_mut();
+        next
+    }
+
+    /// Returns the clock with one fewer segment filled.
+    fn back(&self) -> Self {
+        let mut back = *self;
+        back.back_mut();
+        back
+    }
+}
+
+impl StepMut for Clock {
+    /// Fills one segment.
+    fn next_mut(&mut self) {
+        self.tick(1);
+    }
+
+    /// Clears one segment.
+    fn back_mut(&mut self) {
+        self.clear(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_new() {
+        let clock = Clock::new(6);
+
+        assert_eq!(clock.segments(), 6);
+        assert_eq!(clock.overflow(), Overflow::Saturate);
+        assert!(clock.is_empty());
+        assert!(!clock.is_full());
+    }
+
+    #[test]
+    #[should_panic]
+    fn clock_no_segments() {
+        Clock::new(0);
+    }
+
+    #[test]
+    fn clock_saturate() {
+        let mut clock = Clock::new(4);
+
+        assert_eq!(clock.tick(3), 0);
+        assert_eq!(clock.tick(3), 1);
+        assert_eq!(clock.filled(), 4);
+        assert_eq!(clock.tick(1), 0);
+        assert!(clock.is_full());
+    }
+
+    #[test]
+    fn clock_wrap() {
+        let mut clock = Clock::new(4).with_overflow(Overflow::Wrap);
+
+        assert_eq!(clock.tick(4), 1);
+        assert!(clock.is_full());
+        assert_eq!(clock.tick(1), 0);
+        assert_eq!(clock.filled(), 1);
+        assert_eq!(clock.tick(10), 2);
+        assert_eq!(clock.filled(), 3);
+        assert_eq!(clock.tick(0), 0);
+    }
+
+    #[test]
+    fn clock_clear() {
+        let mut clock = Clock::new(8);
+        clock.tick(5);
+        clock.clear(2);
+
+        assert_eq!(clock.filled(), 3);
+
+        clock.clear(10);
+        assert!(clock.is_empty());
+
+        clock.tick(2);
+        clock.reset();
+        assert!(clock.is_empty());
+    }
+
+    #[test]
+    fn clock_step() {
+        let clock = Clock::new(4);
+
+        assert_eq!(clock.next().filled(), 1);
+        assert_eq!(clock.back().filled(), 0);
+        assert_eq!(clock.next().next().back().filled(), 1);
+    }
+}
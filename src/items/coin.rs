@@ -0,0 +1,480 @@
+use std::fmt::Display;
+
+use crate::stats::Distribution;
+use crate::traits::{Coin, SideSelector};
+use crate::Error;
+
+/// How many positions are selected between to flip a [`BiasedCoin`], i.e. the precision of the bias.
+const RESOLUTION: usize = 1 << 30;
+
+/// A coin that lands on heads with a given probability, and otherwise on tails.
+///
+/// Events that happen with some probability are common in game logic (i.e. a 30% chance to hit),
+/// and a biased coin models them directly, instead of rolling a large die and comparing it to a
+/// threshold. A coin is flipped with any [`SideSelector`], where the bias has a precision of
+/// `2^-30`.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{BiasedCoin, RngRoller};
+///
+/// let coin = BiasedCoin::new(0.3);
+/// assert_eq!(coin.distribution().mean(), 0.3);
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let heads = (0..10_000).filter(|_| coin.flip(&roller).is_heads()).count();
+/// assert_eq!(heads, 2976);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "BiasedCoinRepr")
+)]
+pub struct BiasedCoin {
+    p_heads: f64,
+    heads: bool,
+}
+
+impl BiasedCoin {
+    /// Creates a coin that lands on heads with the probability `p_heads`, showing heads.
+    ///
+    /// # Panics
+    ///
+    /// If `p_heads` is not within `0.0..=1.0`; see [`BiasedCoin::try_new`] for a non-panicking
+    /// alternative.
+    pub fn new(p_heads: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&p_heads),
+            "probability must be within 0.0..=1.0"
+        );
+        Self {
+            p_heads,
+            heads: true,
+        }
+    }
+
+    /// Creates a coin that lands on heads with the probability `p_heads`, showing heads.
+    ///
+    /// # Errors
+    ///
+    /// If `p_heads` is not within `0.0..=1.0` (including `NaN`), returns
+    /// [`Error::InvalidProbability`].
+    pub fn try_new(p_heads: f64) -> Result<Self, Error> {
+        if !(0.0..=1.0).contains(&p_heads) {
+            return Err(Error::InvalidProbability);
+        }
+        Ok(Self::new(p_heads))
+    }
+
+    /// Creates a fair coin, showing heads.
+    pub const fn fair() -> Self {
+        Self {
+            p_heads: 0.5,
+            heads: true,
+        }
+    }
+
+    /// Returns the probability of landing on heads.
+    pub const fn p_heads(&self) -> f64 {
+        self.p_heads
+    }
+
+    /// Returns whether the coin shows heads.
+    pub const fn is_heads(&self) -> bool {
+        self.heads
+    }
+
+    /// Returns whether the coin shows tails.
+    pub const fn is_tails(&self) -> bool {
+        !self.heads
+    }
+
+    /// Returns a copy of the coin, showing heads or tails.
+    #[must_use]
+    pub const fn with_heads(mut self, heads: bool) -> Self {
+        self.heads = heads;
+        self
+    }
+
+    /// Returns a copy of the coin, flipped using the selector.
+    #[must_use]
+    pub fn flip<S>(&self, selector: &S) -> Self
+    where
+        S: SideSelector,
+    {
+        let mut coin = *self;
+        coin.flip_mut(selector);
+        coin
+    }
+
+    /// Flips the coin using the selector.
+    pub fn flip_mut<S>(&mut self, selector: &S)
+    where
+        S: SideSelector,
+    {
+        let threshold = (self.p_heads * RESOLUTION as f64).round() as usize;
+        self.heads = selector.select(RESOLUTION) < threshold;
+    }
+
+    /// Flips a copy of the coin `n` times using the selector, returning every outcome in order.
+    ///
+    /// The exact distribution of the number of heads is [`BiasedCoin::distribution_n`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastrand::Rng;
+    /// use tomb::items::{BiasedCoin, RngRoller};
+    ///
+    /// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+    /// let flips = BiasedCoin::fair().flip_n(4, &roller);
+    ///
+    /// assert_eq!(flips.outcomes(), &[true, false, true, false]);
+    /// assert_eq!(flips.heads(), 2);
+    /// ```
+    pub fn flip_n<S>(&self, n: usize, selector: &S) -> Flips
+    where
+        S: SideSelector,
+    {
+        let mut coin = *self;
+        let outcomes = (0..n)
+            .map(|_| {
+                coin.flip_mut(selector);
+                coin.heads
+            })
+            .collect();
+        Flips { outcomes }
+    }
+
+    /// Returns the exact distribution of a flip, where heads is `1` and tails is `0`.
+    pub fn distribution(&self) -> Distribution {
+        Distribution::bernoulli(self.p_heads)
+    }
+
+    /// Returns the exact distribution of how many of `n` flips land on heads.
+    pub fn distribution_n(&self, n: usize) -> Distribution {
+        Distribution::binomial(n, self.p_heads)
+    }
+}
+
+impl Coin for BiasedCoin {
+    fn is_heads(&self) -> bool {
+        self.heads
+    }
+
+    fn swap(&mut self) {
+        self.heads = !self.heads;
+    }
+
+    /// Flips the coin using the selector, landing on heads with [`BiasedCoin::p_heads`].
+    fn toss_mut<S>(&mut self, selector: &S)
+    where
+        S: SideSelector,
+    {
+        BiasedCoin::flip_mut(self, selector);
+    }
+}
+
+/// The serialized form of a [`BiasedCoin`], which is validated when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct BiasedCoinRepr {
+    p_heads: f64,
+    heads: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<BiasedCoinRepr> for BiasedCoin {
+    type Error = Error;
+
+    fn try_from(repr: BiasedCoinRepr) -> Result<Self, Self::Error> {
+        Ok(Self::try_new(repr.p_heads)?.with_heads(repr.heads))
+    }
+}
+
+impl Default for BiasedCoin {
+    /// Creates a fair coin, showing heads.
+    fn default() -> Self {
+        Self::fair()
+    }
+}
+
+impl Display for BiasedCoin {
+    /// Formats the coin as the side it shows, i.e. `heads`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(if self.heads { "heads" } else { "tails" })
+    }
+}
+
+/// The outcomes of flipping a coin several times; see [`BiasedCoin::flip_n`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Flips {
+    outcomes: Vec<bool>,
+}
+
+impl Flips {
+    /// Returns whether each flip landed on heads, in order.
+    pub fn outcomes(&self) -> &[bool] {
+        &self.outcomes
+    }
+
+    /// Returns how many flips were made.
+    pub fn len(&self) -> usize {
+        self.outcomes.len()
+    }
+
+    /// Returns whether no flips were made.
+    pub fn is_empty(&self) -> bool {
+        self.outcomes.is_empty()
+    }
+
+    /// Returns how many flips landed on heads.
+    pub fn heads(&self) -> usize {
+        self.outcomes.iter().filter(|&&heads| heads).count()
+    }
+
+    /// Returns how many flips landed on tails.
+    pub fn tails(&self) -> usize {
+        self.len() - self.heads()
+    }
+}
+
+/// The result of flipping a [`StreakCoin`], which side it landed on and the streak it is part of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StreakFlip {
+    heads: bool,
+    streak: usize,
+}
+
+impl StreakFlip {
+    /// Returns whether the coin landed on heads.
+    pub const fn is_heads(&self) -> bool {
+        self.heads
+    }
+
+    /// Returns how many flips in a row, including this one, landed on the same side.
+    pub const fn streak(&self) -> usize {
+        self.streak
+    }
+}
+
+/// Wraps a coin to track streaks, or how many flips in a row land on the same side.
+///
+/// Useful for push-your-luck games (i.e. flip until tails) and achievements (i.e. five heads in a
+/// row), where both the current streak and the longest streak of each side are tracked. Any
+/// [`Coin`] can be wrapped, i.e. a [`BiasedCoin`] or a two-sided die.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{BiasedCoin, SequenceRoller, StreakCoin};
+///
+/// // Heads, heads, heads, tails.
+/// let roller = SequenceRoller::new([0, 0, 0, usize::MAX]);
+/// let mut coin = StreakCoin::new(BiasedCoin::fair());
+///
+/// coin.flip(&roller);
+/// coin.flip(&roller);
+/// assert_eq!(coin.flip(&roller).streak(), 3);
+///
+/// let flip = coin.flip(&roller);
+/// assert!(!flip.is_heads());
+/// assert_eq!(flip.streak(), 1);
+/// assert_eq!(coin.longest_heads(), 3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StreakCoin<C = BiasedCoin> {
+    coin: C,
+    current: usize,
+    longest_heads: usize,
+    longest_tails: usize,
+}
+
+impl<C> StreakCoin<C>
+where
+    C: Coin,
+{
+    /// Wraps the coin, where no flips have been made yet.
+    pub const fn new(coin: C) -> Self {
+        Self {
+            coin,
+            current: 0,
+            longest_heads: 0,
+            longest_tails: 0,
+        }
+    }
+
+    /// Returns the wrapped coin, showing the side of the last flip.
+    pub const fn coin(&self) -> &C {
+        &self.coin
+    }
+
+    /// Returns how many flips in a row landed on the side the coin shows, or `0` before any flips.
+    pub const fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the most flips in a row that landed on heads.
+    pub const fn longest_heads(&self) -> usize {
+        self.longest_heads
+    }
+
+    /// Returns the most flips in a row that landed on tails.
+    pub const fn longest_tails(&self) -> usize {
+        self.longest_tails
+    }
+
+    /// Flips the coin using the selector, returning the side and the streak it is part of.
+    pub fn flip<S>(&mut self, selector: &S) -> StreakFlip
+    where
+        S: SideSelector,
+    {
+        let previous = self.coin.is_heads();
+        self.coin.toss_mut(selector);
+        let heads = self.coin.is_heads();
+        self.current = if self.current > 0 && heads == previous {
+            self.current + 1
+        } else {
+            1
+        };
+        let longest = if heads {
+            &mut self.longest_heads
+        } else {
+            &mut self.longest_tails
+        };
+        *longest = (*longest).max(self.current);
+        StreakFlip {
+            heads,
+            streak: self.current,
+        }
+    }
+
+    /// Forgets every streak, as if no flips have been made.
+    pub fn reset(&mut self) {
+        self.current = 0;
+        self.longest_heads = 0;
+        self.longest_tails = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::SequenceRoller;
+
+    #[test]
+    fn biased_coin_extremes() {
+        let roller = SequenceRoller::new([0, RESOLUTION - 1]);
+
+        for _ in 0..2 {
+            assert!(BiasedCoin::new(1.0).flip(&roller).is_heads());
+            assert!(BiasedCoin::new(0.0).flip(&roller).is_tails());
+        }
+    }
+
+    #[test]
+    fn biased_coin_threshold() {
+        let mut coin = BiasedCoin::new(0.25);
+        coin.flip_mut(&SequenceRoller::new([RESOLUTION / 4 - 1]));
+        assert!(coin.is_heads());
+
+        coin.flip_mut(&SequenceRoller::new([RESOLUTION / 4]));
+        assert!(coin.is_tails());
+        assert_eq!(coin.to_string(), "tails");
+    }
+
+    #[test]
+    fn biased_coin_fair() {
+        let coin = BiasedCoin::default();
+
+        assert_eq!(coin, BiasedCoin::new(0.5));
+        assert_eq!(coin.with_heads(false).to_string(), "tails");
+        assert_eq!(coin.distribution().variance(), 0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = "probability must be within 0.0..=1.0")]
+    fn biased_coin_out_of_range() {
+        BiasedCoin::new(-0.1);
+    }
+
+    #[test]
+    fn biased_coin_try_new() {
+        assert_eq!(BiasedCoin::try_new(0.3), Ok(BiasedCoin::new(0.3)));
+        for p_heads in [-0.1, 1.5, f64::NAN, f64::INFINITY] {
+            assert_eq!(BiasedCoin::try_new(p_heads), Err(Error::InvalidProbability));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn biased_coin_serde() {
+        let coin = BiasedCoin::new(0.3).with_heads(false);
+        let json = serde_json::to_string(&coin).unwrap();
+        assert_eq!(serde_json::from_str::<BiasedCoin>(&json).unwrap(), coin);
+
+        for json in [
+            r#"{"p_heads":2.0,"heads":true}"#,
+            r#"{"p_heads":-0.5,"heads":true}"#,
+            r#"{"p_heads":null,"heads":true}"#,
+        ] {
+            assert!(serde_json::from_str::<BiasedCoin>(json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    fn streak_coin_first_flip_tails() {
+        let roller = SequenceRoller::new([usize::MAX, usize::MAX, 0]);
+        let mut coin = StreakCoin::new(BiasedCoin::fair());
+
+        assert_eq!(coin.flip(&roller).streak(), 1);
+        assert_eq!(coin.flip(&roller).streak(), 2);
+        assert!(coin.flip(&roller).is_heads());
+        assert_eq!(coin.current(), 1);
+        assert_eq!((coin.longest_heads(), coin.longest_tails()), (1, 2));
+    }
+
+    #[test]
+    fn streak_coin_reset() {
+        let mut coin = StreakCoin::new(BiasedCoin::new(1.0));
+        coin.flip(&SequenceRoller::new([0]));
+        coin.reset();
+
+        assert_eq!(coin.current(), 0);
+        assert_eq!(coin.longest_heads(), 0);
+        assert!(coin.coin().is_heads());
+    }
+
+    #[test]
+    fn biased_coin_flip_n() {
+        let roller = SequenceRoller::new([0, usize::MAX, usize::MAX]);
+        let flips = BiasedCoin::fair().flip_n(5, &roller);
+
+        assert_eq!(flips.len(), 5);
+        assert_eq!((flips.heads(), flips.tails()), (2, 3));
+        assert!(BiasedCoin::fair().flip_n(0, &roller).is_empty());
+        assert!((BiasedCoin::new(0.2).distribution_n(5).mean() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn streak_coin_two_sided_die() {
+        let mut coin = StreakCoin::new(crate::items::NumericDie::<u8, 2>::new());
+        let roller = SequenceRoller::new([1, 1]);
+        coin.flip(&roller);
+
+        assert_eq!(coin.flip(&roller).streak(), 2);
+        assert_eq!(coin.longest_tails(), 2);
+        assert_eq!(coin.coin().value(), 2);
+    }
+
+    #[test]
+    fn biased_coin_swap() {
+        let mut coin = BiasedCoin::fair();
+        coin.swap();
+
+        assert!(Coin::is_tails(&coin));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::traits::Critical;
+
+/// Configurable thresholds for what is considered a critical success or a critical failure.
+///
+/// By default, only the highest face is a critical and only the lowest face is a fumble, which is
+/// identical to the default behavior of [`Critical`]. Wider ranges are useful for rules such as an
+/// _improved critical_, where a D20 is a critical on either a `19` or a `20`.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{Criticals, D20};
+/// use tomb::traits::Critical;
+///
+/// let criticals = Criticals::new().with_critical_range(2);
+/// let d20 = D20::from(19);
+///
+/// assert!(!d20.is_critical());
+/// assert!(criticals.is_critical(&d20));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Criticals {
+    critical: usize,
+    fumble: usize,
+}
+
+impl Criticals {
+    /// Creates thresholds where only the highest face is a critical and the lowest a fumble.
+    pub const fn new() -> Self {
+        Self {
+            critical: 1,
+            fumble: 1,
+        }
+    }
+
+    /// Returns thresholds where the highest `faces` faces are considered a critical.
+    pub const fn with_critical_range(self, faces: usize) -> Self {
+        Self {
+            critical: faces,
+            fumble: self.fumble,
+        }
+    }
+
+    /// Returns thresholds where the lowest `faces` faces are considered a fumble.
+    pub const fn with_fumble_range(self, faces: usize) -> Self {
+        Self {
+            critical: self.critical,
+            fumble: faces,
+        }
+    }
+
+    /// Returns how many of the highest faces are considered a critical.
+    pub const fn critical_range(&self) -> usize {
+        self.critical
+    }
+
+    /// Returns how many of the lowest faces are considered a fumble.
+    pub const fn fumble_range(&self) -> usize {
+        self.fumble
+    }
+
+    /// Returns whether the given element is showing a critical success.
+    pub fn is_critical<T>(&self, critical: &T) -> bool
+    where
+        T: Critical,
+    {
+        critical.natural() + self.critical > T::sides()
+    }
+
+    /// Returns whether the given element is showing a critical failure.
+    pub fn is_fumble<T>(&self, critical: &T) -> bool
+    where
+        T: Critical,
+    {
+        critical.natural() <= self.fumble
+    }
+}
+
+impl Default for Criticals {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::D20;
+
+    #[test]
+    fn criticals_default() {
+        let criticals: Criticals = Default::default();
+
+        assert_eq!(criticals, Criticals::new());
+        assert_eq!(criticals.critical_range(), 1);
+        assert_eq!(criticals.fumble_range(), 1);
+    }
+
+    #[test]
+    fn criticals_natural() {
+        let criticals = Criticals::new();
+
+        assert!(criticals.is_critical(&D20::from(20)));
+        assert!(!criticals.is_critical(&D20::from(19)));
+        assert!(criticals.is_fumble(&D20::from(1)));
+        assert!(!criticals.is_fumble(&D20::from(2)));
+    }
+
+    #[test]
+    fn criticals_critical_range() {
+        let criticals = Criticals::new().with_critical_range(2);
+
+        assert!(criticals.is_critical(&D20::from(20)));
+        assert!(criticals.is_critical(&D20::from(19)));
+        assert!(!criticals.is_critical(&D20::from(18)));
+    }
+
+    #[test]
+    fn criticals_fumble_range() {
+        let criticals = Criticals::new().with_fumble_range(2);
+
+        assert!(criticals.is_fumble(&D20::from(1)));
+        assert!(criticals.is_fumble(&D20::from(2)));
+        assert!(!criticals.is_fumble(&D20::from(3)));
+    }
+
+    #[test]
+    fn criticals_empty_range() {
+        let criticals = Criticals::new().with_critical_range(0).with_fumble_range(0);
+
+        assert!(!criticals.is_critical(&D20::from(20)));
+        assert!(!criticals.is_fumble(&D20::from(1)));
+    }
+}
@@ -1,6 +1,32 @@
 //! ...
 
+mod array;
+mod ascii;
+mod assets;
+mod bounded;
+mod composite;
+mod custom;
+mod markdown;
 mod numeric;
+mod oriented;
+mod pool;
+mod shared;
 mod slice;
+mod standard;
+mod step;
+mod sticker;
+pub use array::*;
+pub use ascii::*;
+pub use assets::*;
+pub use bounded::*;
+pub use composite::*;
+pub use custom::*;
+pub use markdown::*;
 pub use numeric::*;
+pub use oriented::*;
+pub use pool::*;
+pub use shared::*;
 pub use slice::*;
+pub use standard::*;
+pub use step::*;
+pub use sticker::*;
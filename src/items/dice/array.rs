@@ -0,0 +1,244 @@
+use std::marker::PhantomData;
+
+use crate::traits::{Critical, Positional, SideSelector, Snapshot};
+
+/// Stores many dice of the same type compactly, as a packed array of positions.
+///
+/// Instead of storing each die, only the position of each die's current face is stored, using a
+/// single byte per die, which is cheap to store, copy, and scan in bulk. Dice are created on
+/// demand when read, so reading requires the die type to implement [`Default`].
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{DiceArray, RngRoller, D6};
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let mut dice = DiceArray::<D6>::new(1000);
+/// dice.roll_all(&roller);
+///
+/// assert_eq!(dice.len(), 1000);
+/// assert_eq!(dice.count(&6), 158);
+/// assert_eq!(dice.total(), 3593);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DiceArray<D> {
+    positions: Vec<u8>,
+    die: PhantomData<D>,
+}
+
+impl<D> DiceArray<D>
+where
+    D: Positional,
+{
+    /// Creates an array of `len` dice, each showing the lowest face.
+    ///
+    /// # Panics
+    ///
+    /// If the die has more than 256 sides.
+    pub fn new(len: usize) -> Self {
+        assert!(D::sides() <= 256);
+        Self {
+            positions: vec![0; len],
+            die: PhantomData,
+        }
+    }
+
+    /// Returns how many dice are stored.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns whether no dice are stored.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Returns the position of the current face of every die, in order.
+    pub fn positions(&self) -> &[u8] {
+        &self.positions
+    }
+
+    /// Adds a die to the end of the array.
+    pub fn push(&mut self, die: &D) {
+        self.positions.push(die.position() as u8);
+    }
+
+    /// Changes the die at the given index to show the same face as `die`.
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn set(&mut self, index: usize, die: &D) {
+        self.positions[index] = die.position() as u8;
+    }
+
+    /// Returns the die at the given index, or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<D>
+    where
+        D: Default,
+    {
+        self.positions.get(index).map(|&p| Self::die(p))
+    }
+
+    /// Returns an iterator over every die, in order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = D> + '_
+    where
+        D: Default,
+    {
+        self.positions.iter().map(|&p| Self::die(p))
+    }
+
+    /// Changes every die to show a face at a selected position.
+    pub fn roll_all<S>(&mut self, selector: &S)
+    where
+        S: SideSelector,
+    {
+        for position in &mut self.positions {
+            *position = selector.select(D::sides()) as u8;
+        }
+    }
+
+    /// Returns how many dice show the given value.
+    pub fn count(&self, value: &D::Face) -> usize
+    where
+        D: Default,
+        D::Face: PartialEq,
+    {
+        let matches: Vec<bool> = (0..D::sides())
+            .map(|p| Self::die(p as u8).value() == *value)
+            .collect();
+        self.positions
+            .iter()
+            .filter(|&&p| matches[p as usize])
+            .count()
+    }
+
+    /// Returns the sum of the natural faces (see [`Critical::natural`]) of every die.
+    pub fn total(&self) -> usize
+    where
+        D: Critical + Default,
+    {
+        let naturals: Vec<usize> = (0..D::sides())
+            .map(|p| Self::die(p as u8).natural())
+            .collect();
+        self.positions.iter().map(|&p| naturals[p as usize]).sum()
+    }
+
+    /// Returns a die showing the face at the given position.
+    fn die(position: u8) -> D
+    where
+        D: Default,
+    {
+        let mut die = D::default();
+        die.set_position(position as usize);
+        die
+    }
+}
+
+impl<D> Extend<D> for DiceArray<D>
+where
+    D: Positional,
+{
+    fn extend<I: IntoIterator<Item = D>>(&mut self, iter: I) {
+        for die in iter {
+            self.push(&die);
+        }
+    }
+}
+
+impl<D> FromIterator<D> for DiceArray<D>
+where
+    D: Positional,
+{
+    fn from_iter<I: IntoIterator<Item = D>>(iter: I) -> Self {
+        let mut array = Self::new(0);
+        array.extend(iter);
+        array
+    }
+}
+
+impl<D> Snapshot for DiceArray<D>
+where
+    D: Positional,
+{
+    type Memento = Vec<u8>;
+
+    fn snapshot(&self) -> Vec<u8> {
+        self.positions.clone()
+    }
+
+    fn restore(&mut self, memento: &Vec<u8>) {
+        assert_eq!(self.len(), memento.len(), "snapshot has a different length");
+        self.positions.copy_from_slice(memento);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{NumericDie, D20, D6};
+
+    struct Fixed(usize);
+
+    impl SideSelector for Fixed {
+        fn select(&self, _sides: usize) -> usize {
+            self.0
+        }
+    }
+
+    #[test]
+    fn dice_array_new() {
+        let dice = DiceArray::<D20>::new(3);
+
+        assert_eq!(dice.len(), 3);
+        assert_eq!(dice.positions(), &[0, 0, 0]);
+        assert_eq!(dice.iter().collect::<Vec<_>>(), vec![D20::new(); 3]);
+    }
+
+    #[test]
+    fn dice_array_collect() {
+        let mut dice: DiceArray<D6> = [D6::from(2), D6::from(6)].into_iter().collect();
+        dice.set(0, &D6::from(3));
+
+        assert_eq!(dice.get(0), Some(D6::from(3)));
+        assert_eq!(dice.get(1), Some(D6::from(6)));
+        assert_eq!(dice.get(2), None);
+        assert_eq!(dice.total(), 9);
+        assert_eq!(dice.count(&6), 1);
+    }
+
+    #[test]
+    fn dice_array_roll_all() {
+        let mut dice = DiceArray::<D6>::new(4);
+        dice.roll_all(&Fixed(4));
+
+        assert_eq!(dice.count(&5), 4);
+        assert_eq!(dice.total(), 20);
+    }
+
+    #[test]
+    fn dice_array_empty() {
+        let dice = DiceArray::<D6>::new(0);
+
+        assert!(dice.is_empty());
+        assert_eq!(dice.total(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dice_array_too_many_sides() {
+        DiceArray::<NumericDie<u16, 257>>::new(1);
+    }
+
+    #[test]
+    fn dice_array_snapshot() {
+        let mut dice = DiceArray::<D6>::new(2);
+        let saved = dice.snapshot();
+        dice.roll_all(&Fixed(5));
+        dice.restore(&saved);
+
+        assert_eq!(dice.total(), 2);
+    }
+}
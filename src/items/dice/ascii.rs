@@ -0,0 +1,186 @@
+use std::fmt::Display;
+
+use crate::items::{NumericDie, SliceDie};
+use crate::traits::{AsciiArt, Numeric};
+
+/// Draws a pool (a slice or array) of dice as ASCII art, side by side.
+///
+/// Shorter art is padded at the bottom, so every die lines up at the top.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{AsciiTray, D20, D6};
+///
+/// let tray = [D6::from(3), D6::from(6)];
+///
+/// assert_eq!(
+///     AsciiTray::new(&tray).to_string(),
+///     concat!(
+///         "+-------+ +-------+\n",
+///         "| o     | | o   o |\n",
+///         "|   o   | | o   o |\n",
+///         "|     o | | o   o |\n",
+///         "+-------+ +-------+\n",
+///     ),
+/// );
+///
+/// assert_eq!(
+///     AsciiTray::new(&[D20::from(17)]).to_string(),
+///     "+----+\n|    |\n| 17 |\n|    |\n+----+\n",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct AsciiTray<'a, D> {
+    dice: &'a [D],
+}
+
+impl<'a, D> AsciiTray<'a, D>
+where
+    D: AsciiArt,
+{
+    /// Creates a renderer for the given pool of dice.
+    pub const fn new(dice: &'a [D]) -> Self {
+        Self { dice }
+    }
+}
+
+impl<D> Display for AsciiTray<'_, D>
+where
+    D: AsciiArt,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arts: Vec<Vec<String>> = self.dice.iter().map(AsciiArt::ascii_art).collect();
+        let height = arts.iter().map(Vec::len).max().unwrap_or_default();
+        for row in 0..height {
+            let mut line = String::new();
+            for (i, art) in arts.iter().enumerate() {
+                if i > 0 {
+                    line.push(' ');
+                }
+                let width = art.first().map_or(0, |l| l.chars().count());
+                match art.get(row) {
+                    Some(text) => line.push_str(text),
+                    None => line.push_str(&" ".repeat(width)),
+                }
+            }
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the text centered in a box, with a blank line above and below.
+fn boxed(text: &str, width: usize) -> Vec<String> {
+    let border = format!("+{}+", "-".repeat(width + 2));
+    let blank = format!("|{}|", " ".repeat(width + 2));
+    vec![
+        border.clone(),
+        blank.clone(),
+        format!("| {text:^width$} |"),
+        blank,
+        border,
+    ]
+}
+
+impl<T, const MAXIMUM: usize> AsciiArt for NumericDie<T, MAXIMUM>
+where
+    T: Numeric,
+{
+    /// Draws pips for six-sided dice, and otherwise the value centered in a box.
+    fn ascii_art(&self) -> Vec<String> {
+        let value = self.value().as_usize();
+        if MAXIMUM != 6 {
+            return boxed(&value.to_string(), MAXIMUM.to_string().len());
+        }
+        // Pips at the top-left, top-right, middle-left, center, middle-right, bottom-left, and
+        // bottom-right of the face.
+        let pips: [bool; 7] = match value {
+            1 => [false, false, false, true, false, false, false],
+            2 => [true, false, false, false, false, false, true],
+            3 => [true, false, false, true, false, false, true],
+            4 => [true, true, false, false, false, true, true],
+            5 => [true, true, false, true, false, true, true],
+            _ => [true, true, true, false, true, true, true],
+        };
+        let pip = |on: bool| if on { 'o' } else { ' ' };
+        let border = "+-------+".to_string();
+        vec![
+            border.clone(),
+            format!("| {}   {} |", pip(pips[0]), pip(pips[1])),
+            format!("| {} {} {} |", pip(pips[2]), pip(pips[3]), pip(pips[4])),
+            format!("| {}   {} |", pip(pips[5]), pip(pips[6])),
+            border,
+        ]
+    }
+}
+
+impl<T, const MAXIMUM: usize> AsciiArt for SliceDie<'_, T, MAXIMUM>
+where
+    T: Display,
+{
+    /// Draws the value centered in a box as wide as the widest face.
+    fn ascii_art(&self) -> Vec<String> {
+        let width = self
+            .into_iter()
+            .map(|face| face.to_string().chars().count())
+            .max()
+            .unwrap_or_default();
+        boxed(&self.value().to_string(), width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{D4, D6};
+
+    #[test]
+    fn ascii_d6_pips() {
+        assert_eq!(
+            D6::from(1).ascii_art()[1..4],
+            ["|       |", "|   o   |", "|       |"]
+        );
+        assert_eq!(
+            D6::from(5).ascii_art()[1..4],
+            ["| o   o |", "|   o   |", "| o   o |"]
+        );
+    }
+
+    #[test]
+    fn ascii_slice_die() {
+        let die = SliceDie::new(&["hit", "miss"]);
+
+        assert_eq!(die.ascii_art()[2], "| hit  |");
+    }
+
+    #[test]
+    fn ascii_tray_mixed_heights() {
+        struct Dot;
+
+        impl AsciiArt for Dot {
+            fn ascii_art(&self) -> Vec<String> {
+                vec![".".into()]
+            }
+        }
+
+        struct Bar;
+
+        impl AsciiArt for Bar {
+            fn ascii_art(&self) -> Vec<String> {
+                vec!["|".into(), "|".into()]
+            }
+        }
+
+        assert_eq!(AsciiTray::new(&[Dot]).to_string(), ".\n");
+        let tray: [&dyn AsciiArt; 2] = [&Dot, &Bar];
+        assert_eq!(AsciiTray::new(&tray).to_string(), ". |\n  |\n");
+    }
+
+    #[test]
+    fn ascii_tray_empty() {
+        let tray: [D4; 0] = [];
+
+        assert_eq!(AsciiTray::new(&tray).to_string(), "");
+    }
+}
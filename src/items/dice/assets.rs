@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use crate::traits::{Critical, Polyhedral, Positional, Rotate, RotateMut, Snapshot, Step, StepMut};
+
+/// Wraps a die (or any [`Positional`] element) to associate every face with an opaque asset.
+///
+/// An asset is anything a game engine uses to present a face, i.e. a sprite handle or a sound id,
+/// and is retrieved alongside the value, so rendering a roll doesn't need a lookup keyed by face.
+/// Assets are shared by every clone (and roll) of the wrapper, so they are never copied.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{AssetDie, RngRoller, D4};
+/// use tomb::traits::Roll;
+///
+/// let die = AssetDie::new(D4::new(), ["one.png", "two.png", "three.png", "four.png"]);
+/// assert_eq!(die.value_and_asset(), (1, &"one.png"));
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let rolled = roller.roll(&die);
+/// assert_eq!(rolled.value_and_asset(), (2, &"two.png"));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct AssetDie<D, A> {
+    die: D,
+    assets: Arc<[A]>,
+}
+
+impl<D, A> Clone for AssetDie<D, A>
+where
+    D: Clone,
+{
+    /// Clones the die, which only shares its assets, so the assets do not need to be [`Clone`].
+    fn clone(&self) -> Self {
+        Self {
+            die: self.die.clone(),
+            assets: Arc::clone(&self.assets),
+        }
+    }
+}
+
+impl<D, A> AssetDie<D, A>
+where
+    D: Positional,
+{
+    /// Creates a wrapper around the die, where each asset is associated with the face at the same
+    /// position.
+    ///
+    /// # Panics
+    ///
+    /// If the number of assets is not the number of sides of the die.
+    pub fn new(die: D, assets: impl IntoIterator<Item = A>) -> Self {
+        Self::with_shared(die, assets.into_iter().collect())
+    }
+
+    /// Creates a wrapper around the die, sharing assets with other wrappers.
+    ///
+    /// # Panics
+    ///
+    /// If the number of assets is not the number of sides of the die.
+    pub fn with_shared(die: D, assets: Arc<[A]>) -> Self {
+        assert_eq!(
+            assets.len(),
+            D::sides(),
+            "every face requires exactly one asset"
+        );
+        Self { die, assets }
+    }
+
+    /// Returns a reference to the wrapped die.
+    pub const fn die(&self) -> &D {
+        &self.die
+    }
+
+    /// Returns the wrapped die, discarding the assets.
+    pub fn into_inner(self) -> D {
+        self.die
+    }
+
+    /// Returns the assets, in order of position.
+    pub fn assets(&self) -> &Arc<[A]> {
+        &self.assets
+    }
+
+    /// Returns the asset associated with the current face.
+    pub fn asset(&self) -> &A {
+        &self.assets[self.die.position()]
+    }
+
+    /// Returns the value of the current face and its associated asset.
+    pub fn value_and_asset(&self) -> (D::Face, &A) {
+        (self.die.value(), self.asset())
+    }
+}
+
+impl<D, A> Polyhedral for AssetDie<D, A>
+where
+    D: Positional,
+{
+    type Face = D::Face;
+
+    fn sides() -> usize {
+        D::sides()
+    }
+
+    fn value(&self) -> D::Face {
+        self.die.value()
+    }
+}
+
+impl<D, A> Positional for AssetDie<D, A>
+where
+    D: Positional,
+{
+    fn position(&self) -> usize {
+        self.die.position()
+    }
+
+    fn set_position(&mut self, position: usize) {
+        self.die.set_position(position);
+    }
+}
+
+impl<D, A> Snapshot for AssetDie<D, A>
+where
+    D: Snapshot,
+{
+    type Memento = D::Memento;
+
+    fn snapshot(&self) -> D::Memento {
+        self.die.snapshot()
+    }
+
+    fn restore(&mut self, memento: &D::Memento) {
+        self.die.restore(memento);
+    }
+}
+
+impl<D, A> Critical for AssetDie<D, A>
+where
+    D: Positional + Critical,
+{
+    fn natural(&self) -> usize {
+        self.die.natural()
+    }
+}
+
+impl<D, A> Step for AssetDie<D, A>
+where
+    D: Step,
+{
+    fn next(&self) -> Self {
+        Self {
+            die: self.die.next(),
+            assets: Arc::clone(&self.assets),
+        }
+    }
+
+    fn back(&self) -> Self {
+        Self {
+            die: self.die.back(),
+            assets: Arc::clone(&self.assets),
+        }
+    }
+}
+
+impl<D, A> StepMut for AssetDie<D, A>
+where
+    D: StepMut,
+{
+    fn next_mut(&mut self) {
+        self.die.next_mut();
+    }
+
+    fn back_mut(&mut self) {
+        self.die.back_mut();
+    }
+}
+
+impl<D, A> Rotate for AssetDie<D, A>
+where
+    D: Rotate,
+{
+    fn rotate(&self, amount: isize) -> Self {
+        Self {
+            die: self.die.rotate(amount),
+            assets: Arc::clone(&self.assets),
+        }
+    }
+}
+
+impl<D, A> RotateMut for AssetDie<D, A>
+where
+    D: RotateMut,
+{
+    fn rotate_mut(&mut self, amount: isize) {
+        self.die.rotate_mut(amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{D4, D6};
+
+    #[derive(Debug, PartialEq)]
+    struct Sprite(u32);
+
+    #[test]
+    fn asset_die_follows_rotation() {
+        let mut die = AssetDie::new(D4::new(), (10..14).map(Sprite));
+        die.rotate_mut(-1);
+
+        assert_eq!(die.value_and_asset(), (4, &Sprite(13)));
+        assert_eq!(die.next().asset(), &Sprite(10));
+        assert_eq!(die.back().rotate(3).asset(), &Sprite(11));
+    }
+
+    #[test]
+    fn asset_die_shares_assets() {
+        let assets: Arc<[&str]> = Arc::from(["a", "b", "c", "d", "e", "f"]);
+        let a = AssetDie::with_shared(D6::from(2), Arc::clone(&assets));
+        let b = a.clone();
+
+        assert!(Arc::ptr_eq(a.assets(), b.assets()));
+        assert_eq!(b.asset(), &"b");
+        assert_eq!(b.into_inner(), D6::from(2));
+    }
+
+    #[test]
+    fn asset_die_set_position() {
+        let mut die = AssetDie::new(D6::new(), 1..=6);
+        die.set_position(5);
+
+        assert_eq!(die.asset(), &6);
+        assert!(die.is_critical());
+        assert_eq!(die.die().value(), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "every face requires exactly one asset")]
+    fn asset_die_wrong_count() {
+        let _ = AssetDie::new(D6::new(), ["a", "b"]);
+    }
+}
@@ -0,0 +1,297 @@
+use crate::traits::{Critical, Polyhedral, Positional, Rotate, RotateMut, Snapshot, Step, StepMut};
+
+/// What happens when a [`Bounded`] element is rotated past its first or last face.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RotationMode {
+    /// Continues from the opposite end, i.e. the highest face steps forward to the lowest face.
+    #[default]
+    Wrap,
+
+    /// Stops at the end, and extra rotation is lost.
+    Saturate,
+
+    /// Reverses direction at the end, i.e. a marker that moves back and forth along a track.
+    Bounce,
+}
+
+/// Wraps a die (or any [`Positional`] element) to rotate with a configurable [`RotationMode`].
+///
+/// Rotating (or stepping) the wrapper forwards moves towards the highest face, and backwards
+/// towards the lowest face, and what happens at either end depends on the mode.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{Bounded, RotationMode, D6};
+/// use tomb::traits::{Rotate, RotateMut};
+///
+/// let saturate = Bounded::new(D6::from(5), RotationMode::Saturate);
+/// assert_eq!(saturate.rotate(3).die().value(), 6);
+///
+/// let mut bounce = Bounded::new(D6::from(5), RotationMode::Bounce);
+/// bounce.rotate_mut(3);
+/// assert_eq!(bounce.die().value(), 4);
+/// assert!(bounce.is_reversed());
+///
+/// // Now moving backwards, towards the lowest face.
+/// bounce.rotate_mut(1);
+/// assert_eq!(bounce.die().value(), 3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounded<D> {
+    die: D,
+    mode: RotationMode,
+    reversed: bool,
+}
+
+impl<D> Bounded<D>
+where
+    D: Positional,
+{
+    /// Creates a wrapper around the die with the given rotation mode.
+    pub const fn new(die: D, mode: RotationMode) -> Self {
+        Self {
+            die,
+            mode,
+            reversed: false,
+        }
+    }
+
+    /// Returns a reference to the wrapped die.
+    pub const fn die(&self) -> &D {
+        &self.die
+    }
+
+    /// Returns the wrapped die.
+    pub fn into_inner(self) -> D {
+        self.die
+    }
+
+    /// Returns the rotation mode.
+    pub const fn mode(&self) -> RotationMode {
+        self.mode
+    }
+
+    /// Returns whether forwards currently moves towards the lowest face, after a [bounce].
+    ///
+    /// [bounce]: RotationMode::Bounce
+    pub const fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Moves by the signed amount, returning the new position and whether the direction is reversed.
+    fn moved(&self, amount: isize) -> (usize, bool) {
+        let sides = D::sides() as i128;
+        let position = self.die.position() as i128;
+        let amount = amount as i128;
+        match self.mode {
+            RotationMode::Wrap => ((position + amount).rem_euclid(sides) as usize, false),
+            RotationMode::Saturate => ((position + amount).clamp(0, sides - 1) as usize, false),
+            RotationMode::Bounce if sides == 1 => (0, self.reversed),
+            RotationMode::Bounce => {
+                // Unfolds the track into a cycle, where the second half is the way back.
+                let cycle = 2 * (sides - 1);
+                let unfolded = if self.reversed {
+                    cycle - position
+                } else {
+                    position
+                };
+                let unfolded = (unfolded + amount).rem_euclid(cycle);
+                if unfolded < sides - 1 || (unfolded == sides - 1 && amount > 0) {
+                    (unfolded as usize, unfolded == sides - 1)
+                } else {
+                    ((cycle - unfolded) as usize, unfolded != 0)
+                }
+            }
+        }
+    }
+}
+
+impl<D> Polyhedral for Bounded<D>
+where
+    D: Positional,
+{
+    type Face = D::Face;
+
+    fn sides() -> usize {
+        D::sides()
+    }
+
+    fn value(&self) -> D::Face {
+        self.die.value()
+    }
+}
+
+impl<D> Positional for Bounded<D>
+where
+    D: Positional,
+{
+    fn position(&self) -> usize {
+        self.die.position()
+    }
+
+    /// Changes the current face to the face at the given position, and resets the direction.
+    fn set_position(&mut self, position: usize) {
+        self.die.set_position(position);
+        self.reversed = false;
+    }
+}
+
+impl<D> Snapshot for Bounded<D>
+where
+    D: Snapshot,
+{
+    /// The snapshot of the wrapped die, and whether the direction is reversed.
+    type Memento = (D::Memento, bool);
+
+    fn snapshot(&self) -> Self::Memento {
+        (self.die.snapshot(), self.reversed)
+    }
+
+    fn restore(&mut self, (die, reversed): &Self::Memento) {
+        self.die.restore(die);
+        self.reversed = *reversed;
+    }
+}
+
+impl<D> Critical for Bounded<D>
+where
+    D: Positional + Critical,
+{
+    fn natural(&self) -> usize {
+        self.die.natural()
+    }
+}
+
+impl<D> Step for Bounded<D>
+where
+    D: Positional + Clone,
+{
+    fn next(&self) -> Self {
+        self.rotate(1)
+    }
+
+    fn back(&self) -> Self {
+        self.rotate(-1)
+    }
+}
+
+impl<D> StepMut for Bounded<D>
+where
+    D: Positional,
+{
+    fn next_mut(&mut self) {
+        self.rotate_mut(1);
+    }
+
+    fn back_mut(&mut self) {
+        self.rotate_mut(-1);
+    }
+}
+
+impl<D> Rotate for Bounded<D>
+where
+    D: Positional + Clone,
+{
+    fn rotate(&self, amount: isize) -> Self {
+        let mut next = self.clone();
+        next.rotate_mut(amount);
+        next
+    }
+}
+
+impl<D> RotateMut for Bounded<D>
+where
+    D: Positional,
+{
+    fn rotate_mut(&mut self, amount: isize) {
+        let (position, reversed) = self.moved(amount);
+        self.die.set_position(position);
+        self.reversed = reversed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{D4, D6};
+
+    fn values(mut bounded: Bounded<D4>, steps: usize) -> Vec<u8> {
+        (0..steps)
+            .map(|_| {
+                bounded.next_mut();
+                bounded.die().value()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bounded_wrap() {
+        let bounded = Bounded::new(D4::new(), RotationMode::Wrap);
+
+        assert_eq!(values(bounded, 6), vec![2, 3, 4, 1, 2, 3]);
+        assert_eq!(bounded.rotate(-1).die().value(), 4);
+    }
+
+    #[test]
+    fn bounded_saturate() {
+        let bounded = Bounded::new(D4::new(), RotationMode::Saturate);
+
+        assert_eq!(values(bounded, 5), vec![2, 3, 4, 4, 4]);
+        assert_eq!(bounded.rotate(-100).die().value(), 1);
+    }
+
+    #[test]
+    fn bounded_bounce() {
+        let bounded = Bounded::new(D4::new(), RotationMode::Bounce);
+
+        assert_eq!(values(bounded, 8), vec![2, 3, 4, 3, 2, 1, 2, 3]);
+    }
+
+    #[test]
+    fn bounded_bounce_large() {
+        let bounded = Bounded::new(D4::new(), RotationMode::Bounce);
+
+        // Each round trip on a D4 is 6 steps.
+        assert_eq!(bounded.rotate(6 * 1000 + 4).die().value(), 3);
+        assert!(bounded.rotate(6 * 1000 + 4).is_reversed());
+    }
+
+    #[test]
+    fn bounded_bounce_backwards() {
+        let mut bounded = Bounded::new(D4::new(), RotationMode::Bounce);
+        bounded.back_mut();
+
+        assert_eq!(bounded.die().value(), 2);
+        assert!(bounded.is_reversed());
+
+        bounded.back_mut();
+        assert_eq!(bounded.die().value(), 3);
+    }
+
+    #[test]
+    fn bounded_set_position_resets_direction() {
+        let mut bounded = Bounded::new(D6::from(6), RotationMode::Bounce);
+        bounded.next_mut();
+        assert!(bounded.is_reversed());
+
+        bounded.set_position(0);
+        assert!(!bounded.is_reversed());
+        assert_eq!(bounded.natural(), 1);
+    }
+
+    #[test]
+    fn bounded_snapshot_keeps_direction() {
+        let mut bounded = Bounded::new(D6::from(6), RotationMode::Bounce);
+        bounded.next_mut();
+        let saved = bounded.snapshot();
+
+        bounded.set_position(0);
+        bounded.restore(&saved);
+        assert_eq!(bounded.die().value(), 5);
+        assert!(bounded.is_reversed());
+
+        bounded.next_mut();
+        assert_eq!(bounded.die().value(), 4);
+    }
+}
@@ -0,0 +1,442 @@
+use std::fmt::{Debug, Display};
+
+use super::NumericDie;
+use crate::traits::{Critical, Polyhedral, Positional, Rotate, RotateMut, Snapshot, Step, StepMut};
+
+/// A die that rolls two identical dice as a _tens_ and a _units_ digit, i.e. a `d66`.
+///
+/// Composite dice are rolled as a single die with `SIDES * SIDES` possible outcomes, and are
+/// commonly used for oracle and random tables, for example in _Traveller_.
+///
+/// # Examples
+///
+/// ```
+/// # use tomb::items::D66;
+/// # use tomb::traits::Rotate;
+/// let die = D66::new().rotate(8);
+/// assert_eq!(die.value(), 23);
+/// assert_eq!(die.tens(), 2);
+/// assert_eq!(die.units(), 3);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "CompositeDieRepr<SIDES>")
+)]
+pub struct CompositeDie<const SIDES: usize> {
+    tens: NumericDie<u8, SIDES>,
+    units: NumericDie<u8, SIDES>,
+}
+
+/// A conveniently provided composite die of two 6-sided dice, between `11..=66`.
+///
+/// # Examples
+///
+/// ```
+/// # use tomb::items::D66;
+/// # use tomb::traits::Rotate;
+/// let die = D66::new().rotate(35);
+/// assert_eq!(die.value(), 66);
+/// ```
+pub type D66 = CompositeDie<6>;
+
+/// A conveniently provided composite die of two 8-sided dice, between `11..=88`.
+///
+/// # Examples
+///
+/// ```
+/// # use tomb::items::D88;
+/// # use tomb::traits::Rotate;
+/// let die = D88::new().rotate(63);
+/// assert_eq!(die.value(), 88);
+/// ```
+pub type D88 = CompositeDie<8>;
+
+impl<const SIDES: usize> CompositeDie<SIDES> {
+    /// Creates a new die where both digits start at `1`.
+    ///
+    /// # Panics
+    ///
+    /// If either die would have more than `9` sides, and could no longer represent a digit.
+    pub fn new() -> Self {
+        assert!(SIDES <= 9);
+        Self {
+            tens: NumericDie::new(),
+            units: NumericDie::new(),
+        }
+    }
+
+    /// Creates a new die starting at the given tens and units faces.
+    ///
+    /// # Panics
+    ///
+    /// If either face is out of range.
+    pub fn with_faces(tens: u8, units: u8) -> Self {
+        assert!(SIDES <= 9);
+        Self {
+            tens: NumericDie::from(tens),
+            units: NumericDie::from(units),
+        }
+    }
+
+    /// Returns the total possible sides (outcomes) for the die.
+    pub const fn sides() -> usize {
+        SIDES * SIDES
+    }
+
+    /// Returns the currently faced value of the tens die.
+    pub const fn tens(&self) -> u8 {
+        self.tens.value()
+    }
+
+    /// Returns the currently faced value of the units die.
+    pub const fn units(&self) -> u8 {
+        self.units.value()
+    }
+
+    /// Returns the currently faced value, i.e. `tens * 10 + units`.
+    pub const fn value(&self) -> u8 {
+        self.tens() * 10 + self.units()
+    }
+
+    /// Returns the position of the current outcome, between `0..Self::sides()`.
+    fn position(&self) -> usize {
+        (self.tens() as usize - 1) * SIDES + (self.units() as usize - 1)
+    }
+
+    /// Creates a die showing the outcome at the given position, between `0..Self::sides()`.
+    fn from_position(position: usize) -> Self {
+        debug_assert!(position < Self::sides());
+        Self::with_faces((position / SIDES + 1) as u8, (position % SIDES + 1) as u8)
+    }
+
+    /// Returns the position rotated by the given amount, wrapping around in either direction.
+    fn rotated(&self, amount: isize) -> usize {
+        (self.position() as isize + amount).rem_euclid(Self::sides() as isize) as usize
+    }
+}
+
+impl<const SIDES: usize> Debug for CompositeDie<SIDES> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "D{}{}:{}", SIDES, SIDES, self.value())?;
+        Ok(())
+    }
+}
+
+impl<const SIDES: usize> Display for CompositeDie<SIDES> {
+    /// Formats the die as its name and current value, i.e. `d66 showing 35`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "d{}{} showing {}", SIDES, SIDES, self.value())
+    }
+}
+
+impl<const SIDES: usize> Default for CompositeDie<SIDES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The serialized form of a [`CompositeDie`], which is validated when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct CompositeDieRepr<const SIDES: usize> {
+    tens: NumericDie<u8, SIDES>,
+    units: NumericDie<u8, SIDES>,
+}
+
+#[cfg(feature = "serde")]
+impl<const SIDES: usize> TryFrom<CompositeDieRepr<SIDES>> for CompositeDie<SIDES> {
+    type Error = crate::Error;
+
+    /// Creates a die from the serialized digits, failing if either die would have more than `9`
+    /// sides, like [`CompositeDie::new`].
+    fn try_from(repr: CompositeDieRepr<SIDES>) -> Result<Self, Self::Error> {
+        if SIDES > 9 {
+            return Err(crate::Error::OutOfRange {
+                value: SIDES,
+                min: 0,
+                max: 9,
+            });
+        }
+        Ok(Self {
+            tens: repr.tens,
+            units: repr.units,
+        })
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<const SIDES: usize> rand::distributions::Distribution<u8> for CompositeDie<SIDES> {
+    /// Samples a value (see [`CompositeDie::value`]), with equal odds for each outcome.
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> u8 {
+        Self::from_position(rng.gen_range(0..Self::sides())).value()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, const SIDES: usize> arbitrary::Arbitrary<'a> for CompositeDie<SIDES> {
+    /// Creates a die showing any outcome.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            tens: NumericDie::arbitrary(u)?,
+            units: NumericDie::arbitrary(u)?,
+        })
+    }
+}
+
+impl<const SIDES: usize> Critical for CompositeDie<SIDES> {
+    fn natural(&self) -> usize {
+        self.position() + 1
+    }
+}
+
+impl<const SIDES: usize> Polyhedral for CompositeDie<SIDES> {
+    type Face = u8;
+
+    fn sides() -> usize {
+        Self::sides()
+    }
+
+    fn value(&self) -> u8 {
+        self.value()
+    }
+}
+
+impl<const SIDES: usize> Positional for CompositeDie<SIDES> {
+    fn position(&self) -> usize {
+        self.position()
+    }
+
+    fn set_position(&mut self, position: usize) {
+        assert!(position < Self::sides());
+        *self = Self::from_position(position);
+    }
+}
+
+impl<const SIDES: usize> Snapshot for CompositeDie<SIDES> {
+    type Memento = usize;
+
+    fn snapshot(&self) -> usize {
+        self.position()
+    }
+
+    fn restore(&mut self, memento: &usize) {
+        self.set_position(*memento);
+    }
+}
+
+impl<const SIDES: usize> Step for CompositeDie<SIDES> {
+    /// Rotates the die forward by one outcome, carrying the units die into the tens die.
+    ///
+    /// If the value would have surpassed the maximum, it returns back to the minimum value.
+    fn next(&self) -> Self {
+        Self::from_position(self.rotated(1))
+    }
+
+    /// Rotates the die backwards by one outcome, borrowing from the tens die.
+    ///
+    /// If the value would have surpassed the minimum, it returns back to the maximum value.
+    fn back(&self) -> Self {
+        Self::from_position(self.rotated(-1))
+    }
+}
+
+impl<const SIDES: usize> StepMut for CompositeDie<SIDES> {
+    /// Rotates the die forward by one outcome, carrying the units die into the tens die.
+    ///
+    /// If the value would have surpassed the maximum, it returns back to the minimum value.
+    fn next_mut(&mut self) {
+        *self = self.next();
+    }
+
+    /// Rotates the die backwards by one outcome, borrowing from the tens die.
+    ///
+    /// If the value would have surpassed the minimum, it returns back to the maximum value.
+    fn back_mut(&mut self) {
+        *self = self.back();
+    }
+}
+
+impl<const SIDES: usize> Rotate for CompositeDie<SIDES> {
+    fn rotate(&self, amount: isize) -> Self {
+        Self::from_position(self.rotated(amount))
+    }
+}
+
+impl<const SIDES: usize> RotateMut for CompositeDie<SIDES> {
+    fn rotate_mut(&mut self, amount: isize) {
+        *self = self.rotate(amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn composite_die_new() {
+        let d = D66::new();
+
+        assert_eq!(d.tens(), 1);
+        assert_eq!(d.units(), 1);
+        assert_eq!(d.value(), 11);
+    }
+
+    #[test]
+    fn composite_die_default() {
+        let d: D88 = Default::default();
+
+        assert_eq!(d, D88::new());
+    }
+
+    #[test]
+    fn composite_die_is_debug() {
+        let d = D66::with_faces(3, 5);
+
+        assert_eq!(format!("{:?}", d), "D66:35");
+    }
+
+    #[test]
+    #[should_panic]
+    fn composite_die_with_faces_out_of_bounds() {
+        D66::with_faces(7, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn composite_die_too_many_sides() {
+        CompositeDie::<10>::new();
+    }
+
+    #[test]
+    fn composite_die_sides() {
+        assert_eq!(D66::sides(), 36);
+        assert_eq!(<D88 as Polyhedral>::sides(), 64);
+    }
+
+    #[test]
+    fn composite_die_critical() {
+        assert!(D66::with_faces(6, 6).is_critical());
+        assert!(D66::with_faces(1, 1).is_fumble());
+        assert_eq!(D66::with_faces(2, 1).natural(), 7);
+    }
+
+    #[test]
+    fn composite_die_next_carries() {
+        let d = D66::with_faces(1, 6).next();
+
+        assert_eq!(d.value(), 21);
+    }
+
+    #[test]
+    fn composite_die_next_wrap() {
+        let d = D66::with_faces(6, 6).next();
+
+        assert_eq!(d.value(), 11);
+    }
+
+    #[test]
+    fn composite_die_back_borrows() {
+        let d = D66::with_faces(2, 1).back();
+
+        assert_eq!(d.value(), 16);
+    }
+
+    #[test]
+    fn composite_die_back_wrap() {
+        let d = D66::new().back();
+
+        assert_eq!(d.value(), 66);
+    }
+
+    #[test]
+    fn composite_die_step_mut() {
+        let mut d = D66::with_faces(1, 6);
+        d.next_mut();
+        assert_eq!(d.value(), 21);
+
+        d.back_mut();
+        assert_eq!(d.value(), 16);
+    }
+
+    #[test]
+    fn composite_die_rotate() {
+        let d = D66::new();
+
+        assert_eq!(d.rotate(0).value(), 11);
+        assert_eq!(d.rotate(7).value(), 22);
+        assert_eq!(d.rotate(-1).value(), 66);
+        assert_eq!(d.rotate(-37).value(), 66);
+    }
+
+    #[test]
+    fn composite_die_rotate_mut() {
+        let mut d = D88::new();
+        d.rotate_mut(9);
+
+        assert_eq!(d.value(), 22);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn composite_die_sample() {
+        use rand::rngs::mock::StepRng;
+        use rand::Rng;
+
+        let mut rng = StepRng::new(0, 1 << 58);
+        for value in (&mut rng).sample_iter(D66::new()).take(100) {
+            assert!((1..=6).contains(&(value / 10)));
+            assert!((1..=6).contains(&(value % 10)));
+        }
+    }
+
+    #[test]
+    fn composite_die_display() {
+        assert_eq!(D66::with_faces(3, 5).to_string(), "d66 showing 35");
+    }
+
+    #[test]
+    fn composite_die_ord() {
+        assert!(D66::with_faces(2, 6) < D66::with_faces(3, 1));
+        assert!(D66::with_faces(3, 2) > D66::with_faces(3, 1));
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn composite_die_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let die = D66::arbitrary(&mut Unstructured::new(&[5, 2])).unwrap();
+        assert!((11..=66).contains(&die.value()));
+    }
+
+    #[test]
+    fn composite_die_positional() {
+        let mut d66 = D66::with_faces(2, 3);
+        assert_eq!(Positional::position(&d66), 8);
+
+        d66.set_position(35);
+        assert_eq!(d66.value(), 66);
+    }
+
+    #[test]
+    fn composite_die_faces() {
+        let faces: Vec<u8> = D66::new().faces().collect();
+
+        assert_eq!(faces.len(), 36);
+        assert_eq!(&faces[..7], &[11, 12, 13, 14, 15, 16, 21]);
+        assert_eq!(faces.last(), Some(&66));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn composite_die_serde() {
+        let die = D66::with_faces(3, 5);
+        let json = serde_json::to_string(&die).unwrap();
+
+        assert_eq!(serde_json::from_str::<D66>(&json).unwrap(), die);
+        assert!(serde_json::from_str::<D66>(r#"{"tens":3,"units":7}"#).is_err());
+        assert!(serde_json::from_str::<CompositeDie<12>>(r#"{"tens":3,"units":11}"#).is_err());
+    }
+}
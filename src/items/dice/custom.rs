@@ -0,0 +1,568 @@
+use crate::traits::{
+    Critical, Polyhedral, Positional, Rotate, RotateFast, RotateMut, SideSelector, Snapshot, Step,
+    StepMut,
+};
+use crate::Error;
+
+/// A die with any number of (optionally weighted) faces, chosen at runtime.
+///
+/// Most dice know their number of sides at compile time, i.e. [`crate::items::NumericDie`] and
+/// [`crate::items::SliceDie`], which is required to implement [`Polyhedral`]. For bespoke dice,
+/// i.e. defined in a configuration file, a `Die` is built with [`Die::builder`], and is rolled
+/// directly with a [`SideSelector`] instead of a [`crate::traits::Roll`]. Once the number of sides
+/// is known, [`Die::sized`] converts it into a [`SizedDie`], which implements the traits of dice.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{Die, RngRoller};
+///
+/// let die = Die::builder()
+///     .faces(["A", "B", "C"])
+///     .weight("A", 2)
+///     .start_at(1)
+///     .build();
+///
+/// assert_eq!(die.value(), &"B");
+/// assert_eq!(die.sides(), 3);
+/// assert_eq!(die.total_weight(), 4);
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// assert_eq!(die.roll(&roller).value(), &"A");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "DieRepr<T>")
+)]
+pub struct Die<T> {
+    faces: Vec<T>,
+    weights: Vec<usize>,
+    position: usize,
+}
+
+impl<T> Die<T> {
+    /// Returns a builder for a die.
+    pub fn builder() -> DieBuilder<T> {
+        DieBuilder {
+            faces: Vec::new(),
+            weights: Vec::new(),
+            start: 0,
+        }
+    }
+
+    /// Returns the value of the current face.
+    pub fn value(&self) -> &T {
+        &self.faces[self.position]
+    }
+
+    /// Returns the position of the current face.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Changes the current face to the face at the given position.
+    ///
+    /// # Panics
+    ///
+    /// If the position is not within `0..self.sides()`.
+    pub fn set_position(&mut self, position: usize) {
+        assert!(position < self.sides());
+        self.position = position;
+    }
+
+    /// Returns the number of faces.
+    pub fn sides(&self) -> usize {
+        self.faces.len()
+    }
+
+    /// Returns the value of every face, from the lowest to highest position.
+    pub fn faces(&self) -> &[T] {
+        &self.faces
+    }
+
+    /// Returns the relative weight of the face at the given position.
+    ///
+    /// # Panics
+    ///
+    /// If the position is not within `0..self.sides()`.
+    pub fn weight(&self, position: usize) -> usize {
+        self.weights[position]
+    }
+
+    /// Returns the sum of the weights of every face.
+    pub fn total_weight(&self) -> usize {
+        total_weight(&self.weights).expect("total weight overflowed")
+    }
+
+    /// Returns the die as a [`SizedDie`] with `SIDES` sides, or an error if it has more or fewer.
+    ///
+    /// # Errors
+    ///
+    /// If the die does not have exactly `SIDES` sides, returns [`Error::OutOfRange`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::items::Die;
+    /// use tomb::traits::Critical;
+    ///
+    /// let die = Die::builder().faces(["A", "B", "C"]).start_at(2).build();
+    ///
+    /// assert!(die.clone().sized::<3>().unwrap().is_critical());
+    /// assert!(die.sized::<4>().is_err());
+    /// ```
+    pub fn sized<const SIDES: usize>(self) -> Result<SizedDie<T, SIDES>, Error> {
+        if self.sides() != SIDES {
+            return Err(Error::OutOfRange {
+                value: self.sides(),
+                min: SIDES,
+                max: SIDES,
+            });
+        }
+        Ok(SizedDie(self))
+    }
+
+    /// Returns a copy of the die, showing a face selected in proportion to its weight.
+    #[must_use]
+    pub fn roll<S>(&self, selector: &S) -> Self
+    where
+        S: SideSelector,
+        T: Clone,
+    {
+        let mut die = self.clone();
+        die.roll_mut(selector);
+        die
+    }
+
+    /// Changes the die to show a face selected in proportion to its weight.
+    pub fn roll_mut<S>(&mut self, selector: &S)
+    where
+        S: SideSelector,
+    {
+        let mut target = selector.select(self.total_weight());
+        for (position, &weight) in self.weights.iter().enumerate() {
+            if target < weight {
+                self.position = position;
+                return;
+            }
+            target -= weight;
+        }
+    }
+}
+
+/// Builds a [`Die`], created by [`Die::builder`].
+#[derive(Clone, Debug)]
+pub struct DieBuilder<T> {
+    faces: Vec<T>,
+    weights: Vec<usize>,
+    start: usize,
+}
+
+impl<T> DieBuilder<T> {
+    /// Adds a face with a weight of `1`.
+    #[must_use]
+    pub fn face(mut self, value: T) -> Self {
+        self.faces.push(value);
+        self.weights.push(1);
+        self
+    }
+
+    /// Adds every face, each with a weight of `1`.
+    #[must_use]
+    pub fn faces<I>(self, values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        values.into_iter().fold(self, Self::face)
+    }
+
+    /// Changes the relative weight of every face with the value, i.e. `2` to be twice as likely.
+    ///
+    /// A weight of `0` means the face is never rolled.
+    ///
+    /// # Panics
+    ///
+    /// If no face has the value.
+    #[must_use]
+    pub fn weight(mut self, value: T, weight: usize) -> Self
+    where
+        T: PartialEq,
+    {
+        let mut found = false;
+        for (face, w) in self.faces.iter().zip(&mut self.weights) {
+            if *face == value {
+                *w = weight;
+                found = true;
+            }
+        }
+        assert!(found);
+        self
+    }
+
+    /// Changes the position of the face the die starts on, which is otherwise `0`.
+    #[must_use]
+    pub fn start_at(mut self, position: usize) -> Self {
+        self.start = position;
+        self
+    }
+
+    /// Returns the die.
+    ///
+    /// # Panics
+    ///
+    /// If there are no faces, every face has a weight of `0`, the total weight overflows a
+    /// `usize`, or the starting position is out of range.
+    pub fn build(self) -> Die<T> {
+        assert!(self.start < self.faces.len());
+        let total = total_weight(&self.weights).expect("total weight overflowed");
+        assert!(total > 0);
+        Die {
+            faces: self.faces,
+            weights: self.weights,
+            position: self.start,
+        }
+    }
+}
+
+/// Returns the sum of the weights, or `None` if the sum overflows a `usize`.
+fn total_weight(weights: &[usize]) -> Option<usize> {
+    weights
+        .iter()
+        .try_fold(0usize, |total, &weight| total.checked_add(weight))
+}
+
+/// The serialized form of a [`Die`], which is validated when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct DieRepr<T> {
+    faces: Vec<T>,
+    weights: Vec<usize>,
+    position: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<DieRepr<T>> for Die<T> {
+    type Error = Error;
+
+    /// Creates a die from the serialized fields, failing if they could not be built.
+    fn try_from(repr: DieRepr<T>) -> Result<Self, Self::Error> {
+        let sides = repr.faces.len();
+        if sides == 0 || repr.weights.len() != sides {
+            return Err(Error::OutOfRange {
+                value: repr.weights.len(),
+                min: sides.max(1),
+                max: sides.max(1),
+            });
+        }
+        if repr.position >= sides {
+            return Err(Error::OutOfRange {
+                value: repr.position,
+                min: 0,
+                max: sides - 1,
+            });
+        }
+        match total_weight(&repr.weights) {
+            Some(total) if total > 0 => Ok(Self {
+                faces: repr.faces,
+                weights: repr.weights,
+                position: repr.position,
+            }),
+            total => Err(Error::OutOfRange {
+                value: total.unwrap_or(usize::MAX),
+                min: 1,
+                max: usize::MAX,
+            }),
+        }
+    }
+}
+
+/// A [`Die`] with a number of sides known at compile time, created by [`Die::sized`].
+///
+/// Unlike a `Die`, a sized die implements [`Polyhedral`] (and [`Positional`], [`Critical`],
+/// [`Rotate`], and so on), so it works with every roller and trait of dice. Like any positional
+/// die, a [`crate::traits::Roll`] selects every face with equal odds, so [`SizedDie::roll`] (or
+/// [`Die::roll`]) is used to roll a face in proportion to its weight.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{Die, SequenceRoller};
+/// use tomb::traits::{Polyhedral, Roll};
+///
+/// let die = Die::builder().faces(["A", "B", "C"]).build().sized::<3>().unwrap();
+/// let roller = SequenceRoller::new([2]);
+///
+/// assert_eq!(roller.roll(&die).value(), "C");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SizedDie<T, const SIDES: usize>(Die<T>);
+
+impl<T, const SIDES: usize> SizedDie<T, SIDES> {
+    /// Returns a reference to the die.
+    pub const fn die(&self) -> &Die<T> {
+        &self.0
+    }
+
+    /// Returns the die, which no longer has a number of sides known at compile time.
+    pub fn into_inner(self) -> Die<T> {
+        self.0
+    }
+
+    /// Returns a copy of the die, showing a face selected in proportion to its weight.
+    #[must_use]
+    pub fn roll<S>(&self, selector: &S) -> Self
+    where
+        S: SideSelector,
+        T: Clone,
+    {
+        Self(self.0.roll(selector))
+    }
+
+    /// Changes the die to show a face selected in proportion to its weight.
+    pub fn roll_mut<S>(&mut self, selector: &S)
+    where
+        S: SideSelector,
+    {
+        self.0.roll_mut(selector);
+    }
+}
+
+impl<T, const SIDES: usize> Polyhedral for SizedDie<T, SIDES>
+where
+    T: Clone,
+{
+    type Face = T;
+
+    fn sides() -> usize {
+        SIDES
+    }
+
+    fn value(&self) -> T {
+        self.0.value().clone()
+    }
+}
+
+impl<T, const SIDES: usize> Positional for SizedDie<T, SIDES>
+where
+    T: Clone,
+{
+    fn position(&self) -> usize {
+        self.0.position()
+    }
+
+    fn set_position(&mut self, position: usize) {
+        self.0.set_position(position);
+    }
+}
+
+impl<T, const SIDES: usize> Snapshot for SizedDie<T, SIDES>
+where
+    T: Clone,
+{
+    type Memento = usize;
+
+    fn snapshot(&self) -> usize {
+        self.position()
+    }
+
+    fn restore(&mut self, memento: &usize) {
+        self.set_position(*memento);
+    }
+}
+
+impl<T, const SIDES: usize> Critical for SizedDie<T, SIDES>
+where
+    T: Clone,
+{
+    fn natural(&self) -> usize {
+        self.0.position() + 1
+    }
+}
+
+impl<T, const SIDES: usize> Step for SizedDie<T, SIDES>
+where
+    T: Clone,
+{
+    /// Rotates the die forward by one face, wrapping around to the first face.
+    fn next(&self) -> Self {
+        self.rotate_fast(1)
+    }
+
+    /// Rotates the die backwards by one face, wrapping around to the last face.
+    fn back(&self) -> Self {
+        self.rotate_fast(-1)
+    }
+}
+
+impl<T, const SIDES: usize> StepMut for SizedDie<T, SIDES>
+where
+    T: Clone,
+{
+    /// Rotates the die forward by one face, wrapping around to the first face.
+    fn next_mut(&mut self) {
+        self.rotate_fast_mut(1);
+    }
+
+    /// Rotates the die backwards by one face, wrapping around to the last face.
+    fn back_mut(&mut self) {
+        self.rotate_fast_mut(-1);
+    }
+}
+
+impl<T, const SIDES: usize> Rotate for SizedDie<T, SIDES>
+where
+    T: Clone,
+{
+    fn rotate(&self, amount: isize) -> Self {
+        self.rotate_fast(amount)
+    }
+}
+
+impl<T, const SIDES: usize> RotateMut for SizedDie<T, SIDES>
+where
+    T: Clone,
+{
+    fn rotate_mut(&mut self, amount: isize) {
+        self.rotate_fast_mut(amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fixed(usize);
+
+    impl SideSelector for Fixed {
+        fn select(&self, sides: usize) -> usize {
+            assert!(self.0 < sides);
+            self.0
+        }
+    }
+
+    #[test]
+    fn die_builder_defaults() {
+        let die = Die::builder().face('x').face('y').build();
+
+        assert_eq!(die.position(), 0);
+        assert_eq!(die.faces(), &['x', 'y']);
+        assert_eq!(die.weight(1), 1);
+    }
+
+    #[test]
+    fn die_roll_weighted() {
+        let die = Die::builder()
+            .faces(["A", "B", "C"])
+            .weight("A", 2)
+            .weight("B", 0)
+            .build();
+
+        assert_eq!(die.roll(&Fixed(0)).value(), &"A");
+        assert_eq!(die.roll(&Fixed(1)).value(), &"A");
+        assert_eq!(die.roll(&Fixed(2)).value(), &"C");
+    }
+
+    #[test]
+    fn die_set_position() {
+        let mut die = Die::builder().faces(1..=4).build();
+        die.set_position(3);
+
+        assert_eq!(die.value(), &4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn die_builder_empty() {
+        Die::<u8>::builder().build();
+    }
+
+    #[test]
+    #[should_panic]
+    fn die_builder_start_out_of_range() {
+        Die::builder().face(1).start_at(1).build();
+    }
+
+    #[test]
+    #[should_panic]
+    fn die_builder_weight_missing() {
+        let _ = Die::builder().face(1).weight(2, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn die_builder_all_zero_weights() {
+        Die::builder().face(1).weight(1, 0).build();
+    }
+
+    #[test]
+    #[should_panic(expected = "total weight overflowed")]
+    fn die_builder_weight_overflow() {
+        Die::builder()
+            .faces([1, 2])
+            .weight(1, usize::MAX)
+            .weight(2, 1)
+            .build();
+    }
+
+    #[test]
+    fn die_sized() {
+        let mut die = Die::builder().faces(1..=4).build().sized::<4>().unwrap();
+        die.rotate_mut(-1);
+
+        assert_eq!(Polyhedral::value(&die), 4);
+        assert!(die.is_critical());
+        assert_eq!(die.next().natural(), 1);
+        assert_eq!(die.into_inner().position(), 3);
+        assert_eq!(
+            Die::builder().face(1).build().sized::<2>(),
+            Err(Error::OutOfRange {
+                value: 1,
+                min: 2,
+                max: 2
+            })
+        );
+    }
+
+    #[test]
+    fn die_sized_roll_weighted() {
+        let die = Die::builder()
+            .faces(["A", "B"])
+            .weight("A", 0)
+            .build()
+            .sized::<2>()
+            .unwrap();
+
+        assert_eq!(die.roll(&Fixed(0)).die().value(), &"B");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn die_serde() {
+        let die = Die::builder().faces(['a', 'b']).weight('a', 3).build();
+        let json = serde_json::to_string(&die).unwrap();
+
+        assert_eq!(serde_json::from_str::<Die<char>>(&json).unwrap(), die);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn die_serde_invalid() {
+        for json in [
+            r#"{"faces":["a"],"weights":[1],"position":1}"#,
+            r#"{"faces":["a","b"],"weights":[0,0],"position":0}"#,
+            r#"{"faces":["a"],"weights":[1,1],"position":0}"#,
+            r#"{"faces":[],"weights":[],"position":0}"#,
+        ] {
+            assert!(serde_json::from_str::<Die<char>>(json).is_err(), "{json}");
+        }
+
+        let overflow = format!(
+            r#"{{"faces":["a","b"],"weights":[{},1],"position":0}}"#,
+            usize::MAX
+        );
+        assert!(serde_json::from_str::<Die<char>>(&overflow).is_err());
+    }
+}
@@ -0,0 +1,188 @@
+use std::fmt::Display;
+
+use crate::mechanics::{Keep, Notation, NotationRoll};
+use crate::traits::Critical;
+
+/// Formats a pool (a slice or array) of dice as Markdown, i.e. for a chat bot.
+///
+/// Like [`PoolDisplay`](crate::items::PoolDisplay), the natural face of each die is listed in
+/// order, followed by the total (see [`NotationRoll`]), but:
+///
+/// - Critical faces (see [`Critical::is_critical`]) are **bold**;
+/// - Dice dropped by [`keep_highest`](Self::keep_highest) or [`keep_lowest`](Self::keep_lowest)
+///   are ~~struck through~~, and are not part of the total;
+/// - [`hidden`](Self::hidden) rolls are wrapped in a `||spoiler||` tag.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{MarkdownDisplay, D6};
+///
+/// let pool = [D6::from(2), D6::from(6), D6::from(1), D6::from(4)];
+/// let markdown = MarkdownDisplay::new(&pool).keep_highest(3).with_modifier(2);
+///
+/// assert_eq!(markdown.to_string(), "4d6kh3+2: [2, **6**, ~~1~~, 4] + 2 = 14");
+/// assert_eq!(markdown.hidden().to_string(), "||4d6kh3+2: [2, **6**, ~~1~~, 4] + 2 = 14||");
+/// ```
+#[derive(Clone, Debug)]
+pub struct MarkdownDisplay<'a, D> {
+    dice: &'a [D],
+    roll: NotationRoll,
+    hidden: bool,
+}
+
+impl<'a, D> MarkdownDisplay<'a, D>
+where
+    D: Critical,
+{
+    /// Creates a formatter for the given pool of dice, keeping every die and without a modifier.
+    pub fn new(dice: &'a [D]) -> Self {
+        let notation = Notation::new(dice.len(), D::sides());
+        let rolls = dice.iter().map(Critical::natural).collect();
+        Self {
+            dice,
+            roll: NotationRoll::new(notation, rolls),
+            hidden: false,
+        }
+    }
+
+    /// Returns the formatter with a flat modifier added to the total.
+    pub fn with_modifier(self, modifier: i32) -> Self {
+        let notation = self.roll.notation().with_modifier(modifier);
+        self.with_notation(notation)
+    }
+
+    /// Returns the formatter keeping only the highest `count` dice, i.e. `4d6kh3`.
+    ///
+    /// When dice are tied, the earliest dice are kept.
+    pub fn keep_highest(self, count: usize) -> Self {
+        let notation = self.roll.notation().with_keep(Keep::Highest(count));
+        self.with_notation(notation)
+    }
+
+    /// Returns the formatter keeping only the lowest `count` dice, i.e. `2d20kl1`.
+    ///
+    /// When dice are tied, the earliest dice are kept.
+    pub fn keep_lowest(self, count: usize) -> Self {
+        let notation = self.roll.notation().with_keep(Keep::Lowest(count));
+        self.with_notation(notation)
+    }
+
+    /// Returns the formatter wrapping the roll in a spoiler tag, i.e. for a secret roll.
+    pub fn hidden(self) -> Self {
+        Self {
+            hidden: true,
+            ..self
+        }
+    }
+
+    /// Returns the roll being formatted, with which dice are kept.
+    pub const fn roll(&self) -> &NotationRoll {
+        &self.roll
+    }
+
+    /// Returns the sum of every kept die plus the modifier.
+    pub fn total(&self) -> i64 {
+        self.roll.total()
+    }
+
+    /// Returns the formatter for the same dice, rolled as the given notation.
+    fn with_notation(self, notation: Notation) -> Self {
+        let rolls = self.roll.rolls().to_vec();
+        Self {
+            roll: NotationRoll::new(notation, rolls),
+            ..self
+        }
+    }
+}
+
+impl<D> Display for MarkdownDisplay<'_, D>
+where
+    D: Critical,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.hidden {
+            f.write_str("||")?;
+        }
+        write!(f, "{}: [", self.roll.notation())?;
+        for (i, die) in self.dice.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            match (self.roll.is_kept(i), die.is_critical()) {
+                (false, _) => write!(f, "~~{}~~", die.natural())?,
+                (true, true) => write!(f, "**{}**", die.natural())?,
+                (true, false) => write!(f, "{}", die.natural())?,
+            }
+        }
+        f.write_str("]")?;
+        match self.roll.notation().modifier() {
+            0 => {}
+            m if m > 0 => write!(f, " + {m}")?,
+            m => write!(f, " - {}", m.unsigned_abs())?,
+        }
+        write!(f, " = {}", self.total())?;
+        if self.hidden {
+            f.write_str("||")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{D20, D6};
+
+    #[test]
+    fn markdown_display_pool() {
+        let pool = [D6::from(3), D6::from(6)];
+
+        assert_eq!(
+            MarkdownDisplay::new(&pool).to_string(),
+            "2d6: [3, **6**] = 9"
+        );
+    }
+
+    #[test]
+    fn markdown_display_negative_modifier() {
+        let pool = [D20::from(12)];
+        let markdown = MarkdownDisplay::new(&pool).with_modifier(-2);
+
+        assert_eq!(markdown.total(), 10);
+        assert_eq!(markdown.to_string(), "1d20-2: [12] - 2 = 10");
+    }
+
+    #[test]
+    fn markdown_display_keep_lowest() {
+        let pool = [D20::from(20), D20::from(4)];
+        let markdown = MarkdownDisplay::new(&pool).keep_lowest(1);
+
+        assert_eq!(markdown.to_string(), "2d20kl1: [~~20~~, 4] = 4");
+    }
+
+    #[test]
+    fn markdown_display_keep_ties() {
+        let pool = [D6::from(5), D6::from(5), D6::from(5)];
+        let markdown = MarkdownDisplay::new(&pool).keep_highest(2);
+
+        assert_eq!(markdown.to_string(), "3d6kh2: [5, 5, ~~5~~] = 10");
+    }
+
+    #[test]
+    fn markdown_display_keep_more_than_pool() {
+        let pool = [D6::from(2)];
+
+        assert_eq!(MarkdownDisplay::new(&pool).keep_highest(3).total(), 2);
+    }
+
+    #[test]
+    fn markdown_display_roll() {
+        let pool = [D6::from(2), D6::from(6), D6::from(1)];
+        let markdown = MarkdownDisplay::new(&pool).keep_lowest(2).with_modifier(1);
+
+        assert_eq!(markdown.roll().notation().to_string(), "3d6kl2+1");
+        assert_eq!(markdown.roll().kept().collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(markdown.total(), markdown.roll().total());
+    }
+}
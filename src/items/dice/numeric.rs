@@ -1,9 +1,14 @@
 use std::{
-    fmt::Debug,
+    fmt::{Debug, Display},
+    iter::Sum,
     ops::{Add, Sub},
 };
 
-use crate::traits::{Numeric, Polyhedral, Rotate, RotateMut, Step, StepMut};
+use crate::traits::{
+    Coin, Critical, Faces, Numeric, OppositeFaces, Polyhedral, Positional, Rotate, RotateMut,
+    SideSelector, Snapshot, Step, StepMut,
+};
+use crate::Error;
 
 /// A die that starts at `1` and has a defined maximum numeric value.
 ///
@@ -23,6 +28,14 @@ use crate::traits::{Numeric, Polyhedral, Rotate, RotateMut, Step, StepMut};
 /// D6::new();
 /// ```
 ///
+/// Dice are ordered by their current value, so a pool of dice can be sorted or compared:
+///
+/// ```
+/// # use tomb::items::D6;
+/// let pool = [D6::from(2), D6::from(6), D6::from(4)];
+/// assert_eq!(pool.iter().max(), Some(&D6::from(6)));
+/// ```
+///
 /// # Trivia
 ///
 /// Despite the name, _Numeric_Die does accept non-numbers, as long as they are number-_like_:
@@ -30,11 +43,23 @@ use crate::traits::{Numeric, Polyhedral, Rotate, RotateMut, Step, StepMut};
 /// 1. Implement the [`Numeric`] trait.
 /// 2. The _default_ value should be `1` or `1`-like.
 /// 3. Solemnly swear to behave like numbers so that future traits can utilize them like one.
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NumericDie<T, const MAXIMUM: usize>(T)
 where
     T: Numeric;
 
+/// A conveniently provided 3-sided numeric die.
+///
+/// # Examples
+///
+/// ```
+/// # use tomb::items::D3;
+/// # use tomb::traits::Rotate;
+/// let die = D3::new().rotate(2);
+/// assert_eq!(die.value(), 3);
+/// ```
+pub type D3 = NumericDie<u8, 3>;
+
 /// A conveniently provided 4-sided numeric die.
 ///
 /// # Examples
@@ -47,6 +72,18 @@ where
 /// ```
 pub type D4 = NumericDie<u8, 4>;
 
+/// A conveniently provided 5-sided numeric die.
+///
+/// # Examples
+///
+/// ```
+/// # use tomb::items::D5;
+/// # use tomb::traits::Rotate;
+/// let die = D5::new().rotate(4);
+/// assert_eq!(die.value(), 5);
+/// ```
+pub type D5 = NumericDie<u8, 5>;
+
 /// A conveniently provided 6-sided numeric die.
 ///
 /// # Examples
@@ -59,6 +96,18 @@ pub type D4 = NumericDie<u8, 4>;
 /// ```
 pub type D6 = NumericDie<u8, 6>;
 
+/// A conveniently provided 7-sided numeric die.
+///
+/// # Examples
+///
+/// ```
+/// # use tomb::items::D7;
+/// # use tomb::traits::Rotate;
+/// let die = D7::new().rotate(6);
+/// assert_eq!(die.value(), 7);
+/// ```
+pub type D7 = NumericDie<u8, 7>;
+
 /// A conveniently provided 8-sided numeric die.
 ///
 /// # Examples
@@ -95,6 +144,30 @@ pub type D10 = NumericDie<u8, 10>;
 /// ```
 pub type D12 = NumericDie<u8, 12>;
 
+/// A conveniently provided 14-sided numeric die.
+///
+/// # Examples
+///
+/// ```
+/// # use tomb::items::D14;
+/// # use tomb::traits::Rotate;
+/// let die = D14::new().rotate(13);
+/// assert_eq!(die.value(), 14);
+/// ```
+pub type D14 = NumericDie<u8, 14>;
+
+/// A conveniently provided 16-sided numeric die.
+///
+/// # Examples
+///
+/// ```
+/// # use tomb::items::D16;
+/// # use tomb::traits::Rotate;
+/// let die = D16::new().rotate(15);
+/// assert_eq!(die.value(), 16);
+/// ```
+pub type D16 = NumericDie<u8, 16>;
+
 /// A conveniently provided 20-sided numeric die.
 ///
 /// # Examples
@@ -107,21 +180,87 @@ pub type D12 = NumericDie<u8, 12>;
 /// ```
 pub type D20 = NumericDie<u8, 20>;
 
+/// A conveniently provided 24-sided numeric die.
+///
+/// # Examples
+///
+/// ```
+/// # use tomb::items::D24;
+/// # use tomb::traits::Rotate;
+/// let die = D24::new().rotate(23);
+/// assert_eq!(die.value(), 24);
+/// ```
+pub type D24 = NumericDie<u8, 24>;
+
+/// A conveniently provided 30-sided numeric die.
+///
+/// # Examples
+///
+/// ```
+/// # use tomb::items::D30;
+/// # use tomb::traits::Rotate;
+/// let die = D30::new().rotate(29);
+/// assert_eq!(die.value(), 30);
+/// ```
+pub type D30 = NumericDie<u8, 30>;
+
+/// A conveniently provided 100-sided numeric die, also known as a _percentile_ die.
+///
+/// # Examples
+///
+/// ```
+/// # use tomb::items::D100;
+/// # use tomb::traits::Rotate;
+/// let die = D100::new().rotate(99);
+/// assert_eq!(die.value(), 100);
+/// ```
+pub type D100 = NumericDie<u8, 100>;
+
 impl<T, const MAXIMUM: usize> NumericDie<T, MAXIMUM>
 where
     T: Numeric,
 {
+    /// Fails to compile, once used, if `MAXIMUM` can not be represented by `T`.
+    const FITS: () = assert!(
+        MAXIMUM as u128 <= T::CAPACITY,
+        "MAXIMUM is too large for the type of the die"
+    );
+
     /// Creates a new die starting at `1` or the equivalent of `1` for non-numbers.
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
+        let () = Self::FITS;
         Self(T::MINIMUM)
     }
 
-    /// Creates a new die starting at the given `value`.
+    /// Creates a new die showing the given value, or an error if out of range for the die.
+    ///
+    /// This is the non-panicking equivalent of [`NumericDie::from`], i.e. for user input. It is not
+    /// provided as [`TryFrom`], which would conflict with the blanket implementation for [`From`].
     ///
-    /// # Safety
+    /// # Examples
     ///
-    /// The value is _not_ checked for bounds correctness, and could cause undefined behavior.
-    unsafe fn from_unchecked(value: T) -> Self {
+    /// ```
+    /// use tomb::items::D20;
+    ///
+    /// assert_eq!(D20::try_from_value(17).map(|d| d.value()), Ok(17));
+    /// assert!(D20::try_from_value(21).is_err());
+    /// ```
+    pub fn try_from_value(value: T) -> Result<Self, Error> {
+        let () = Self::FITS;
+        if value < T::MINIMUM || value.as_usize() > MAXIMUM {
+            return Err(Error::OutOfRange {
+                value: value.as_usize(),
+                min: T::MINIMUM.as_usize(),
+                max: MAXIMUM,
+            });
+        }
+        Ok(Self(value))
+    }
+
+    /// Creates a new die starting at the given `value`, which is only checked in debug builds.
+    fn from_unchecked(value: T) -> Self {
+        let () = Self::FITS;
+        debug_assert!(value >= T::MINIMUM && value.as_usize() <= MAXIMUM);
         Self(value)
     }
 
@@ -134,6 +273,35 @@ where
     pub const fn value(&self) -> T {
         self.0
     }
+
+    /// Changes the die to show the given value, or returns an error if out of range for the die.
+    ///
+    /// On error, the die is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::items::D6;
+    ///
+    /// let mut d6 = D6::new();
+    ///
+    /// assert!(d6.set(4).is_ok());
+    /// assert_eq!(d6.value(), 4);
+    ///
+    /// assert!(d6.set(7).is_err());
+    /// assert_eq!(d6.value(), 4);
+    /// ```
+    pub fn set(&mut self, value: T) -> Result<(), Error> {
+        *self = Self::try_from_value(value)?;
+        Ok(())
+    }
+
+    /// Changes the die to show the given value, which is only checked in debug builds.
+    ///
+    /// Prefer [`NumericDie::set`] unless the value is already known to be in range for the die.
+    pub fn set_unchecked(&mut self, value: T) {
+        *self = Self::from_unchecked(value);
+    }
 }
 
 impl<T, const MAXIMUM: usize> Debug for NumericDie<T, MAXIMUM>
@@ -146,6 +314,16 @@ where
     }
 }
 
+impl<T, const MAXIMUM: usize> Display for NumericDie<T, MAXIMUM>
+where
+    T: Display + Numeric,
+{
+    /// Formats the die as its number of sides and current value, i.e. `d20 showing 17`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "d{} showing {}", MAXIMUM, self.0)
+    }
+}
+
 impl<T, const MAXIMUM: usize> Default for NumericDie<T, MAXIMUM>
 where
     T: Numeric,
@@ -163,21 +341,269 @@ where
     ///
     /// # Panics
     ///
-    /// If the number is out of range for the capacity of the die.
+    /// If the number is out of range for the capacity of the die; see
+    /// [`NumericDie::try_from_value`] for a non-panicking alternative.
     fn from(number: T) -> Self {
         assert!(number >= T::MINIMUM);
         assert!(number.as_usize() <= MAXIMUM);
-        unsafe { Self::from_unchecked(number) }
+        Self::from_unchecked(number)
     }
 }
 
+macro_rules! numeric_into {
+    ($from:ty => $($into:ty),*) => {
+        $(
+            impl<const MAXIMUM: usize> From<NumericDie<$from, MAXIMUM>> for $into {
+                /// Converts the die into its currently faced value.
+                fn from(die: NumericDie<$from, MAXIMUM>) -> Self {
+                    die.0.into()
+                }
+            }
+        )*
+    };
+}
+
+// Mirrors the lossless conversions between integers in the standard library.
+numeric_into!(u8 => u8, u16, u32, u64, u128, usize);
+numeric_into!(u16 => u16, u32, u64, u128, usize);
+numeric_into!(u32 => u32, u64, u128);
+numeric_into!(u64 => u64, u128);
+numeric_into!(u128 => u128);
+numeric_into!(usize => usize);
+
+macro_rules! numeric_const {
+    ($($t:ty),*) => {
+        $(
+            impl<const MAXIMUM: usize> NumericDie<$t, MAXIMUM> {
+                /// Creates a new die showing the given value, which is usable in const contexts.
+                ///
+                /// # Panics
+                ///
+                /// If the number is out of range for the capacity of the die, which is a compile
+                /// error when evaluated in a const context. A die with more sides than the type
+                /// can represent is always a compile error:
+                ///
+                /// ```compile_fail
+                /// use tomb::items::NumericDie;
+                ///
+                /// let _ = NumericDie::<u8, 300>::from_value(255);
+                /// ```
+                pub const fn from_value(value: $t) -> Self {
+                    let () = Self::FITS;
+                    assert!(value >= 1 && value as u128 <= MAXIMUM as u128);
+                    Self(value)
+                }
+
+                /// Returns a copy of the die rotated by the signed amount, like [`Rotate::rotate`],
+                /// which is usable in const contexts.
+                #[must_use]
+                pub const fn rotated_const(&self, amount: isize) -> Self {
+                    let position = (self.0 - 1) as i128 + amount as i128;
+                    Self((position.rem_euclid(MAXIMUM as i128) + 1) as $t)
+                }
+            }
+        )*
+    };
+}
+
+// Trait methods can not be called in const contexts, so each integer is implemented separately.
+numeric_const!(u8, u16, u32, u64, u128, usize);
+
 impl<T, const MAXIMUM: usize> Polyhedral for NumericDie<T, MAXIMUM>
 where
     T: Numeric,
 {
+    type Face = T;
+
     fn sides() -> usize {
         Self::sides()
     }
+
+    fn value(&self) -> T {
+        self.value()
+    }
+}
+
+impl<T, const MAXIMUM: usize> Add for NumericDie<T, MAXIMUM>
+where
+    T: Numeric,
+{
+    type Output = usize;
+
+    /// Adds the values of both dice, as a `usize` so that the total never overflows `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::items::D6;
+    ///
+    /// assert_eq!(D6::from(4) + D6::from(5), 9);
+    /// ```
+    fn add(self, rhs: Self) -> usize {
+        self.0.as_usize() + rhs.0.as_usize()
+    }
+}
+
+impl<T, const MAXIMUM: usize> Sum<NumericDie<T, MAXIMUM>> for usize
+where
+    T: Numeric,
+{
+    /// Adds the values of every die, i.e. the total of a pool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::items::D100;
+    ///
+    /// let pool = [D100::from(90), D100::from(80), D100::from(95)];
+    ///
+    /// assert_eq!(pool.into_iter().sum::<usize>(), 265);
+    /// ```
+    fn sum<I: Iterator<Item = NumericDie<T, MAXIMUM>>>(iter: I) -> usize {
+        iter.map(|die| die.0.as_usize()).sum()
+    }
+}
+
+impl<'a, T, const MAXIMUM: usize> Sum<&'a NumericDie<T, MAXIMUM>> for usize
+where
+    T: Numeric,
+{
+    /// Adds the values of every die, i.e. the total of a pool.
+    fn sum<I: Iterator<Item = &'a NumericDie<T, MAXIMUM>>>(iter: I) -> usize {
+        iter.map(|die| die.0.as_usize()).sum()
+    }
+}
+
+impl<T, const MAXIMUM: usize> IntoIterator for &NumericDie<T, MAXIMUM>
+where
+    T: Numeric,
+{
+    type Item = T;
+    type IntoIter = Faces<NumericDie<T, MAXIMUM>>;
+
+    /// Returns an iterator over every face of the die, in order; see [`Positional::faces`].
+    fn into_iter(self) -> Self::IntoIter {
+        self.faces()
+    }
+}
+
+impl<T, const MAXIMUM: usize> OppositeFaces for NumericDie<T, MAXIMUM> where T: Numeric {}
+
+impl<T> Coin for NumericDie<T, 2>
+where
+    T: Numeric,
+{
+    /// Returns whether the first face is showing.
+    fn is_heads(&self) -> bool {
+        self.position() == 0
+    }
+
+    /// Turns the coin over, showing the other face.
+    fn swap(&mut self) {
+        self.set_position(1 - self.position());
+    }
+
+    /// Changes the die to show the face at a [selected](SideSelector::select) position.
+    fn toss_mut<S>(&mut self, selector: &S)
+    where
+        S: SideSelector,
+    {
+        selector.pick_mut(self);
+    }
+}
+
+impl<T, const MAXIMUM: usize> Positional for NumericDie<T, MAXIMUM>
+where
+    T: Numeric,
+{
+    fn position(&self) -> usize {
+        self.0.as_usize() - T::MINIMUM.as_usize()
+    }
+
+    fn set_position(&mut self, position: usize) {
+        assert!(position < MAXIMUM);
+        self.0 = T::from_usize(position + T::MINIMUM.as_usize());
+    }
+}
+
+impl<T, const MAXIMUM: usize> Snapshot for NumericDie<T, MAXIMUM>
+where
+    T: Numeric,
+{
+    type Memento = usize;
+
+    fn snapshot(&self) -> usize {
+        self.position()
+    }
+
+    fn restore(&mut self, memento: &usize) {
+        self.set_position(*memento);
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T, const MAXIMUM: usize> rand::distributions::Distribution<T> for NumericDie<T, MAXIMUM>
+where
+    T: Numeric,
+{
+    /// Samples a value between `1..=MAXIMUM`, with equal odds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::Rng;
+    /// use rand::rngs::mock::StepRng;
+    /// use tomb::items::D20;
+    ///
+    /// let mut rng = StepRng::new(0, 1 << 60);
+    /// let value: u8 = rng.sample(D20::new());
+    /// assert!((1..=20).contains(&value));
+    /// ```
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> T {
+        T::from_usize(rng.gen_range(1..=MAXIMUM))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, const MAXIMUM: usize> arbitrary::Arbitrary<'a> for NumericDie<T, MAXIMUM>
+where
+    T: Numeric,
+{
+    /// Creates a die showing any value between `1..=MAXIMUM`.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(T::from_usize(u.int_in_range(1..=MAXIMUM)?)))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, const MAXIMUM: usize> serde::Serialize for NumericDie<T, MAXIMUM>
+where
+    T: Numeric + serde::Serialize,
+{
+    /// Serializes the die as its current value.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, const MAXIMUM: usize> serde::Deserialize<'de> for NumericDie<T, MAXIMUM>
+where
+    T: Numeric + serde::Deserialize<'de>,
+{
+    /// Deserializes the die from its current value, failing if out of range for the die.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::try_from_value(T::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<T, const MAXIMUM: usize> Critical for NumericDie<T, MAXIMUM>
+where
+    T: Numeric,
+{
+    fn natural(&self) -> usize {
+        self.0.as_usize()
+    }
 }
 
 impl<T, const MAXIMUM: usize> Step for NumericDie<T, MAXIMUM>
@@ -192,7 +618,7 @@ where
         if next >= T::from_usize(MAXIMUM) {
             next = T::MINIMUM;
         }
-        unsafe { Self::from_unchecked(next) }
+        Self::from_unchecked(next)
     }
 
     /// Rotates the die backwards by 1.
@@ -203,7 +629,7 @@ where
         if back < T::MINIMUM {
             back = T::from_usize(MAXIMUM);
         }
-        unsafe { Self::from_unchecked(back) }
+        Self::from_unchecked(back)
     }
 }
 
@@ -234,50 +660,50 @@ where
     }
 }
 
-fn rotate_forward_usize<T, const MAXIMUM: usize>(amount: usize, mut next: usize) -> T
+fn rotate_forward_usize<T, const MAXIMUM: usize>(amount: usize, next: usize) -> T
 where
     T: Numeric + Add<Output = T> + Sub<Output = T>,
 {
     debug_assert!(amount > 0);
-    next += amount;
-    if next > MAXIMUM {
-        next %= MAXIMUM;
-    }
-    T::from_usize(next)
+    let offset = amount % MAXIMUM;
+    T::from_usize((next - 1 + offset) % MAXIMUM + 1)
 }
 
-fn rotate_backward_usize<T, const MAXIMUM: usize>(amount: usize, mut next: usize) -> T
+fn rotate_backward_usize<T, const MAXIMUM: usize>(amount: usize, next: usize) -> T
 where
     T: Numeric + Add<Output = T> + Sub<Output = T>,
 {
     debug_assert!(amount > 0);
-    println!("next:{next} - amount:{amount}");
-    let rotated = next as i64 - (amount as i64);
-    if rotated < 1 {
-        let rotated = rotated % MAXIMUM as i64 + MAXIMUM as i64;
-        next = rotated as usize;
-    } else {
-        next -= amount;
-    }
-    T::from_usize(next)
+    let offset = amount % MAXIMUM;
+    T::from_usize((next - 1 + MAXIMUM - offset) % MAXIMUM + 1)
 }
 
 impl<T, const MAXIMUM: usize> Rotate for NumericDie<T, MAXIMUM>
 where
     T: Numeric + Add<Output = T> + Sub<Output = T>,
 {
+    /// Rotates the die directly to the resulting face, without stepping through each face.
+    ///
+    /// Like [`Rotate::rotate`], the rotated die must be used:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// use tomb::items::D6;
+    /// use tomb::traits::Rotate;
+    ///
+    /// D6::new().rotate(1);
+    /// ```
     #[allow(clippy::comparison_chain)]
-    #[must_use]
-    fn rotate(&self, amount: i8) -> Self {
+    fn rotate(&self, amount: isize) -> Self {
         if amount == 0 {
-            return self.clone();
+            return *self;
         }
         let result = if amount > 0 {
-            rotate_forward_usize::<T, MAXIMUM>(amount.unsigned_abs() as usize, self.0.as_usize())
+            rotate_forward_usize::<T, MAXIMUM>(amount.unsigned_abs(), self.0.as_usize())
         } else {
-            rotate_backward_usize::<T, MAXIMUM>(amount.unsigned_abs() as usize, self.0.as_usize())
+            rotate_backward_usize::<T, MAXIMUM>(amount.unsigned_abs(), self.0.as_usize())
         };
-        unsafe { Self::from_unchecked(result) }
+        Self::from_unchecked(result)
     }
 }
 
@@ -285,14 +711,14 @@ impl<T, const MAXIMUM: usize> RotateMut for NumericDie<T, MAXIMUM>
 where
     T: Numeric + Debug + Add<Output = T> + Sub<Output = T>,
 {
-    fn rotate_mut(&mut self, amount: i8) {
+    fn rotate_mut(&mut self, amount: isize) {
         if amount == 0 {
             return;
         }
         let result = if amount > 0 {
-            rotate_forward_usize::<T, MAXIMUM>(amount.unsigned_abs() as usize, self.0.as_usize())
+            rotate_forward_usize::<T, MAXIMUM>(amount.unsigned_abs(), self.0.as_usize())
         } else {
-            rotate_backward_usize::<T, MAXIMUM>(amount.unsigned_abs() as usize, self.0.as_usize())
+            rotate_backward_usize::<T, MAXIMUM>(amount.unsigned_abs(), self.0.as_usize())
         };
         self.0 = result;
     }
@@ -349,6 +775,24 @@ mod tests {
         D4::from(5);
     }
 
+    #[test]
+    fn numeric_die_critical() {
+        let d4 = D4::from(4);
+
+        assert_eq!(d4.natural(), 4);
+        assert!(d4.is_critical());
+        assert!(!d4.is_fumble());
+    }
+
+    #[test]
+    fn numeric_die_fumble() {
+        let d4 = D4::from(1);
+
+        assert_eq!(d4.natural(), 1);
+        assert!(!d4.is_critical());
+        assert!(d4.is_fumble());
+    }
+
     #[test]
     fn numeric_die_step_next() {
         let d4_2 = D4::from(2);
@@ -461,6 +905,22 @@ mod tests {
         assert_eq!(d4_3.value(), 3);
     }
 
+    #[test]
+    fn numeric_die_rotate_full_turn() {
+        let d20 = D20::from(20);
+
+        assert_eq!(d20.rotate(20).value(), 20);
+        assert_eq!(d20.rotate(-20).value(), 20);
+    }
+
+    #[test]
+    fn numeric_die_rotate_large() {
+        let d100 = D100::from(50);
+
+        assert_eq!(d100.rotate(250).value(), 100);
+        assert_eq!(d100.rotate(-251).value(), 99);
+    }
+
     #[test]
     fn numeric_die_rotate_next_mut() {
         let mut d4 = D4::from(2);
@@ -492,4 +952,190 @@ mod tests {
 
         assert_eq!(d4.value(), 3);
     }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn numeric_die_sample() {
+        use rand::rngs::mock::StepRng;
+        use rand::Rng;
+
+        let mut rng = StepRng::new(0, 1 << 59);
+        let values: Vec<u8> = (&mut rng).sample_iter(D6::new()).take(100).collect();
+
+        assert!(values.iter().all(|v| (1..=6).contains(v)));
+        assert!(values.iter().any(|&v| v != values[0]));
+    }
+
+    #[test]
+    fn numeric_die_display() {
+        assert_eq!(D20::from(17).to_string(), "d20 showing 17");
+        assert_eq!(D6::new().to_string(), "d6 showing 1");
+    }
+
+    #[test]
+    fn numeric_die_hash() {
+        use std::collections::HashSet;
+
+        let dice: HashSet<_> = [D6::from(3), D6::from(3), D6::from(4)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(dice.len(), 2);
+        assert!(dice.contains(&D6::from(4)));
+    }
+
+    #[test]
+    fn numeric_die_is_copy() {
+        let d6 = D6::from(2);
+        let copy = d6;
+
+        assert_eq!(d6, copy);
+    }
+
+    #[test]
+    fn numeric_die_ord() {
+        let mut pool = vec![D6::from(5), D6::from(1), D6::from(3)];
+        pool.sort();
+
+        assert_eq!(pool, vec![D6::from(1), D6::from(3), D6::from(5)]);
+        assert!(D20::from(20) > D20::from(19));
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn numeric_die_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut u = Unstructured::new(&[0, 7, 255, 19]);
+        for _ in 0..4 {
+            let die = D20::arbitrary(&mut u).unwrap();
+            assert!((1..=20).contains(&die.value()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn numeric_die_serde() {
+        assert_eq!(serde_json::to_string(&D20::from(17)).unwrap(), "17");
+        assert_eq!(serde_json::from_str::<D20>("17").unwrap(), D20::from(17));
+        assert!(serde_json::from_str::<D20>("0").is_err());
+        assert!(serde_json::from_str::<D20>("21").is_err());
+    }
+
+    #[test]
+    fn numeric_die_try_from_value() {
+        assert_eq!(D6::try_from_value(1), Ok(D6::from(1)));
+        assert_eq!(D6::try_from_value(6), Ok(D6::from(6)));
+        assert_eq!(
+            D6::try_from_value(0),
+            Err(Error::OutOfRange {
+                value: 0,
+                min: 1,
+                max: 6
+            })
+        );
+    }
+
+    #[test]
+    fn numeric_die_positional() {
+        let mut d20 = D20::from(17);
+        assert_eq!(Positional::position(&d20), 16);
+
+        d20.set_position(0);
+        assert_eq!(d20.value(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn numeric_die_set_position_out_of_range() {
+        D20::new().set_position(20);
+    }
+
+    #[test]
+    fn numeric_die_faces() {
+        let faces = D20::from(7).faces();
+
+        assert_eq!(faces.len(), 20);
+        assert_eq!(faces.sum::<u8>(), 210);
+    }
+
+    #[test]
+    fn numeric_die_into_iter() {
+        let mut faces = Vec::new();
+        for face in &D4::new() {
+            faces.push(face);
+        }
+
+        assert_eq!(faces, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn numeric_die_set() {
+        let mut d20 = D20::new();
+
+        assert_eq!(d20.set(20), Ok(()));
+        assert_eq!(d20.value(), 20);
+        assert_eq!(
+            d20.set(0),
+            Err(Error::OutOfRange {
+                value: 0,
+                min: 1,
+                max: 20
+            })
+        );
+        assert_eq!(d20.value(), 20);
+    }
+
+    #[test]
+    fn numeric_die_set_unchecked() {
+        let mut d20 = D20::new();
+        d20.set_unchecked(13);
+
+        assert_eq!(d20.value(), 13);
+    }
+
+    #[test]
+    fn numeric_die_add() {
+        assert_eq!(D100::from(100) + D100::from(100), 200);
+    }
+
+    #[test]
+    fn numeric_die_sum() {
+        let pool = vec![D6::from(6); 50];
+
+        assert_eq!(pool.iter().sum::<usize>(), 300);
+        assert_eq!(pool.into_iter().sum::<usize>(), 300);
+        assert_eq!(std::iter::empty::<D6>().sum::<usize>(), 0);
+    }
+
+    #[test]
+    fn numeric_die_into_integers() {
+        let d20 = D20::from(17);
+
+        assert_eq!(u8::from(d20), 17);
+        assert_eq!(u32::from(d20), 17);
+        assert_eq!(usize::from(d20), 17);
+        assert_eq!(u64::from(NumericDie::<u32, 1000>::from(999)), 999);
+    }
+
+    #[test]
+    fn numeric_const() {
+        const START: D6 = D6::from_value(3);
+        const TABLE: [D6; 4] = [
+            START.rotated_const(1),
+            START.rotated_const(-3),
+            START.rotated_const(10),
+            START.rotated_const(isize::MIN),
+        ];
+
+        for (die, amount) in TABLE.iter().zip([1, -3, 10, isize::MIN]) {
+            assert_eq!(*die, START.rotate(amount));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn numeric_from_value_out_of_range() {
+        let _ = D6::from_value(7);
+    }
 }
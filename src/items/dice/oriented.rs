@@ -0,0 +1,396 @@
+use crate::geometry::Solid;
+use crate::traits::{Polyhedral, Positional, Rotate, RotateMut, Step, StepMut};
+use crate::Error;
+
+/// Wraps a die to track its full orientation on a table, not only its top face.
+///
+/// Unlike rotating a die by position, which jumps from one face to any other, tipping an oriented
+/// die rolls it over an edge of its top face, so the new top face is always adjacent to the old
+/// one (see [`Solid::around`]), and the face that points north changes as it would for a physical
+/// die. [`Rotate`] (and [`Step`]) tips the die over its north edge, or back again.
+///
+/// The wrapped die's position is the top face, and every die with the same number of sides as a
+/// standard [`Solid`] can be oriented. A D6 has opposite faces that add up to `7`, and faces `1`,
+/// `2` and `3` meet counterclockwise around a corner, like most physical dice.
+///
+/// On solids with an odd number of edges around each face (i.e. a D20), tipping alternates between
+/// an edge and a corner of the top face pointing north, in which case the north face is the first
+/// face clockwise from north. A tetrahedron, which rests on a face with a corner on top, and a
+/// pentagonal trapezohedron, which has irregular faces, are tipped using the same rules.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{Oriented, D20, D6};
+/// use tomb::traits::Rotate;
+///
+/// let die = Oriented::new(D6::from(1)).unwrap();
+/// assert_eq!((die.top(), die.north()), (1, 2));
+/// assert_eq!(die.adjacent(), vec![2, 4, 5, 3]);
+///
+/// // Tip the south face up, rolling the die away from the viewer.
+/// let die = die.tip(2);
+/// assert_eq!((die.top(), die.north()), (5, 1));
+///
+/// // Tip the die over its north edge, and back.
+/// let die = die.rotate(1);
+/// assert_eq!((die.top(), die.north()), (1, 2));
+/// assert_eq!(die.rotate(-1).top(), 5);
+///
+/// let die = Oriented::new(D20::from(1)).unwrap();
+/// assert_eq!(die.tip(1).top(), 6);
+/// ```
+///
+/// A die that is not the shape of a standard solid can't be oriented:
+///
+/// ```
+/// use tomb::items::{Oriented, D3};
+/// use tomb::Error;
+///
+/// assert_eq!(Oriented::new(D3::new()), Err(Error::NotFound));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Oriented<D> {
+    die: D,
+    solid: Solid,
+    north: usize,
+    cornered: bool,
+}
+
+impl<D> Oriented<D>
+where
+    D: Positional + Clone,
+{
+    /// Creates an oriented die, where the north face is the lowest face adjacent to the top face.
+    ///
+    /// Fails with [`Error::NotFound`] if no standard solid has as many faces as the die.
+    pub fn new(die: D) -> Result<Self, Error> {
+        let solid = Solid::of::<D>().ok_or(Error::NotFound)?;
+        let north = solid.around(die.position() + 1)[0] - 1;
+        Ok(Self {
+            die,
+            solid,
+            north,
+            cornered: false,
+        })
+    }
+
+    /// Creates an oriented die, where the face at the given position faces north.
+    ///
+    /// Fails with [`Error::NotFound`] if no standard solid has as many faces as the die, or the
+    /// face is not adjacent to the top face.
+    pub fn with_north(die: D, north: usize) -> Result<Self, Error> {
+        let solid = Solid::of::<D>().ok_or(Error::NotFound)?;
+        if !solid.around(die.position() + 1).contains(&(north + 1)) {
+            return Err(Error::NotFound);
+        }
+        Ok(Self {
+            die,
+            solid,
+            north,
+            cornered: false,
+        })
+    }
+
+    /// Returns a reference to the wrapped die, showing the top face.
+    pub const fn die(&self) -> &D {
+        &self.die
+    }
+
+    /// Returns the wrapped die, showing the top face.
+    pub fn into_inner(self) -> D {
+        self.die
+    }
+
+    /// Returns the shape of the die.
+    pub const fn solid(&self) -> Solid {
+        self.solid
+    }
+
+    /// Returns the value of the face on top.
+    pub fn top(&self) -> D::Face {
+        self.die.value()
+    }
+
+    /// Returns the value of the face on the bottom, resting on the table, or `None` on a
+    /// tetrahedron.
+    pub fn bottom(&self) -> Option<D::Face> {
+        let opposite = self.solid.opposite(self.die.position() + 1)?;
+        Some(self.face(opposite - 1))
+    }
+
+    /// Returns the value of the face pointing north.
+    pub fn north(&self) -> D::Face {
+        self.face(self.north)
+    }
+
+    /// Returns the values of the faces adjacent to the top face, from north clockwise.
+    pub fn adjacent(&self) -> Vec<D::Face> {
+        let around = self.around(self.die.position());
+        let north = Self::index_of(&around, self.north);
+        (0..around.len())
+            .map(|edge| self.face(around[(north + edge) % around.len()]))
+            .collect()
+    }
+
+    /// Returns a copy of the die, tipped over the given edge of its top face.
+    ///
+    /// Edges are counted clockwise from the north edge, and wrap around, so on a D6 `0` tips the
+    /// north face up (towards the viewer), and `2` tips the south face up (away from the viewer).
+    #[must_use]
+    pub fn tip(&self, edge: usize) -> Self {
+        let mut tipped = self.clone();
+        tipped.tip_mut(edge);
+        tipped
+    }
+
+    /// Tips the die over the given edge of its top face.
+    ///
+    /// See [`Oriented::tip`] for how edges are counted.
+    pub fn tip_mut(&mut self, edge: usize) {
+        let top = self.die.position();
+        let around = self.around(top);
+        let edges = around.len();
+        let edge = edge % edges;
+        let next = around[(Self::index_of(&around, self.north) + edge) % edges];
+
+        // The edge tipped over ends up opposite where it was, which on a face with an odd number of
+        // edges moves north between an edge and a corner, measured in halves of an edge.
+        let cornered = self.cornered ^ (edges % 2 == 1);
+        let halves = usize::from(self.cornered) + edges - usize::from(cornered);
+        let back = (edge + halves / 2) % edges;
+
+        let around = self.around(next);
+        let north = (Self::index_of(&around, top) + edges - back) % edges;
+        self.die.set_position(next);
+        self.north = around[north];
+        self.cornered = cornered;
+    }
+
+    /// Returns the edge of the top face that tips the die back to how it was before tipping it
+    /// over the north edge.
+    fn back_edge(&self) -> usize {
+        let edges = self.solid.edges_per_face();
+        let cornered = self.cornered ^ (edges % 2 == 1);
+        (usize::from(cornered) + edges - usize::from(self.cornered)) / 2 % edges
+    }
+
+    /// Returns the positions of the faces around the face at the given position, clockwise.
+    fn around(&self, position: usize) -> Vec<usize> {
+        self.solid
+            .around(position + 1)
+            .iter()
+            .map(|face| face - 1)
+            .collect()
+    }
+
+    /// Returns the index of the given position in the positions around a face.
+    fn index_of(around: &[usize], position: usize) -> usize {
+        around
+            .iter()
+            .position(|&p| p == position)
+            .unwrap_or_default()
+    }
+
+    /// Returns the value of the face at the given position.
+    fn face(&self, position: usize) -> D::Face {
+        let mut die = self.die.clone();
+        die.set_position(position);
+        die.value()
+    }
+}
+
+impl<D> Polyhedral for Oriented<D>
+where
+    D: Polyhedral,
+{
+    type Face = D::Face;
+
+    fn sides() -> usize {
+        D::sides()
+    }
+
+    fn value(&self) -> D::Face {
+        self.die.value()
+    }
+}
+
+impl<D> Step for Oriented<D>
+where
+    D: Positional + Clone,
+{
+    /// Tips the die over its north edge.
+    fn next(&self) -> Self {
+        self.tip(0)
+    }
+
+    /// Tips the die back, undoing [`Step::next`].
+    fn back(&self) -> Self {
+        self.tip(self.back_edge())
+    }
+}
+
+impl<D> StepMut for Oriented<D>
+where
+    D: Positional + Clone,
+{
+    /// Tips the die over its north edge.
+    fn next_mut(&mut self) {
+        self.tip_mut(0);
+    }
+
+    /// Tips the die back, undoing [`StepMut::next_mut`].
+    fn back_mut(&mut self) {
+        self.tip_mut(self.back_edge());
+    }
+}
+
+impl<D> Rotate for Oriented<D> where D: Positional + Clone {}
+
+impl<D> RotateMut for Oriented<D> where D: Positional + Clone {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{D10, D12, D20, D4, D6, D8};
+
+    /// Returns every orientation of a die, by tipping it from the given orientation.
+    fn orientations<D>(die: Oriented<D>) -> Vec<Oriented<D>>
+    where
+        D: Positional + Clone + PartialEq,
+    {
+        let edges = die.solid().edges_per_face();
+        let mut found = vec![die];
+        let mut index = 0;
+        while index < found.len() {
+            for edge in 0..edges {
+                let tipped = found[index].tip(edge);
+                if !found.contains(&tipped) {
+                    found.push(tipped);
+                }
+            }
+            index += 1;
+        }
+        found
+    }
+
+    #[test]
+    fn oriented_new() {
+        let die = Oriented::new(D6::from(4)).unwrap();
+
+        assert_eq!(die.top(), 4);
+        assert_eq!(die.bottom(), Some(3));
+        assert_eq!(die.adjacent(), vec![1, 2, 6, 5]);
+    }
+
+    #[test]
+    fn oriented_unknown_solid() {
+        assert_eq!(Oriented::new(crate::items::D3::new()), Err(Error::NotFound));
+    }
+
+    #[test]
+    fn oriented_not_adjacent() {
+        assert_eq!(Oriented::with_north(D6::from(1), 5), Err(Error::NotFound));
+        assert_eq!(
+            Oriented::with_north(D6::from(1), 2).map(|d| d.north()),
+            Ok(3)
+        );
+    }
+
+    #[test]
+    fn oriented_opposites() {
+        let die = Oriented::with_north(D6::from(2), 2).unwrap();
+        let adjacent = die.adjacent();
+
+        assert_eq!(die.top() + die.bottom().unwrap(), 7);
+        assert_eq!(adjacent[0] + adjacent[2], 7);
+        assert_eq!(adjacent[1] + adjacent[3], 7);
+    }
+
+    #[test]
+    fn oriented_tetrahedron_has_no_bottom() {
+        let die = Oriented::new(D4::from(1)).unwrap();
+
+        assert_eq!(die.bottom(), None);
+        assert_eq!(die.adjacent(), vec![2, 4, 3]);
+    }
+
+    #[test]
+    fn oriented_tip_and_back() {
+        let die = Oriented::new(D6::from(3)).unwrap();
+
+        for (there, back) in [(0, 2), (1, 3)] {
+            assert_eq!(die.tip(there).tip(back), die);
+        }
+    }
+
+    #[test]
+    fn oriented_tip_full_turn() {
+        let mut die = Oriented::new(D6::from(1)).unwrap();
+        let mut tops = Vec::new();
+        for _ in 0..4 {
+            die.tip_mut(1);
+            tops.push(die.top());
+        }
+
+        assert_eq!(tops, vec![4, 6, 3, 1]);
+        assert_eq!(die, Oriented::new(D6::from(1)).unwrap());
+    }
+
+    #[test]
+    fn oriented_tip_wraps() {
+        let die = Oriented::new(D8::from(1)).unwrap();
+
+        assert_eq!(die.tip(4), die.tip(1));
+    }
+
+    #[test]
+    fn oriented_tip_stays_adjacent() {
+        let die = Oriented::new(D20::from(5)).unwrap();
+
+        for edge in 0..3 {
+            assert!(die.adjacent().contains(&die.tip(edge).top()));
+        }
+    }
+
+    #[test]
+    fn oriented_rotate_is_tipping() {
+        let die = Oriented::new(D12::from(1)).unwrap();
+
+        assert_eq!(die.rotate(1), die.tip(0));
+        assert_eq!(die.rotate(3), die.tip(0).tip(0).tip(0));
+        assert_eq!(die.rotate(-1).rotate(1), die);
+
+        let mut rotated = die;
+        rotated.rotate_mut(-2);
+        assert_eq!(rotated, die.back().back());
+    }
+
+    #[test]
+    fn oriented_every_solid_reversible() {
+        fn check<D: Positional + Clone + PartialEq + std::fmt::Debug>(die: D, count: usize) {
+            let orientations = orientations(Oriented::new(die).unwrap());
+            assert_eq!(orientations.len(), count);
+            for die in orientations {
+                assert_eq!(die.next().back(), die);
+                assert_eq!(die.back().next(), die);
+                for edge in 0..die.solid().edges_per_face() {
+                    let tipped = die.tip(edge);
+                    assert!(tipped
+                        .solid()
+                        .is_adjacent(die.die().position() + 1, tipped.die().position() + 1));
+                }
+            }
+        }
+
+        // Every face on top, pointing north at each edge (and corner, on odd faces), except that
+        // a rolled tetrahedron always lands the same way on the same spot of the table, and an
+        // octahedron only ever points north at either an edge or a corner of each face.
+        check(D4::new(), 4 * 2);
+        check(D6::new(), 6 * 4);
+        check(D8::new(), 8 * 3);
+        check(D10::new(), 10 * 4);
+        check(D12::new(), 12 * 5 * 2);
+        check(D20::new(), 20 * 3 * 2);
+    }
+}
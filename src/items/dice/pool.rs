@@ -0,0 +1,68 @@
+use std::fmt::Display;
+
+use crate::traits::Critical;
+
+/// Formats a pool (a slice or array) of dice with the same number of sides.
+///
+/// The natural face of each die is listed in order, followed by the sum.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{PoolDisplay, D6};
+///
+/// let pool = [D6::from(2), D6::from(5), D6::from(6)];
+///
+/// assert_eq!(PoolDisplay::new(&pool).to_string(), "3d6: [2, 5, 6] = 13");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PoolDisplay<'a, D> {
+    dice: &'a [D],
+}
+
+impl<'a, D> PoolDisplay<'a, D>
+where
+    D: Critical,
+{
+    /// Creates a formatter for the given pool of dice.
+    pub const fn new(dice: &'a [D]) -> Self {
+        Self { dice }
+    }
+}
+
+impl<D> Display for PoolDisplay<'_, D>
+where
+    D: Critical,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}d{}: [", self.dice.len(), D::sides())?;
+        for (i, die) in self.dice.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", die.natural())?;
+        }
+        let sum: usize = self.dice.iter().map(|d| d.natural()).sum();
+        write!(f, "] = {sum}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{D20, D6};
+
+    #[test]
+    fn pool_display_empty() {
+        let pool: [D20; 0] = [];
+
+        assert_eq!(PoolDisplay::new(&pool).to_string(), "0d20: [] = 0");
+    }
+
+    #[test]
+    fn pool_display_vec() {
+        let pool = vec![D6::from(4); 2];
+
+        assert_eq!(PoolDisplay::new(&pool).to_string(), "2d6: [4, 4] = 8");
+    }
+}
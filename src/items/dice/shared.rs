@@ -0,0 +1,417 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use super::SliceDie;
+use crate::traits::{Rotate, RotateMut, SideSelector, Snapshot, Step, StepMut};
+use crate::Error;
+
+/// A die like [`crate::items::SliceDie`], but which shares ownership of its sides.
+///
+/// A `SliceDie` borrows its sides, so it can't outlive them, which is impossible in `'static`
+/// contexts (i.e. a thread or an async task) unless the sides are also `'static`. A `SharedDie`
+/// stores its sides in an [`Arc`] instead, so any number of dice (across threads) can share one
+/// set of sides, and cloning a die never clones the sides.
+///
+/// Like a [`crate::items::Die`], the number of sides is only known at runtime (i.e. sides loaded
+/// from a configuration file), so a shared die is rolled directly with a [`SideSelector`] instead
+/// of a [`crate::traits::Roll`]. Once the number of sides is known, [`SharedDie::as_sized`]
+/// borrows the die as a `SliceDie`, which implements the traits of dice.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// use tomb::items::{SequenceRoller, SharedDie};
+/// use tomb::traits::Step;
+///
+/// let die = SharedDie::new(Arc::new([String::from("hit"), String::from("miss")]));
+/// let next = thread::spawn({
+///     let die = die.clone();
+///     move || die.next()
+/// });
+///
+/// assert_eq!(die.value(), "hit");
+/// assert_eq!(next.join().unwrap().value(), "miss");
+///
+/// let roller = SequenceRoller::new([1]);
+/// assert_eq!(die.roll(&roller).value(), "miss");
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct SharedDie<T> {
+    position: usize,
+    elements: Arc<[T]>,
+}
+
+impl<T> Clone for SharedDie<T> {
+    /// Clones the die, which only shares its sides, so the sides do not need to be [`Clone`].
+    fn clone(&self) -> Self {
+        Self {
+            position: self.position,
+            elements: Arc::clone(&self.elements),
+        }
+    }
+}
+
+impl<T> SharedDie<T> {
+    /// Creates a new die from the given possible sides of the die.
+    ///
+    /// The current position is set to `0`, or the first element.
+    ///
+    /// # Panics
+    ///
+    /// If there are no sides; see [`SharedDie::try_from`] for a non-panicking alternative.
+    pub fn new(elements: Arc<[T]>) -> Self {
+        assert!(!elements.is_empty());
+        Self {
+            elements,
+            position: 0,
+        }
+    }
+
+    /// Creates a new die starting at the given position.
+    ///
+    /// # Panics
+    ///
+    /// If the value is out of bounds; see [`SharedDie::try_with_position`] for a non-panicking
+    /// alternative.
+    pub fn with_position(elements: Arc<[T]>, position: usize) -> Self {
+        assert!(position < elements.len());
+        Self { elements, position }
+    }
+
+    /// Creates a new die starting at the given position, or an error if the position is out of
+    /// bounds (or there are no sides).
+    pub fn try_with_position(elements: Arc<[T]>, position: usize) -> Result<Self, Error> {
+        if position >= elements.len() {
+            return Err(Error::OutOfRange {
+                value: position,
+                min: 0,
+                max: elements.len().saturating_sub(1),
+            });
+        }
+        Ok(Self { elements, position })
+    }
+
+    /// Returns the current position within the die, between `0..self.sides()`.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Changes the current face to the face at the given position.
+    ///
+    /// # Panics
+    ///
+    /// If the position is not within `0..self.sides()`.
+    pub fn set_position(&mut self, position: usize) {
+        assert!(position < self.sides());
+        self.position = position;
+    }
+
+    /// Returns the number of sides.
+    pub fn sides(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns the shared sides of the die.
+    pub fn faces(&self) -> &Arc<[T]> {
+        &self.elements
+    }
+
+    /// Returns a reference to the currently faced value.
+    pub fn value(&self) -> &T {
+        &self.elements[self.position]
+    }
+
+    /// Returns a copy of the die, showing the face opposite the current face.
+    #[must_use]
+    pub fn opposite(&self) -> Self {
+        Self {
+            position: self.sides() - 1 - self.position,
+            elements: Arc::clone(&self.elements),
+        }
+    }
+
+    /// Returns the die as a [`SliceDie`] with `SIDES` sides borrowing the shared sides, or an
+    /// error if it has more or fewer.
+    ///
+    /// # Errors
+    ///
+    /// If the die does not have exactly `SIDES` sides, returns [`Error::OutOfRange`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use tomb::items::SharedDie;
+    /// use tomb::traits::{Coin, Critical};
+    ///
+    /// let die = SharedDie::with_position(Arc::new(['H', 'T']), 1);
+    ///
+    /// assert!(die.as_sized::<2>().unwrap().is_tails());
+    /// assert!(die.as_sized::<2>().unwrap().is_critical());
+    /// assert!(die.as_sized::<3>().is_err());
+    /// ```
+    pub fn as_sized<const SIDES: usize>(&self) -> Result<SliceDie<'_, T, SIDES>, Error> {
+        let elements: &[T; SIDES] =
+            self.elements[..]
+                .try_into()
+                .map_err(|_| Error::OutOfRange {
+                    value: self.sides(),
+                    min: SIDES,
+                    max: SIDES,
+                })?;
+        Ok(SliceDie::with_position(elements, self.position))
+    }
+
+    /// Returns a copy of the die, showing a [selected](SideSelector::select) face.
+    #[must_use]
+    pub fn roll<S>(&self, selector: &S) -> Self
+    where
+        S: SideSelector,
+    {
+        let mut die = self.clone();
+        die.roll_mut(selector);
+        die
+    }
+
+    /// Changes the die to show a [selected](SideSelector::select) face.
+    pub fn roll_mut<S>(&mut self, selector: &S)
+    where
+        S: SideSelector,
+    {
+        self.position = selector.select(self.sides());
+    }
+
+    /// Returns the position the given amount away from the current position, wrapping around.
+    fn rotated_position(&self, amount: isize) -> usize {
+        let position = self.position as i128 + amount as i128;
+        position.rem_euclid(self.sides() as i128) as usize
+    }
+}
+
+impl<T, const LENGTH: usize> From<Arc<[T; LENGTH]>> for SharedDie<T> {
+    /// Converts shared sides into a die of the same length.
+    ///
+    /// # Panics
+    ///
+    /// If there are no sides.
+    fn from(elements: Arc<[T; LENGTH]>) -> Self {
+        Self::new(elements)
+    }
+}
+
+impl<T, const LENGTH: usize> From<[T; LENGTH]> for SharedDie<T> {
+    /// Converts sides into a die of the same length, moving the sides into an [`Arc`].
+    ///
+    /// # Panics
+    ///
+    /// If there are no sides.
+    fn from(elements: [T; LENGTH]) -> Self {
+        Self::new(Arc::new(elements))
+    }
+}
+
+impl<T> TryFrom<Arc<[T]>> for SharedDie<T> {
+    type Error = Error;
+
+    /// Converts shared sides of any length into a die, or an error if there are no sides.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use tomb::items::SharedDie;
+    ///
+    /// let sides: Arc<[char]> = Arc::from(vec!['A', 'B', 'C']);
+    /// let empty: Arc<[char]> = Arc::from(vec![]);
+    ///
+    /// assert_eq!(SharedDie::try_from(sides).map(|d| d.sides()), Ok(3));
+    /// assert!(SharedDie::try_from(empty).is_err());
+    /// ```
+    fn try_from(elements: Arc<[T]>) -> Result<Self, Self::Error> {
+        if elements.is_empty() {
+            return Err(Error::OutOfRange {
+                value: 0,
+                min: 1,
+                max: usize::MAX,
+            });
+        }
+        Ok(Self::new(elements))
+    }
+}
+
+impl<T> Display for SharedDie<T>
+where
+    T: Display,
+{
+    /// Formats the die as its number of sides and current value, i.e. `d2 showing heads`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "d{} showing {}", self.sides(), self.value())
+    }
+}
+
+impl<T> Snapshot for SharedDie<T> {
+    type Memento = usize;
+
+    fn snapshot(&self) -> usize {
+        self.position
+    }
+
+    fn restore(&mut self, memento: &usize) {
+        self.set_position(*memento);
+    }
+}
+
+impl<T> Step for SharedDie<T> {
+    /// Rotates the die forward by one element, wrapping around to the first element.
+    fn next(&self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Rotates the die backwards by one element, wrapping around to the last element.
+    fn back(&self) -> Self {
+        self.rotate(-1)
+    }
+}
+
+impl<T> StepMut for SharedDie<T> {
+    /// Rotates the die forward by one element, wrapping around to the first element.
+    fn next_mut(&mut self) {
+        self.rotate_mut(1);
+    }
+
+    /// Rotates the die backwards by one element, wrapping around to the last element.
+    fn back_mut(&mut self) {
+        self.rotate_mut(-1);
+    }
+}
+
+impl<T> Rotate for SharedDie<T> {
+    fn rotate(&self, amount: isize) -> Self {
+        Self {
+            position: self.rotated_position(amount),
+            elements: Arc::clone(&self.elements),
+        }
+    }
+}
+
+impl<T> RotateMut for SharedDie<T> {
+    fn rotate_mut(&mut self, amount: isize) {
+        self.position = self.rotated_position(amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::SequenceRoller;
+    use crate::traits::{Critical, Polyhedral, Roll};
+
+    const GRADES: [char; 5] = ['A', 'B', 'C', 'D', 'F'];
+
+    #[test]
+    fn shared_clone_shares_sides() {
+        let a = SharedDie::from(GRADES);
+        let b = a.next();
+
+        assert!(Arc::ptr_eq(a.faces(), b.faces()));
+        assert_eq!(a, SharedDie::from(GRADES));
+        assert_eq!(b.value(), &'B');
+    }
+
+    #[test]
+    fn shared_step_wraps() {
+        let mut d = SharedDie::from(GRADES);
+        d.back_mut();
+
+        assert_eq!(d.value(), &'F');
+        assert_eq!(d.next().value(), &'A');
+        assert_eq!(d.back().value(), &'D');
+    }
+
+    #[test]
+    fn shared_rotate() {
+        let mut d = SharedDie::from(GRADES);
+
+        assert_eq!(d.rotate(1001).value(), &'B');
+        d.rotate_mut(-1001);
+        assert_eq!(d.value(), &'F');
+        assert_eq!(d.rotate(isize::MIN).position(), 1);
+    }
+
+    #[test]
+    fn shared_try_with_position() {
+        let sides: Arc<[char]> = Arc::new(GRADES);
+
+        assert_eq!(
+            SharedDie::try_with_position(Arc::clone(&sides), 4).map(|d| d.to_string()),
+            Ok("d5 showing F".to_string())
+        );
+        assert_eq!(
+            SharedDie::try_with_position(sides, 5),
+            Err(Error::OutOfRange {
+                value: 5,
+                min: 0,
+                max: 4
+            })
+        );
+    }
+
+    #[test]
+    fn shared_runtime_sides() {
+        let sides: Arc<[char]> = Arc::from(&GRADES[..4]);
+        let die = SharedDie::try_from(sides).unwrap();
+
+        assert_eq!(die.sides(), 4);
+        assert_eq!(die.roll(&SequenceRoller::new([3])).value(), &'D');
+    }
+
+    #[test]
+    #[should_panic]
+    fn shared_no_sides() {
+        SharedDie::<char>::new(Arc::from(vec![]));
+    }
+
+    #[test]
+    fn shared_as_sized() {
+        let die = SharedDie::with_position(Arc::new(GRADES), 4);
+        let sized = die.as_sized::<5>().unwrap();
+
+        assert_eq!(Polyhedral::value(&sized), &'F');
+        assert!(sized.is_critical());
+        assert_eq!(
+            SequenceRoller::new([1]).roll(&sized).value(),
+            &'B',
+            "rolled as a slice die"
+        );
+        assert_eq!(
+            die.as_sized::<4>(),
+            Err(Error::OutOfRange {
+                value: 5,
+                min: 4,
+                max: 4
+            })
+        );
+    }
+
+    #[test]
+    fn shared_opposite() {
+        let die = SharedDie::with_position(Arc::new(GRADES), 1);
+
+        assert_eq!(die.opposite().value(), &'D');
+    }
+
+    #[test]
+    fn shared_snapshot() {
+        let mut die = SharedDie::from(GRADES);
+        let memento = die.snapshot();
+        die.next_mut();
+        die.restore(&memento);
+
+        assert_eq!(die.value(), &'A');
+    }
+}
@@ -1,4 +1,10 @@
-use crate::traits::{Polyhedral, Rotate, RotateMut, Step, StepMut};
+use std::fmt::Display;
+
+use crate::traits::{
+    Coin, Critical, Faces, OppositeFaces, Polyhedral, Positional, Rotate, RotateMut, SideSelector,
+    Snapshot, Step, StepMut,
+};
+use crate::Error;
 
 /// A die that has a known and fixed set of values, and a position that points at the current value.
 ///
@@ -16,12 +22,22 @@ use crate::traits::{Polyhedral, Rotate, RotateMut, Step, StepMut};
 ///
 /// SliceDie::from(&GRADES);
 /// ```
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct SliceDie<'a, T, const LENGTH: usize> {
     position: usize,
     elements: &'a [T; LENGTH],
 }
 
+impl<T, const LENGTH: usize> Clone for SliceDie<'_, T, LENGTH> {
+    /// Clones the die, which only borrows its sides, so the sides do not need to be [`Clone`].
+    fn clone(&self) -> Self {
+        Self {
+            position: self.position,
+            elements: self.elements,
+        }
+    }
+}
+
 impl<'a, T, const LENGTH: usize> SliceDie<'a, T, LENGTH> {
     /// Creates a new slice die from the given possible sides of the die.
     ///
@@ -38,12 +54,14 @@ impl<'a, T, const LENGTH: usize> SliceDie<'a, T, LENGTH> {
         }
     }
 
-    /// Creates a new die starting at the given `value`.
-    ///
-    /// # Safety
+    /// Creates a new die starting at the given position, without checking the position.
     ///
-    /// The value is _not_ checked for bounds correctness, and could cause undefined behavior.
-    pub unsafe fn from_unchecked(position: usize, elements: &'a [T; LENGTH]) -> Self {
+    /// The position is only checked in debug builds, so prefer [`SliceDie::with_position`] or
+    /// [`SliceDie::try_with_position`] unless the position is already known to be in bounds. This
+    /// is always memory safe, but a die with an out of bounds position panics when its value is
+    /// read.
+    pub fn from_unchecked(position: usize, elements: &'a [T; LENGTH]) -> Self {
+        debug_assert!(position < LENGTH);
         Self { elements, position }
     }
 
@@ -51,12 +69,37 @@ impl<'a, T, const LENGTH: usize> SliceDie<'a, T, LENGTH> {
     ///
     /// # Panics
     ///
-    /// If the value is out of bounds.
+    /// If the value is out of bounds; see [`SliceDie::try_with_position`] for a non-panicking
+    /// alternative.
     pub fn with_position(elements: &'a [T; LENGTH], position: usize) -> Self {
         assert!(position < LENGTH);
         Self { elements, position }
     }
 
+    /// Creates a new die starting at the given position, or an error if the position is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::items::SliceDie;
+    ///
+    /// const GRADES: [char; 5] = ['A', 'B', 'C', 'D', 'F'];
+    ///
+    /// assert_eq!(SliceDie::try_with_position(&GRADES, 1).map(|d| *d.value()), Ok('B'));
+    /// assert!(SliceDie::try_with_position(&GRADES, 5).is_err());
+    /// ```
+    pub fn try_with_position(elements: &'a [T; LENGTH], position: usize) -> Result<Self, Error> {
+        if position >= LENGTH {
+            return Err(Error::OutOfRange {
+                value: position,
+                min: 0,
+                max: LENGTH - 1,
+            });
+        }
+        Ok(Self { elements, position })
+    }
+
     /// Returns the current position within the die, between `0..self.len()`.
     pub const fn position(&self) -> usize {
         self.position
@@ -75,6 +118,40 @@ impl<'a, T, const LENGTH: usize> SliceDie<'a, T, LENGTH> {
     }
 }
 
+impl<T, const LENGTH: usize> SliceDie<'_, T, LENGTH>
+where
+    T: PartialEq,
+{
+    /// Returns the position of the first side equal to the given value, if any.
+    pub fn position_of(&self, value: &T) -> Option<usize> {
+        self.elements.iter().position(|side| side == value)
+    }
+
+    /// Changes the die to show the first side equal to the given value, or returns an error if no
+    /// side is equal to the value.
+    ///
+    /// On error, the die is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::items::SliceDie;
+    ///
+    /// const GRADES: [char; 5] = ['A', 'B', 'C', 'D', 'F'];
+    /// let mut d = SliceDie::new(&GRADES);
+    ///
+    /// assert!(d.set_value(&'D').is_ok());
+    /// assert_eq!(d.position(), 3);
+    ///
+    /// assert!(d.set_value(&'E').is_err());
+    /// assert_eq!(d.value(), &'D');
+    /// ```
+    pub fn set_value(&mut self, value: &T) -> Result<(), Error> {
+        self.position = self.position_of(value).ok_or(Error::NotFound)?;
+        Ok(())
+    }
+}
+
 impl<'a, T, const LENGTH: usize> From<&'a [T; LENGTH]> for SliceDie<'a, T, LENGTH> {
     /// Converts a slice of elements into a die of the same length.
     ///
@@ -88,10 +165,97 @@ impl<'a, T, const LENGTH: usize> From<&'a [T; LENGTH]> for SliceDie<'a, T, LENGT
     }
 }
 
-impl<T, const MAXIMUM: usize> Polyhedral for SliceDie<'_, T, MAXIMUM> {
+impl<T, const LENGTH: usize> Display for SliceDie<'_, T, LENGTH>
+where
+    T: Display,
+{
+    /// Formats the die as its number of sides and current value, i.e. `d2 showing heads`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "d{} showing {}", LENGTH, self.value())
+    }
+}
+
+impl<'a, T, const MAXIMUM: usize> Polyhedral for SliceDie<'a, T, MAXIMUM> {
+    type Face = &'a T;
+
     fn sides() -> usize {
         MAXIMUM
     }
+
+    fn value(&self) -> &'a T {
+        &self.elements[self.position]
+    }
+}
+
+impl<'a, T, const MAXIMUM: usize> IntoIterator for &SliceDie<'a, T, MAXIMUM> {
+    type Item = &'a T;
+    type IntoIter = Faces<SliceDie<'a, T, MAXIMUM>>;
+
+    /// Returns an iterator over every side of the die, in order; see [`Positional::faces`].
+    fn into_iter(self) -> Self::IntoIter {
+        self.faces()
+    }
+}
+
+impl<T, const LENGTH: usize> OppositeFaces for SliceDie<'_, T, LENGTH> {}
+
+impl<T> Coin for SliceDie<'_, T, 2> {
+    /// Returns whether the first face is showing.
+    fn is_heads(&self) -> bool {
+        self.position() == 0
+    }
+
+    /// Turns the coin over, showing the other face.
+    fn swap(&mut self) {
+        self.set_position(1 - self.position());
+    }
+
+    /// Changes the die to show the face at a [selected](SideSelector::select) position.
+    fn toss_mut<S>(&mut self, selector: &S)
+    where
+        S: SideSelector,
+    {
+        selector.pick_mut(self);
+    }
+}
+
+impl<T, const MAXIMUM: usize> Positional for SliceDie<'_, T, MAXIMUM> {
+    fn position(&self) -> usize {
+        self.position
+    }
+
+    fn set_position(&mut self, position: usize) {
+        assert!(position < MAXIMUM);
+        self.position = position;
+    }
+}
+
+impl<T, const MAXIMUM: usize> Snapshot for SliceDie<'_, T, MAXIMUM> {
+    type Memento = usize;
+
+    fn snapshot(&self) -> usize {
+        self.position()
+    }
+
+    fn restore(&mut self, memento: &usize) {
+        self.set_position(*memento);
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<'a, T, const LENGTH: usize> rand::distributions::Distribution<&'a T>
+    for SliceDie<'a, T, LENGTH>
+{
+    /// Samples one of the elements, with equal odds.
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> &'a T {
+        &self.elements[rng.gen_range(0..LENGTH)]
+    }
+}
+
+impl<T, const MAXIMUM: usize> Critical for SliceDie<'_, T, MAXIMUM> {
+    fn natural(&self) -> usize {
+        self.position + 1
+    }
 }
 
 impl<'a, T, const MAXIMUM: usize> Step for SliceDie<'a, T, MAXIMUM> {
@@ -103,7 +267,7 @@ impl<'a, T, const MAXIMUM: usize> Step for SliceDie<'a, T, MAXIMUM> {
         if next == MAXIMUM {
             next = 0;
         }
-        unsafe { Self::from_unchecked(next, self.elements) }
+        Self::from_unchecked(next, self.elements)
     }
 
     /// Rotates the die backwards by one element.
@@ -116,7 +280,7 @@ impl<'a, T, const MAXIMUM: usize> Step for SliceDie<'a, T, MAXIMUM> {
         } else {
             next -= 1;
         }
-        unsafe { Self::from_unchecked(next, self.elements) }
+        Self::from_unchecked(next, self.elements)
     }
 }
 
@@ -148,19 +312,12 @@ impl<'a, T, const MAXIMUM: usize> StepMut for SliceDie<'a, T, MAXIMUM> {
 
 fn rotate_forward_usize<const MAXIMUM: usize>(position: usize, amount: usize) -> usize {
     debug_assert!(amount > 0);
-    (position + amount) % MAXIMUM
+    (position + amount % MAXIMUM) % MAXIMUM
 }
 
-fn rotate_backward_usize<const MAXIMUM: usize>(position: usize, amount: i8) -> usize {
-    let current = position as i8;
-    let rotated = current + amount;
-    if rotated >= 0 {
-        return rotated.unsigned_abs() as usize;
-    }
-    let size = MAXIMUM as i8;
-    let rotated = rotated % size + size;
-    debug_assert!(rotated >= 0);
-    rotated as usize
+fn rotate_backward_usize<const MAXIMUM: usize>(position: usize, amount: isize) -> usize {
+    debug_assert!(amount < 0);
+    (position + MAXIMUM - amount.unsigned_abs() % MAXIMUM) % MAXIMUM
 }
 
 impl<'a, T, const MAXIMUM: usize> Rotate for SliceDie<'a, T, MAXIMUM>
@@ -168,26 +325,26 @@ where
     T: Clone,
 {
     #[allow(clippy::comparison_chain)]
-    fn rotate(&self, amount: i8) -> Self {
+    fn rotate(&self, amount: isize) -> Self {
         if amount == 0 {
             return self.clone();
         }
         let position = if amount > 0 {
-            rotate_forward_usize::<MAXIMUM>(self.position, amount.unsigned_abs() as usize)
+            rotate_forward_usize::<MAXIMUM>(self.position, amount.unsigned_abs())
         } else {
             rotate_backward_usize::<MAXIMUM>(self.position, amount)
         };
-        unsafe { Self::from_unchecked(position, self.elements) }
+        Self::from_unchecked(position, self.elements)
     }
 }
 
 impl<'a, T, const MAXIMUM: usize> RotateMut for SliceDie<'a, T, MAXIMUM> {
-    fn rotate_mut(&mut self, amount: i8) {
+    fn rotate_mut(&mut self, amount: isize) {
         if amount == 0 {
             return;
         }
         let position = if amount > 0 {
-            rotate_forward_usize::<MAXIMUM>(self.position, amount.unsigned_abs() as usize)
+            rotate_forward_usize::<MAXIMUM>(self.position, amount.unsigned_abs())
         } else {
             rotate_backward_usize::<MAXIMUM>(self.position, amount)
         };
@@ -312,6 +469,24 @@ mod tests {
         assert_eq!(get_sides(d), GRADES.len());
     }
 
+    #[test]
+    fn slice_critical() {
+        let d = GradeDie::with_position(&GRADES, 4);
+
+        assert_eq!(d.natural(), 5);
+        assert!(d.is_critical());
+        assert!(!d.is_fumble());
+    }
+
+    #[test]
+    fn slice_fumble() {
+        let d = GradeDie::new(&GRADES);
+
+        assert_eq!(d.natural(), 1);
+        assert!(!d.is_critical());
+        assert!(d.is_fumble());
+    }
+
     #[test]
     fn slice_rotate_none() {
         let d = GradeDie::new(&GRADES);
@@ -360,6 +535,22 @@ mod tests {
         assert_eq!(r.value(), &'F');
     }
 
+    #[test]
+    fn slice_rotate_full_turn() {
+        let d = GradeDie::new(&GRADES);
+
+        assert_eq!(d.rotate(5).value(), &'A');
+        assert_eq!(d.rotate(-5).value(), &'A');
+    }
+
+    #[test]
+    fn slice_rotate_large() {
+        let d = GradeDie::new(&GRADES);
+
+        assert_eq!(d.rotate(1001).value(), &'B');
+        assert_eq!(d.rotate(-1001).value(), &'F');
+    }
+
     #[test]
     fn numeric_die_rotate_next_mut() {
         let mut d = GradeDie::new(&GRADES);
@@ -391,4 +582,111 @@ mod tests {
 
         assert_eq!(d.value(), &'F');
     }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn slice_die_sample() {
+        use rand::rngs::mock::StepRng;
+        use rand::Rng;
+
+        let elements = ['a', 'b', 'c'];
+        let die = SliceDie::new(&elements);
+        let mut rng = StepRng::new(0, 1 << 60);
+
+        for _ in 0..10 {
+            assert!(elements.contains(rng.sample(die.clone())));
+        }
+    }
+
+    #[test]
+    fn slice_die_display() {
+        let die = SliceDie::with_position(&["heads", "tails"], 1);
+
+        assert_eq!(die.to_string(), "d2 showing tails");
+    }
+
+    #[test]
+    fn slice_die_hash() {
+        use std::collections::HashMap;
+
+        let faces = ['x', 'y'];
+        let mut seen = HashMap::new();
+        *seen.entry(SliceDie::new(&faces)).or_insert(0) += 1;
+        *seen.entry(SliceDie::new(&faces)).or_insert(0) += 1;
+
+        assert_eq!(seen[&SliceDie::new(&faces)], 2);
+    }
+
+    #[test]
+    fn slice_try_with_position() {
+        assert_eq!(
+            GradeDie::try_with_position(&GRADES, 4),
+            Ok(GradeDie::with_position(&GRADES, 4))
+        );
+        assert_eq!(
+            GradeDie::try_with_position(&GRADES, 5),
+            Err(Error::OutOfRange {
+                value: 5,
+                min: 0,
+                max: 4
+            })
+        );
+    }
+
+    #[test]
+    fn slice_from_unchecked() {
+        let d = GradeDie::from_unchecked(3, &GRADES);
+
+        assert_eq!(d.value(), &'D');
+    }
+
+    #[test]
+    fn slice_positional() {
+        let mut d = GradeDie::new(&GRADES);
+        d.set_position(4);
+
+        assert_eq!(Positional::position(&d), 4);
+        assert_eq!(d.value(), &'F');
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_set_position_out_of_range() {
+        GradeDie::new(&GRADES).set_position(5);
+    }
+
+    #[test]
+    fn slice_into_iter() {
+        let d = GradeDie::with_position(&GRADES, 2);
+        let faces: String = (&d).into_iter().collect();
+
+        assert_eq!(faces, "ABCDF");
+    }
+
+    #[test]
+    fn slice_position_of() {
+        let d = GradeDie::new(&GRADES);
+
+        assert_eq!(d.position_of(&'A'), Some(0));
+        assert_eq!(d.position_of(&'F'), Some(4));
+        assert_eq!(d.position_of(&'E'), None);
+    }
+
+    #[test]
+    fn slice_set_value_first_match() {
+        const SIDES: [u8; 4] = [1, 2, 2, 3];
+        let mut d = SliceDie::new(&SIDES);
+
+        assert_eq!(d.set_value(&2), Ok(()));
+        assert_eq!(d.position(), 1);
+        assert_eq!(d.set_value(&4), Err(Error::NotFound));
+        assert_eq!(d.position(), 1);
+    }
+
+    #[test]
+    fn slice_opposite() {
+        let die = GradeDie::try_with_position(&GRADES, 1).unwrap();
+
+        assert_eq!(die.opposite().value(), &'D');
+    }
 }
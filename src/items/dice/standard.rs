@@ -0,0 +1,202 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::items::{D10, D100, D12, D20, D4, D6, D8};
+use crate::mechanics::Notation;
+use crate::traits::{Roll, RollMut};
+use crate::Error;
+
+/// One of the dice in a standard set, chosen at runtime, i.e. parsed from `"d12"`.
+///
+/// Pools of dice, i.e. `"2d8"`, are parsed as [`Notation`] instead.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{RngRoller, StandardDie};
+///
+/// let die: StandardDie = "d12".parse().unwrap();
+/// assert_eq!(die.sides(), 12);
+/// assert_eq!(die.to_string(), "d12");
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// assert_eq!(die.roll(&roller).value(), 6);
+///
+/// assert!("d7".parse::<StandardDie>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StandardDie {
+    /// A four-sided die.
+    D4(D4),
+
+    /// A six-sided die.
+    D6(D6),
+
+    /// An eight-sided die.
+    D8(D8),
+
+    /// A ten-sided die.
+    D10(D10),
+
+    /// A twelve-sided die.
+    D12(D12),
+
+    /// A twenty-sided die.
+    D20(D20),
+
+    /// A percentile die.
+    D100(D100),
+}
+
+impl StandardDie {
+    /// Creates the standard die with the given number of sides, starting at `1`, if one exists.
+    pub const fn with_sides(sides: usize) -> Option<Self> {
+        match sides {
+            4 => Some(Self::D4(D4::new())),
+            6 => Some(Self::D6(D6::new())),
+            8 => Some(Self::D8(D8::new())),
+            10 => Some(Self::D10(D10::new())),
+            12 => Some(Self::D12(D12::new())),
+            20 => Some(Self::D20(D20::new())),
+            100 => Some(Self::D100(D100::new())),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of sides.
+    pub const fn sides(&self) -> usize {
+        match self {
+            Self::D4(_) => 4,
+            Self::D6(_) => 6,
+            Self::D8(_) => 8,
+            Self::D10(_) => 10,
+            Self::D12(_) => 12,
+            Self::D20(_) => 20,
+            Self::D100(_) => 100,
+        }
+    }
+
+    /// Returns the currently faced value.
+    pub const fn value(&self) -> u8 {
+        match self {
+            Self::D4(d) => d.value(),
+            Self::D6(d) => d.value(),
+            Self::D8(d) => d.value(),
+            Self::D10(d) => d.value(),
+            Self::D12(d) => d.value(),
+            Self::D20(d) => d.value(),
+            Self::D100(d) => d.value(),
+        }
+    }
+
+    /// Returns notation for rolling the die once, i.e. `1d12`.
+    pub const fn notation(&self) -> Notation {
+        Notation::new(1, self.sides())
+    }
+
+    /// Rolls the die using the provided roller, returning the result.
+    #[must_use]
+    pub fn roll<R>(&self, roller: &R) -> Self
+    where
+        R: Roll,
+    {
+        match self {
+            Self::D4(d) => Self::D4(roller.roll(d)),
+            Self::D6(d) => Self::D6(roller.roll(d)),
+            Self::D8(d) => Self::D8(roller.roll(d)),
+            Self::D10(d) => Self::D10(roller.roll(d)),
+            Self::D12(d) => Self::D12(roller.roll(d)),
+            Self::D20(d) => Self::D20(roller.roll(d)),
+            Self::D100(d) => Self::D100(roller.roll(d)),
+        }
+    }
+
+    /// Rolls the die in place using the provided roller.
+    pub fn roll_mut<R>(&mut self, roller: &R)
+    where
+        R: RollMut,
+    {
+        match self {
+            Self::D4(d) => roller.roll_mut(d),
+            Self::D6(d) => roller.roll_mut(d),
+            Self::D8(d) => roller.roll_mut(d),
+            Self::D10(d) => roller.roll_mut(d),
+            Self::D12(d) => roller.roll_mut(d),
+            Self::D20(d) => roller.roll_mut(d),
+            Self::D100(d) => roller.roll_mut(d),
+        }
+    }
+}
+
+impl Display for StandardDie {
+    /// Formats the die as notation without a count, i.e. `d20`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "d{}", self.sides())
+    }
+}
+
+impl FromStr for StandardDie {
+    type Err = Error;
+
+    /// Parses a single die, i.e. `d20`, `D6`, `1d8`, or `d%`, ignoring surrounding whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let notation = Notation::parse(s)?;
+        let invalid = Error::InvalidNotation {
+            position: s.len() - s.trim_start().len(),
+        };
+        if notation != Notation::new(1, notation.sides()) {
+            return Err(invalid);
+        }
+        Self::with_sides(notation.sides()).ok_or(invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::NopRoller;
+
+    #[test]
+    fn standard_die_parse() {
+        assert_eq!("d4".parse(), Ok(StandardDie::D4(D4::new())));
+        assert_eq!(" D6 ".parse(), Ok(StandardDie::D6(D6::new())));
+        assert_eq!("1d8".parse(), Ok(StandardDie::D8(D8::new())));
+        assert_eq!("d%".parse(), Ok(StandardDie::D100(D100::new())));
+    }
+
+    #[test]
+    fn standard_die_parse_invalid() {
+        for s in ["d7", "2d6", "d6+1", "4d6kh3", " d3"] {
+            assert!(s.parse::<StandardDie>().is_err(), "{s}");
+        }
+        assert_eq!(
+            "x".parse::<StandardDie>(),
+            Err(Error::InvalidNotation { position: 0 })
+        );
+        assert_eq!(
+            " d3".parse::<StandardDie>(),
+            Err(Error::InvalidNotation { position: 1 })
+        );
+    }
+
+    #[test]
+    fn standard_die_round_trips() {
+        for sides in [4, 6, 8, 10, 12, 20, 100] {
+            let die = StandardDie::with_sides(sides).unwrap();
+
+            assert_eq!(die.to_string().parse(), Ok(die));
+            assert_eq!(die.value(), 1);
+            assert_eq!(die.notation().to_string(), format!("1d{sides}"));
+        }
+        assert_eq!(StandardDie::with_sides(3), None);
+    }
+
+    #[test]
+    fn standard_die_roll_mut() {
+        let mut die = StandardDie::D20(D20::from(7));
+        die.roll_mut(&NopRoller);
+
+        assert_eq!(die.roll(&NopRoller).value(), 7);
+    }
+}
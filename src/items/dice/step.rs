@@ -0,0 +1,395 @@
+use super::{NumericDie, D10, D12, D14, D16, D20, D24, D3, D30, D4, D5, D6, D7, D8};
+use crate::traits::RollMut;
+
+/// A die in the _Savage Worlds_ style step chain, `d4 → d6 → d8 → d10 → d12`.
+///
+/// Traits and skills that advance (or are reduced) move along the chain, which is modeled as a
+/// runtime tag that can be rolled using any [`RollMut`] roller.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::DieStep;
+///
+/// let die = DieStep::D6;
+/// assert_eq!(die.step_up(), Some(DieStep::D8));
+/// assert_eq!(die.step_down(), Some(DieStep::D4));
+/// assert_eq!(DieStep::D12.step_up(), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DieStep {
+    /// A 4-sided die, the lowest step.
+    D4,
+
+    /// A 6-sided die.
+    D6,
+
+    /// An 8-sided die.
+    D8,
+
+    /// A 10-sided die.
+    D10,
+
+    /// A 12-sided die, the highest step.
+    D12,
+}
+
+impl DieStep {
+    /// Every step of the chain, from lowest to highest.
+    pub const CHAIN: [DieStep; 5] = [
+        DieStep::D4,
+        DieStep::D6,
+        DieStep::D8,
+        DieStep::D10,
+        DieStep::D12,
+    ];
+
+    /// Returns the total possible sides for the die.
+    pub const fn sides(&self) -> usize {
+        match self {
+            Self::D4 => 4,
+            Self::D6 => 6,
+            Self::D8 => 8,
+            Self::D10 => 10,
+            Self::D12 => 12,
+        }
+    }
+
+    /// Returns the next larger die in the chain, or `None` if already the largest.
+    pub const fn step_up(&self) -> Option<Self> {
+        match self {
+            Self::D4 => Some(Self::D6),
+            Self::D6 => Some(Self::D8),
+            Self::D8 => Some(Self::D10),
+            Self::D10 => Some(Self::D12),
+            Self::D12 => None,
+        }
+    }
+
+    /// Returns the next smaller die in the chain, or `None` if already the smallest.
+    pub const fn step_down(&self) -> Option<Self> {
+        match self {
+            Self::D4 => None,
+            Self::D6 => Some(Self::D4),
+            Self::D8 => Some(Self::D6),
+            Self::D10 => Some(Self::D8),
+            Self::D12 => Some(Self::D10),
+        }
+    }
+
+    /// Rolls a die of this step using the provided roller, returning the faced value.
+    pub fn roll<R>(&self, roller: &R) -> u8
+    where
+        R: RollMut,
+    {
+        match self {
+            Self::D4 => roll(roller, D4::new()),
+            Self::D6 => roll(roller, D6::new()),
+            Self::D8 => roll(roller, D8::new()),
+            Self::D10 => roll(roller, D10::new()),
+            Self::D12 => roll(roller, D12::new()),
+        }
+    }
+}
+
+impl Default for DieStep {
+    /// Returns the lowest step, a `d4`, which is where untrained traits typically start.
+    fn default() -> Self {
+        Self::D4
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DieStep {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(&Self::CHAIN).copied()
+    }
+}
+
+impl TryFrom<usize> for DieStep {
+    type Error = usize;
+
+    /// Converts a number of sides into a step, returning the sides back if not part of the chain.
+    fn try_from(sides: usize) -> Result<Self, Self::Error> {
+        Self::CHAIN
+            .into_iter()
+            .find(|step| step.sides() == sides)
+            .ok_or(sides)
+    }
+}
+
+/// A die in the _Dungeon Crawl Classics_ dice chain, from a `d3` up to a `d30`.
+///
+/// The chain includes the unusual sizes (`d3`, `d5`, `d7`, `d14`, `d16`, `d24`, and `d30`) that
+/// are awkward to remember, and rules often adjust a roll by one or more steps along the chain.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::DiceChain;
+///
+/// let die = DiceChain::D20;
+/// assert_eq!(die.step_up(), Some(DiceChain::D24));
+/// assert_eq!(die.step_down(), Some(DiceChain::D16));
+/// assert_eq!(die.walk(-3), Some(DiceChain::D12));
+/// assert_eq!(die.walk(3), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiceChain {
+    /// A 3-sided die, the lowest step.
+    D3,
+
+    /// A 4-sided die.
+    D4,
+
+    /// A 5-sided die.
+    D5,
+
+    /// A 6-sided die.
+    D6,
+
+    /// A 7-sided die.
+    D7,
+
+    /// A 8-sided die.
+    D8,
+
+    /// A 10-sided die.
+    D10,
+
+    /// A 12-sided die.
+    D12,
+
+    /// A 14-sided die.
+    D14,
+
+    /// A 16-sided die.
+    D16,
+
+    /// A 20-sided die.
+    D20,
+
+    /// A 24-sided die.
+    D24,
+
+    /// A 30-sided die, the highest step.
+    D30,
+}
+
+impl DiceChain {
+    /// Every step of the chain, from lowest to highest.
+    pub const CHAIN: [DiceChain; 13] = [
+        DiceChain::D3,
+        DiceChain::D4,
+        DiceChain::D5,
+        DiceChain::D6,
+        DiceChain::D7,
+        DiceChain::D8,
+        DiceChain::D10,
+        DiceChain::D12,
+        DiceChain::D14,
+        DiceChain::D16,
+        DiceChain::D20,
+        DiceChain::D24,
+        DiceChain::D30,
+    ];
+
+    /// Returns the total possible sides for the die.
+    pub const fn sides(&self) -> usize {
+        match self {
+            Self::D3 => 3,
+            Self::D4 => 4,
+            Self::D5 => 5,
+            Self::D6 => 6,
+            Self::D7 => 7,
+            Self::D8 => 8,
+            Self::D10 => 10,
+            Self::D12 => 12,
+            Self::D14 => 14,
+            Self::D16 => 16,
+            Self::D20 => 20,
+            Self::D24 => 24,
+            Self::D30 => 30,
+        }
+    }
+
+    /// Returns the die the given number of steps along the chain, or `None` if out of the chain.
+    ///
+    /// Positive steps move up the chain (to larger dice), and negative steps move down.
+    pub fn walk(&self, steps: isize) -> Option<Self> {
+        let index = (*self as usize).checked_add_signed(steps)?;
+        Self::CHAIN.get(index).copied()
+    }
+
+    /// Returns the next larger die in the chain, or `None` if already the largest.
+    pub fn step_up(&self) -> Option<Self> {
+        self.walk(1)
+    }
+
+    /// Returns the next smaller die in the chain, or `None` if already the smallest.
+    pub fn step_down(&self) -> Option<Self> {
+        self.walk(-1)
+    }
+
+    /// Rolls a die of this step using the provided roller, returning the faced value.
+    pub fn roll<R>(&self, roller: &R) -> u8
+    where
+        R: RollMut,
+    {
+        match self {
+            Self::D3 => roll(roller, D3::new()),
+            Self::D4 => roll(roller, D4::new()),
+            Self::D5 => roll(roller, D5::new()),
+            Self::D6 => roll(roller, D6::new()),
+            Self::D7 => roll(roller, D7::new()),
+            Self::D8 => roll(roller, D8::new()),
+            Self::D10 => roll(roller, D10::new()),
+            Self::D12 => roll(roller, D12::new()),
+            Self::D14 => roll(roller, D14::new()),
+            Self::D16 => roll(roller, D16::new()),
+            Self::D20 => roll(roller, D20::new()),
+            Self::D24 => roll(roller, D24::new()),
+            Self::D30 => roll(roller, D30::new()),
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DiceChain {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(&Self::CHAIN).copied()
+    }
+}
+
+impl TryFrom<usize> for DiceChain {
+    type Error = usize;
+
+    /// Converts a number of sides into a step, returning the sides back if not part of the chain.
+    fn try_from(sides: usize) -> Result<Self, Self::Error> {
+        Self::CHAIN
+            .into_iter()
+            .find(|step| step.sides() == sides)
+            .ok_or(sides)
+    }
+}
+
+fn roll<R, const MAXIMUM: usize>(roller: &R, mut die: NumericDie<u8, MAXIMUM>) -> u8
+where
+    R: RollMut,
+{
+    roller.roll_mut(&mut die);
+    die.value()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::NopRoller;
+
+    #[test]
+    fn die_step_default() {
+        let d: DieStep = Default::default();
+
+        assert_eq!(d, DieStep::D4);
+    }
+
+    #[test]
+    fn die_step_sides() {
+        let sides = DieStep::CHAIN.map(|d| d.sides());
+
+        assert_eq!(sides, [4, 6, 8, 10, 12]);
+    }
+
+    #[test]
+    fn die_step_up_chain() {
+        let mut step = DieStep::D4;
+        let mut count = 0;
+        while let Some(next) = step.step_up() {
+            assert!(next > step);
+            step = next;
+            count += 1;
+        }
+
+        assert_eq!(step, DieStep::D12);
+        assert_eq!(count, 4);
+    }
+
+    #[test]
+    fn die_step_down_chain() {
+        let mut step = DieStep::D12;
+        let mut count = 0;
+        while let Some(next) = step.step_down() {
+            assert!(next < step);
+            step = next;
+            count += 1;
+        }
+
+        assert_eq!(step, DieStep::D4);
+        assert_eq!(count, 4);
+    }
+
+    #[test]
+    fn die_step_try_from() {
+        assert_eq!(DieStep::try_from(8), Ok(DieStep::D8));
+        assert_eq!(DieStep::try_from(20), Err(20));
+    }
+
+    #[test]
+    fn die_step_roll() {
+        for step in DieStep::CHAIN {
+            assert_eq!(step.roll(&NopRoller), 1);
+        }
+    }
+
+    #[test]
+    fn dice_chain_sides() {
+        let sides = DiceChain::CHAIN.map(|d| d.sides());
+
+        assert_eq!(sides, [3, 4, 5, 6, 7, 8, 10, 12, 14, 16, 20, 24, 30]);
+    }
+
+    #[test]
+    fn dice_chain_step() {
+        assert_eq!(DiceChain::D3.step_down(), None);
+        assert_eq!(DiceChain::D3.step_up(), Some(DiceChain::D4));
+        assert_eq!(DiceChain::D30.step_up(), None);
+        assert_eq!(DiceChain::D30.step_down(), Some(DiceChain::D24));
+    }
+
+    #[test]
+    fn dice_chain_walk() {
+        assert_eq!(DiceChain::D6.walk(0), Some(DiceChain::D6));
+        assert_eq!(DiceChain::D6.walk(2), Some(DiceChain::D8));
+        assert_eq!(DiceChain::D6.walk(-3), Some(DiceChain::D3));
+        assert_eq!(DiceChain::D6.walk(-4), None);
+        assert_eq!(DiceChain::D6.walk(9), Some(DiceChain::D30));
+        assert_eq!(DiceChain::D6.walk(10), None);
+        assert_eq!(DiceChain::D6.walk(isize::MAX), None);
+        assert_eq!(DiceChain::D6.walk(isize::MIN), None);
+    }
+
+    #[test]
+    fn dice_chain_try_from() {
+        assert_eq!(DiceChain::try_from(7), Ok(DiceChain::D7));
+        assert_eq!(DiceChain::try_from(9), Err(9));
+    }
+
+    #[test]
+    fn dice_chain_roll() {
+        for step in DiceChain::CHAIN {
+            assert_eq!(step.roll(&NopRoller), 1);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn die_step_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut u = Unstructured::new(&[3, 12]);
+        assert!(DieStep::CHAIN.contains(&DieStep::arbitrary(&mut u).unwrap()));
+        assert!(DiceChain::CHAIN.contains(&DiceChain::arbitrary(&mut u).unwrap()));
+    }
+}
@@ -0,0 +1,373 @@
+use std::fmt::Display;
+
+use crate::traits::{
+    Critical, OppositeFaces, Polyhedral, Positional, Rotate, RotateFast, RotateMut, Snapshot, Step,
+    StepMut,
+};
+
+/// A change to a face of a [`StickerDie`], where a sticker was applied, replaced, or peeled off.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StickerChange<T> {
+    position: usize,
+    previous: Option<T>,
+    current: Option<T>,
+}
+
+impl<T> StickerChange<T> {
+    /// Returns the position of the face that changed.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the sticker before the change, or `None` if the face was blank.
+    pub const fn previous(&self) -> Option<&T> {
+        self.previous.as_ref()
+    }
+
+    /// Returns the sticker after the change, or `None` if the face is now blank.
+    pub const fn current(&self) -> Option<&T> {
+        self.current.as_ref()
+    }
+}
+
+/// A die with blank faces, where stickers can be applied, replaced, or peeled off at runtime.
+///
+/// Models mechanics where dice change over a campaign (i.e. _legacy_ games), where a die starts
+/// blank (or partially labelled) and gains faces over time. Every change is recorded, in order,
+/// until [`StickerDie::take_changes`] is called, i.e. to persist or present what changed.
+///
+/// The value of a blank face is `None`.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::StickerDie;
+///
+/// let mut die = StickerDie::<&str, 6>::new();
+/// assert_eq!(die.value(), None);
+///
+/// die.stick(0, "sword");
+/// die.stick(0, "flaming sword");
+/// assert_eq!(die.value(), Some(&"flaming sword"));
+///
+/// let changes = die.take_changes();
+/// assert_eq!(changes.len(), 2);
+/// assert_eq!(changes[1].previous(), Some(&"sword"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StickerDie<T, const SIDES: usize> {
+    stickers: [Option<T>; SIDES],
+    position: usize,
+    changes: Vec<StickerChange<T>>,
+}
+
+impl<T, const SIDES: usize> StickerDie<T, SIDES> {
+    /// Creates a die where every face is blank, showing the first face.
+    ///
+    /// # Panics
+    ///
+    /// If the die has no sides.
+    pub fn new() -> Self {
+        assert!(SIDES > 0);
+        Self {
+            stickers: std::array::from_fn(|_| None),
+            position: 0,
+            changes: Vec::new(),
+        }
+    }
+
+    /// Returns the current position within the die, between `0..SIDES`.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the sticker on the current face, or `None` if the face is blank.
+    pub const fn value(&self) -> Option<&T> {
+        self.stickers[self.position].as_ref()
+    }
+
+    /// Returns the sticker on the face at the given position, or `None` if the face is blank.
+    ///
+    /// # Panics
+    ///
+    /// If the position is out of bounds.
+    pub const fn sticker(&self, position: usize) -> Option<&T> {
+        self.stickers[position].as_ref()
+    }
+
+    /// Returns the sticker on every face, in order of position.
+    pub const fn stickers(&self) -> &[Option<T>; SIDES] {
+        &self.stickers
+    }
+
+    /// Returns how many faces have a sticker.
+    pub fn labelled(&self) -> usize {
+        self.stickers.iter().filter(|s| s.is_some()).count()
+    }
+
+    /// Returns every change since the die was created, or changes were last taken, in order.
+    pub fn changes(&self) -> &[StickerChange<T>] {
+        &self.changes
+    }
+
+    /// Returns and clears the recorded changes.
+    pub fn take_changes(&mut self) -> Vec<StickerChange<T>> {
+        std::mem::take(&mut self.changes)
+    }
+}
+
+impl<T, const SIDES: usize> StickerDie<T, SIDES>
+where
+    T: Clone,
+{
+    /// Applies a sticker to the face at the given position, returning the sticker it replaced.
+    ///
+    /// # Panics
+    ///
+    /// If the position is out of bounds.
+    pub fn stick(&mut self, position: usize, sticker: T) -> Option<T> {
+        self.replace(position, Some(sticker))
+    }
+
+    /// Removes the sticker from the face at the given position, leaving it blank.
+    ///
+    /// Peeling a blank face is not recorded as a change.
+    ///
+    /// # Panics
+    ///
+    /// If the position is out of bounds.
+    pub fn peel(&mut self, position: usize) -> Option<T> {
+        self.stickers[position].as_ref()?;
+        self.replace(position, None)
+    }
+
+    /// Replaces the sticker at the given position, recording the change.
+    fn replace(&mut self, position: usize, sticker: Option<T>) -> Option<T> {
+        let previous = std::mem::replace(&mut self.stickers[position], sticker.clone());
+        self.changes.push(StickerChange {
+            position,
+            previous: previous.clone(),
+            current: sticker,
+        });
+        previous
+    }
+}
+
+impl<T, const SIDES: usize> Default for StickerDie<T, SIDES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const SIDES: usize> From<[T; SIDES]> for StickerDie<T, SIDES> {
+    /// Creates a die with a sticker on every face, showing the first face, with no changes.
+    ///
+    /// # Panics
+    ///
+    /// If the die has no sides.
+    fn from(stickers: [T; SIDES]) -> Self {
+        assert!(SIDES > 0);
+        Self {
+            stickers: stickers.map(Some),
+            position: 0,
+            changes: Vec::new(),
+        }
+    }
+}
+
+impl<T, const SIDES: usize> Display for StickerDie<T, SIDES>
+where
+    T: Display,
+{
+    /// Formats the die as its number of sides and current value, i.e. `d6 showing blank`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.value() {
+            Some(sticker) => write!(f, "d{SIDES} showing {sticker}"),
+            None => write!(f, "d{SIDES} showing blank"),
+        }
+    }
+}
+
+impl<T, const SIDES: usize> Polyhedral for StickerDie<T, SIDES>
+where
+    T: Clone,
+{
+    type Face = Option<T>;
+
+    fn sides() -> usize {
+        SIDES
+    }
+
+    fn value(&self) -> Option<T> {
+        self.stickers[self.position].clone()
+    }
+}
+
+impl<T, const SIDES: usize> Positional for StickerDie<T, SIDES>
+where
+    T: Clone,
+{
+    fn position(&self) -> usize {
+        self.position
+    }
+
+    fn set_position(&mut self, position: usize) {
+        assert!(position < SIDES);
+        self.position = position;
+    }
+}
+
+impl<T, const SIDES: usize> Snapshot for StickerDie<T, SIDES>
+where
+    T: Clone,
+{
+    /// The position, and the sticker on every face.
+    type Memento = (usize, [Option<T>; SIDES]);
+
+    fn snapshot(&self) -> Self::Memento {
+        (self.position, self.stickers.clone())
+    }
+
+    /// Changes the die to show the position, with the stickers, in the snapshot.
+    ///
+    /// Restoring is not recorded as a change; see [`StickerDie::changes`].
+    fn restore(&mut self, (position, stickers): &Self::Memento) {
+        self.set_position(*position);
+        self.stickers = stickers.clone();
+    }
+}
+
+impl<T, const SIDES: usize> OppositeFaces for StickerDie<T, SIDES> where T: Clone {}
+
+impl<T, const SIDES: usize> Critical for StickerDie<T, SIDES>
+where
+    T: Clone,
+{
+    fn natural(&self) -> usize {
+        self.position + 1
+    }
+}
+
+impl<T, const SIDES: usize> Step for StickerDie<T, SIDES>
+where
+    T: Clone,
+{
+    fn next(&self) -> Self {
+        self.rotate_fast(1)
+    }
+
+    fn back(&self) -> Self {
+        self.rotate_fast(-1)
+    }
+}
+
+impl<T, const SIDES: usize> StepMut for StickerDie<T, SIDES>
+where
+    T: Clone,
+{
+    fn next_mut(&mut self) {
+        self.rotate_fast_mut(1);
+    }
+
+    fn back_mut(&mut self) {
+        self.rotate_fast_mut(-1);
+    }
+}
+
+impl<T, const SIDES: usize> Rotate for StickerDie<T, SIDES>
+where
+    T: Clone,
+{
+    fn rotate(&self, amount: isize) -> Self {
+        self.rotate_fast(amount)
+    }
+}
+
+impl<T, const SIDES: usize> RotateMut for StickerDie<T, SIDES>
+where
+    T: Clone,
+{
+    fn rotate_mut(&mut self, amount: isize) {
+        self.rotate_fast_mut(amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sticker_new_is_blank() {
+        let die = StickerDie::<char, 4>::default();
+
+        assert_eq!(die.labelled(), 0);
+        assert_eq!(die.stickers(), &[None; 4]);
+        assert_eq!(die.to_string(), "d4 showing blank");
+    }
+
+    #[test]
+    fn sticker_from_array() {
+        let die = StickerDie::from(['A', 'B', 'C']);
+
+        assert_eq!(die.labelled(), 3);
+        assert!(die.changes().is_empty());
+        assert_eq!(die.next().to_string(), "d3 showing B");
+    }
+
+    #[test]
+    fn sticker_peel() {
+        let mut die = StickerDie::from(['A', 'B', 'C']);
+
+        assert_eq!(die.peel(1), Some('B'));
+        assert_eq!(die.peel(1), None);
+        assert_eq!(die.sticker(1), None);
+        assert_eq!(
+            die.changes(),
+            &[StickerChange {
+                position: 1,
+                previous: Some('B'),
+                current: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn sticker_take_changes() {
+        let mut die = StickerDie::<u8, 6>::new();
+        die.stick(5, 6);
+        let changes = die.take_changes();
+
+        assert_eq!(changes[0].position(), 5);
+        assert_eq!(changes[0].previous(), None);
+        assert_eq!(changes[0].current(), Some(&6));
+        assert!(die.changes().is_empty());
+    }
+
+    #[test]
+    fn sticker_rotate_keeps_stickers() {
+        let mut die = StickerDie::<u8, 6>::new();
+        die.stick(5, 6);
+        die.rotate_mut(-1);
+
+        assert_eq!(Polyhedral::value(&die), Some(6));
+        assert!(die.is_critical());
+        assert_eq!(die.rotate(7).value(), None);
+    }
+
+    #[test]
+    fn sticker_snapshot_keeps_stickers() {
+        let mut die = StickerDie::<char, 4>::new();
+        die.stick(1, 'a');
+        die.set_position(1);
+        let saved = die.snapshot();
+
+        die.stick(1, 'b');
+        die.stick(2, 'c');
+        die.set_position(2);
+        die.restore(&saved);
+
+        assert_eq!(die.value(), Some(&'a'));
+        assert_eq!(die.stickers(), &[None, Some('a'), None, None]);
+        assert_eq!(die.changes().len(), 3);
+    }
+}
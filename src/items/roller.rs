@@ -1,6 +1,9 @@
+use std::cell::Cell;
+
 use fastrand::Rng;
 
-use crate::traits::{Polyhedral, Roll, RollMut, Rotate, RotateMut};
+use crate::items::D16;
+use crate::traits::{Positional, Roll, RollMut, Rotate, RotateMut, SideSelector};
 
 /// Declares that it rolls entities, but does nothing.
 ///
@@ -83,34 +86,162 @@ impl From<Rng> for RngRoller {
     }
 }
 
+impl SideSelector for RngRoller {
+    fn select(&self, sides: usize) -> usize {
+        self.0.usize(0..sides)
+    }
+}
+
 impl Roll for RngRoller {
+    /// Returns a copy of the entity showing a [selected](SideSelector::select) position.
     fn roll<T>(&self, rotate: &T) -> T
     where
-        T: Polyhedral + Rotate,
+        T: Positional + Rotate,
     {
-        let sides = T::sides();
-        let range = 0..sides;
-        let amount = self.0.usize(range);
-        rotate.rotate(amount as i8)
+        let rolled = self.pick(rotate);
+        #[cfg(feature = "tracing")]
+        trace_roll(&rolled);
+        rolled
     }
 }
 
 impl RollMut for RngRoller {
+    /// Changes the entity to show a [selected](SideSelector::select) position.
     fn roll_mut<T>(&self, rotate: &mut T)
     where
-        T: Polyhedral + RotateMut,
+        T: Positional + RotateMut,
     {
-        let sides = T::sides();
-        let range = 0..sides;
-        let amount = self.0.usize(range);
+        self.pick_mut(rotate);
+        #[cfg(feature = "tracing")]
+        trace_roll(rotate);
+    }
+}
+
+/// Selects positions from a fixed sequence, in order, repeating the sequence once exhausted.
+///
+/// Useful in tests, to force outcomes. Like [`RngRoller`], rolling changes an element to show the
+/// [selected](SideSelector::select) position, regardless of the face it was showing before.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{D6, SequenceRoller};
+/// use tomb::traits::Roll;
+///
+/// let roller = SequenceRoller::new([5, 0]);
+///
+/// assert_eq!(roller.roll(&D6::new()).value(), 6);
+/// assert_eq!(roller.roll(&D6::new()).value(), 1);
+/// assert_eq!(roller.roll(&D6::new()).value(), 6);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SequenceRoller {
+    positions: Vec<usize>,
+    next: Cell<usize>,
+}
+
+impl SequenceRoller {
+    /// Creates a roller that selects the given positions, in order.
+    ///
+    /// A position beyond the number of sides wraps around, i.e. `7` is `1` for a D6.
+    ///
+    /// # Panics
+    ///
+    /// If `positions` is empty.
+    pub fn new(positions: impl IntoIterator<Item = usize>) -> Self {
+        let positions: Vec<usize> = positions.into_iter().collect();
+        assert!(!positions.is_empty(), "at least one position is required");
+        Self {
+            positions,
+            next: Cell::new(0),
+        }
+    }
+}
 
-        rotate.rotate_mut(amount as i8);
+impl SideSelector for SequenceRoller {
+    fn select(&self, sides: usize) -> usize {
+        let next = self.next.get();
+        self.next.set((next + 1) % self.positions.len());
+        self.positions[next] % sides
+    }
+}
+
+impl Roll for SequenceRoller {
+    /// Returns a copy of the entity showing a [selected](SideSelector::select) position.
+    fn roll<T>(&self, rotate: &T) -> T
+    where
+        T: Positional + Rotate,
+    {
+        self.pick(rotate)
     }
 }
 
+impl RollMut for SequenceRoller {
+    /// Changes the entity to show a [selected](SideSelector::select) position.
+    fn roll_mut<T>(&self, rotate: &mut T)
+    where
+        T: Positional + RotateMut,
+    {
+        self.pick_mut(rotate);
+    }
+}
+
+/// Emits an event for a roll, recording the position the element landed on.
+///
+/// The state of the RNG is never recorded, as it would allow predicting every future roll.
+#[cfg(feature = "tracing")]
+fn trace_roll<T: Positional>(rolled: &T) {
+    tracing::trace!(
+        die = std::any::type_name::<T>(),
+        sides = T::sides(),
+        position = rolled.position(),
+        "roll"
+    );
+}
+
+/// Returns a uniformly random index within `0..length`, using the roller for entropy.
+///
+/// A roller only rolls dice with a fixed number of sides, so a [`D16`] is rolled once per
+/// hexadecimal digit of the length, and results that would bias the index are rolled again.
+///
+/// A roller is not guaranteed to ever produce an unbiased result (i.e. a [`SequenceRoller`] that
+/// always selects the last face), so after [`ROLL_INDEX_ATTEMPTS`] the (slightly biased) modulo of
+/// the last result is used instead.
+pub(crate) fn roll_index<R>(roller: &R, length: usize) -> usize
+where
+    R: Roll,
+{
+    if length <= 1 {
+        return 0;
+    }
+    let length = length as u128;
+    let mut range = 16u128;
+    while range < length {
+        range *= 16;
+    }
+    let limit = range - range % length;
+    let mut value = 0u128;
+    for _ in 0..ROLL_INDEX_ATTEMPTS {
+        value = 0;
+        let mut digits = range;
+        while digits > 1 {
+            value = value * 16 + (roller.roll(&D16::new()).value() - 1) as u128;
+            digits /= 16;
+        }
+        if value < limit {
+            break;
+        }
+    }
+    (value % length) as usize
+}
+
+/// How many times [`roll_index`] rolls before falling back to a biased result.
+const ROLL_INDEX_ATTEMPTS: usize = 64;
+
 #[cfg(test)]
 mod tests {
-    use crate::traits::{Step, StepMut};
+    use crate::items::D6;
+    use crate::traits::{Polyhedral, Step, StepMut};
 
     use super::*;
 
@@ -142,9 +273,25 @@ mod tests {
     impl RotateMut for PanicDie {}
 
     impl Polyhedral for PanicDie {
+        type Face = ();
+
         fn sides() -> usize {
             unreachable!()
         }
+
+        fn value(&self) {
+            unreachable!()
+        }
+    }
+
+    impl Positional for PanicDie {
+        fn position(&self) -> usize {
+            unreachable!()
+        }
+
+        fn set_position(&mut self, _position: usize) {
+            unreachable!()
+        }
     }
 
     #[test]
@@ -186,4 +333,126 @@ mod tests {
     fn rng_roller_default() {
         let _: RngRoller = Default::default();
     }
+
+    #[test]
+    fn rng_roller_pick_matches_roll() {
+        let picked = RngRoller::from(Rng::with_seed(7194422452970863838)).pick(&D16::new());
+        let rolled = RngRoller::from(Rng::with_seed(7194422452970863838)).roll(&D16::new());
+
+        assert_eq!(picked, rolled);
+    }
+
+    #[test]
+    fn roll_index_in_range() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let mut seen = [false; 20];
+        for _ in 0..1000 {
+            seen[roll_index(&roller, 20)] = true;
+        }
+
+        assert!(seen.iter().all(|&s| s));
+        assert_eq!(roll_index(&roller, 0), 0);
+        assert_eq!(roll_index(&roller, 1), 0);
+    }
+
+    #[test]
+    fn roll_index_large() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+
+        assert!(roll_index(&roller, 1000) < 1000);
+        assert!(roll_index(&roller, usize::MAX) < usize::MAX);
+    }
+
+    #[test]
+    fn roll_index_stuck_on_maximum() {
+        let roller = SequenceRoller::new([15]);
+
+        assert_eq!(roll_index(&roller, 3), 15 % 3);
+        assert_eq!(roll_index(&roller, 1000), 0xfff % 1000);
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn rng_roller_traces_position() {
+        use std::sync::{Arc, Mutex};
+
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata};
+
+        /// Records the fields of every event, i.e. `sides=6`.
+        #[derive(Clone, Default)]
+        struct Recorder(Arc<Mutex<Vec<String>>>);
+
+        impl Visit for Recorder {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                let field = format!("{}={value:?}", field.name());
+                self.0.lock().unwrap().push(field);
+            }
+        }
+
+        impl tracing::Subscriber for Recorder {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, _: &Attributes<'_>) -> Id {
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                event.record(&mut self.clone());
+            }
+
+            fn enter(&self, _: &Id) {}
+
+            fn exit(&self, _: &Id) {}
+        }
+
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+            let mut d6 = D6::new();
+            roller.roll_mut(&mut d6);
+            assert_eq!(d6.value(), 3);
+        });
+
+        let fields = recorder.0.lock().unwrap().clone();
+        assert!(fields.contains(&"sides=6".to_string()), "{fields:?}");
+        assert!(fields.contains(&"position=2".to_string()), "{fields:?}");
+        assert!(!fields.iter().any(|f| f.starts_with("seed")), "{fields:?}");
+    }
+
+    #[test]
+    fn sequence_roller_repeats() {
+        let roller = SequenceRoller::new([1, 8]);
+        let mut d6 = D6::new();
+        roller.roll_mut(&mut d6);
+
+        assert_eq!(d6.value(), 2);
+        assert_eq!(roller.select(6), 2);
+        assert_eq!(roller.select(6), 1);
+    }
+
+    #[test]
+    fn sequence_roller_ignores_starting_face() {
+        let roller = SequenceRoller::new([2]);
+        for start in 1..=6 {
+            let mut d6 = D6::from(start);
+            roller.roll_mut(&mut d6);
+
+            assert_eq!(roller.roll(&D6::from(start)).value(), 3);
+            assert_eq!(d6.value(), 3);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one position is required")]
+    fn sequence_roller_empty() {
+        let _ = SequenceRoller::new([]);
+    }
 }
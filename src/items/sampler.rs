@@ -0,0 +1,134 @@
+use crate::items::roll_index;
+use crate::traits::Roll;
+
+/// Picks random elements from slices using a roller, without creating a die.
+///
+/// A [`SliceDie`](crate::items::SliceDie) is useful when the current face matters between rolls;
+/// for a one-off choice, a sampler is lighter weight, and works with slices of any length.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{RngRoller, Sampler};
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let sampler = Sampler::new(&roller);
+///
+/// let directions = ["north", "east", "south", "west"];
+/// assert_eq!(sampler.choose(&directions), Some(&"west"));
+///
+/// // Storms are rare, and clear skies are common.
+/// let weather = [("clear", 6), ("rain", 3), ("fog", 2), ("storm", 1)];
+/// let (sky, _) = sampler.choose_weighted(&weather, |(_, w)| *w).unwrap();
+/// assert_eq!(*sky, "fog");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Sampler<'a, R> {
+    roller: &'a R,
+}
+
+impl<'a, R> Sampler<'a, R>
+where
+    R: Roll,
+{
+    /// Creates a sampler that uses the given roller.
+    pub const fn new(roller: &'a R) -> Self {
+        Self { roller }
+    }
+
+    /// Returns a uniformly random index within `0..length`.
+    ///
+    /// # Panics
+    ///
+    /// If the length is `0`.
+    pub fn index(&self, length: usize) -> usize {
+        assert!(length > 0);
+        roll_index(self.roller, length)
+    }
+
+    /// Returns a uniformly random element of the slice, or `None` if the slice is empty.
+    pub fn choose<'s, T>(&self, items: &'s [T]) -> Option<&'s T> {
+        if items.is_empty() {
+            return None;
+        }
+        items.get(self.index(items.len()))
+    }
+
+    /// Returns a random element of the slice, where each element is as likely as its weight.
+    ///
+    /// Returns `None` if the slice is empty, or every weight is `0`.
+    ///
+    /// # Panics
+    ///
+    /// If the sum of the weights overflows a `usize`.
+    pub fn choose_weighted<'s, T, F>(&self, items: &'s [T], mut weight: F) -> Option<&'s T>
+    where
+        F: FnMut(&T) -> usize,
+    {
+        let weights: Vec<usize> = items.iter().map(&mut weight).collect();
+        let total = weights
+            .iter()
+            .try_fold(0usize, |sum, &w| sum.checked_add(w))
+            .expect("total weight overflowed");
+        if total == 0 {
+            return None;
+        }
+        let mut target = self.index(total);
+        for (item, weight) in items.iter().zip(weights) {
+            if target < weight {
+                return Some(item);
+            }
+            target -= weight;
+        }
+        unreachable!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+    use crate::items::{NopRoller, RngRoller};
+
+    #[test]
+    fn sampler_choose_empty() {
+        let sampler = Sampler::new(&NopRoller);
+        let items: [u8; 0] = [];
+
+        assert_eq!(sampler.choose(&items), None);
+        assert_eq!(sampler.choose_weighted(&items, |_| 1), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sampler_index_empty() {
+        let _ = Sampler::new(&NopRoller).index(0);
+    }
+
+    #[test]
+    fn sampler_choose_every_element() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let sampler = Sampler::new(&roller);
+        let items = [1, 2, 3, 4, 5];
+        let mut seen = [false; 5];
+        for _ in 0..100 {
+            seen[*sampler.choose(&items).unwrap() - 1] = true;
+        }
+
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn sampler_choose_weighted_skips_zero() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let sampler = Sampler::new(&roller);
+        let items = [0, 1, 0, 2];
+
+        for _ in 0..100 {
+            assert_ne!(*sampler.choose_weighted(&items, |&w| w).unwrap(), 0);
+        }
+        assert_eq!(sampler.choose_weighted(&[0, 0], |&w| w), None);
+    }
+}
@@ -0,0 +1,263 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+/// A source of the current time, used by a [`Timer`].
+///
+/// Time is measured as a [`Duration`] since an arbitrary (but fixed) starting point, which allows
+/// using a [`ManualTime`] in place of a [`SystemTime`] to test timers deterministically.
+pub trait TimeSource {
+    /// Returns the current time.
+    fn now(&self) -> Duration;
+}
+
+/// Measures real (monotonic) time since the source was created.
+#[derive(Clone, Debug)]
+pub struct SystemTime(Instant);
+
+impl SystemTime {
+    /// Creates a time source starting now.
+    pub fn new() -> Self {
+        Self(Instant::now())
+    }
+}
+
+impl Default for SystemTime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeSource for SystemTime {
+    fn now(&self) -> Duration {
+        self.0.elapsed()
+    }
+}
+
+/// Time that only passes when advanced manually.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use tomb::items::{ManualTime, TimeSource};
+///
+/// let time = ManualTime::new();
+/// time.advance(Duration::from_secs(5));
+/// assert_eq!(time.now(), Duration::from_secs(5));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ManualTime(Cell<Duration>);
+
+impl ManualTime {
+    /// Creates a time source starting at zero.
+    pub const fn new() -> Self {
+        Self(Cell::new(Duration::ZERO))
+    }
+
+    /// Moves the current time forward.
+    pub fn advance(&self, duration: Duration) {
+        self.0.set(self.0.get() + duration);
+    }
+}
+
+impl TimeSource for ManualTime {
+    fn now(&self) -> Duration {
+        self.0.get()
+    }
+}
+
+/// A sand timer (or turn timer) of a fixed duration.
+///
+/// Like a physical sand timer, it can be _flipped_ at any time, after which the time remaining is
+/// however much time had elapsed. The current time is always read from a [`TimeSource`].
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use tomb::items::{ManualTime, Timer};
+///
+/// let time = ManualTime::new();
+/// let mut timer = Timer::new(Duration::from_secs(60));
+/// timer.start(&time);
+///
+/// time.advance(Duration::from_secs(45));
+/// assert_eq!(timer.remaining(&time), Duration::from_secs(15));
+///
+/// timer.flip(&time);
+/// assert_eq!(timer.remaining(&time), Duration::from_secs(45));
+///
+/// time.advance(Duration::from_secs(50));
+/// assert!(timer.is_expired(&time));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Timer {
+    duration: Duration,
+    remaining: Duration,
+    started: Option<Duration>,
+}
+
+impl Timer {
+    /// Creates a stopped timer with the full duration remaining.
+    pub const fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            remaining: duration,
+            started: None,
+        }
+    }
+
+    /// Returns the full duration of the timer.
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns whether the timer is running.
+    pub const fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+
+    /// Starts (or resumes) the timer, if not already running.
+    pub fn start<C>(&mut self, time: &C)
+    where
+        C: TimeSource,
+    {
+        if self.started.is_none() {
+            self.started = Some(time.now());
+        }
+    }
+
+    /// Stops (or pauses) the timer, keeping the time remaining.
+    pub fn stop<C>(&mut self, time: &C)
+    where
+        C: TimeSource,
+    {
+        self.remaining = self.remaining(time);
+        self.started = None;
+    }
+
+    /// Turns the timer over, so the time remaining is the time elapsed, and starts the timer.
+    pub fn flip<C>(&mut self, time: &C)
+    where
+        C: TimeSource,
+    {
+        self.remaining = self.elapsed(time);
+        self.started = Some(time.now());
+    }
+
+    /// Stops the timer, and restores the full duration.
+    pub fn reset(&mut self) {
+        self.remaining = self.duration;
+        self.started = None;
+    }
+
+    /// Returns the time remaining.
+    pub fn remaining<C>(&self, time: &C) -> Duration
+    where
+        C: TimeSource,
+    {
+        match self.started {
+            Some(started) => self
+                .remaining
+                .saturating_sub(time.now().saturating_sub(started)),
+            None => self.remaining,
+        }
+    }
+
+    /// Returns the time elapsed, i.e. the duration minus the time remaining.
+    pub fn elapsed<C>(&self, time: &C) -> Duration
+    where
+        C: TimeSource,
+    {
+        self.duration - self.remaining(time)
+    }
+
+    /// Returns whether there is no time remaining.
+    pub fn is_expired<C>(&self, time: &C) -> bool
+    where
+        C: TimeSource,
+    {
+        self.remaining(time).is_zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: Duration = Duration::from_secs(60);
+    const SECOND: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn timer_stopped() {
+        let time = ManualTime::new();
+        let timer = Timer::new(MINUTE);
+        time.advance(MINUTE);
+
+        assert!(!timer.is_running());
+        assert_eq!(timer.duration(), MINUTE);
+        assert_eq!(timer.remaining(&time), MINUTE);
+        assert!(!timer.is_expired(&time));
+    }
+
+    #[test]
+    fn timer_stop_and_resume() {
+        let time = ManualTime::new();
+        let mut timer = Timer::new(MINUTE);
+        timer.start(&time);
+        time.advance(SECOND * 10);
+        timer.stop(&time);
+        time.advance(SECOND * 10);
+
+        assert_eq!(timer.remaining(&time), SECOND * 50);
+
+        timer.start(&time);
+        timer.start(&time);
+        time.advance(SECOND * 20);
+        assert_eq!(timer.remaining(&time), SECOND * 30);
+        assert_eq!(timer.elapsed(&time), SECOND * 30);
+    }
+
+    #[test]
+    fn timer_expired() {
+        let time = ManualTime::new();
+        let mut timer = Timer::new(MINUTE);
+        timer.start(&time);
+        time.advance(MINUTE * 2);
+
+        assert!(timer.is_expired(&time));
+        assert_eq!(timer.remaining(&time), Duration::ZERO);
+
+        timer.flip(&time);
+        assert_eq!(timer.remaining(&time), MINUTE);
+    }
+
+    #[test]
+    fn timer_flip_stopped() {
+        let time = ManualTime::new();
+        let mut timer = Timer::new(MINUTE);
+        timer.flip(&time);
+
+        assert!(timer.is_running());
+        assert!(timer.is_expired(&time));
+    }
+
+    #[test]
+    fn timer_reset() {
+        let time = ManualTime::new();
+        let mut timer = Timer::new(MINUTE);
+        timer.start(&time);
+        time.advance(SECOND);
+        timer.reset();
+
+        assert!(!timer.is_running());
+        assert_eq!(timer.remaining(&time), MINUTE);
+    }
+
+    #[test]
+    fn system_time_advances() {
+        let time = SystemTime::default();
+
+        assert!(time.now() <= time.now());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::geometry::neighbors;
+use crate::traits::{Positional, SideSelector};
+
+/// A dice tower, which rolls a die by deflecting it off a series of baffles before it settles.
+///
+/// Each baffle either tips the die over one of the edges of its current face, onto a face that
+/// shares an edge with it (see [`crate::geometry::Solid`]), or leaves it on the same face, chosen
+/// by a [`SideSelector`]. A die without a known solid (i.e. a D3) may tip onto any other face. This
+/// is a deterministic (and testable) middle ground between selecting a face directly and simulating
+/// physics: the result depends on the face the die was dropped on, and approaches a fair roll as
+/// the number of baffles increases.
+///
+/// A die may stay on its face because on some solids (i.e. the faces of a D8, or a coin) every tip
+/// changes which half of the faces the die shows, so if a die had to tip at every baffle, the half
+/// it settles on would only depend on the number of baffles.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::geometry::Solid;
+/// use tomb::items::{DiceTower, RngRoller, D6};
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let tower = DiceTower::new(3);
+/// let path = tower.deflections(&D6::from(1), &roller);
+///
+/// assert_eq!(path.iter().map(|d| d.value()).collect::<Vec<_>>(), vec![3, 5, 1]);
+/// assert!(Solid::CUBE.is_adjacent(1, 3));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiceTower {
+    baffles: usize,
+}
+
+impl DiceTower {
+    /// Creates a tower with the given number of baffles, each of which deflects the die once.
+    pub const fn new(baffles: usize) -> Self {
+        Self { baffles }
+    }
+
+    /// Returns the number of baffles.
+    pub const fn baffles(&self) -> usize {
+        self.baffles
+    }
+
+    /// Drops the die through the tower, returning the die as it settles.
+    ///
+    /// With no baffles, the die settles on the face it was dropped on.
+    #[must_use]
+    pub fn roll<D, S>(&self, die: &D, selector: &S) -> D
+    where
+        D: Positional + Clone,
+        S: SideSelector,
+    {
+        let mut die = die.clone();
+        self.roll_mut(&mut die, selector);
+        die
+    }
+
+    /// Drops the die through the tower, changing it to show the face it settles on.
+    pub fn roll_mut<D, S>(&self, die: &mut D, selector: &S)
+    where
+        D: Positional,
+        S: SideSelector,
+    {
+        for _ in 0..self.baffles {
+            deflect(die, selector);
+        }
+    }
+
+    /// Drops the die through the tower, returning the die after every deflection, in order.
+    ///
+    /// The last die is the die as it settles, or no dice are returned if there are no baffles.
+    pub fn deflections<D, S>(&self, die: &D, selector: &S) -> Vec<D>
+    where
+        D: Positional + Clone,
+        S: SideSelector,
+    {
+        let mut die = die.clone();
+        (0..self.baffles)
+            .map(|_| {
+                deflect(&mut die, selector);
+                die.clone()
+            })
+            .collect()
+    }
+}
+
+/// Tips the die onto a face that shares an edge with its current face, or leaves it on the current
+/// face, chosen by the selector.
+fn deflect<D, S>(die: &mut D, selector: &S)
+where
+    D: Positional,
+    S: SideSelector,
+{
+    let options = neighbors(D::sides(), die.position());
+    match selector.select(options.len() + 1) {
+        0 => {}
+        index => die.set_position(options[index - 1]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+    use crate::geometry::Solid;
+    use crate::items::{NumericDie, RngRoller, SequenceRoller, D20, D3, D6, D8};
+
+    #[test]
+    fn tower_no_baffles() {
+        let roller = SequenceRoller::new([0]);
+        let tower = DiceTower::new(0);
+
+        assert_eq!(tower.roll(&D6::from(2), &roller), D6::from(2));
+        assert!(tower.deflections(&D6::from(2), &roller).is_empty());
+    }
+
+    #[test]
+    fn tower_deflects_to_adjacent_faces() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let tower = DiceTower::new(10);
+        let mut previous = 1;
+        for die in tower.deflections(&D20::from(1), &roller) {
+            let value = die.value() as usize;
+            assert!(value == previous || Solid::ICOSAHEDRON.is_adjacent(previous, value));
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn tower_is_deterministic() {
+        let roller = SequenceRoller::new([1, 4, 2, 0]);
+        let mut die = D6::from(1);
+        DiceTower::new(4).roll_mut(&mut die, &roller);
+
+        // 1 -> 2 (lowest neighbor of 1), -> 6 (last neighbor of 2), -> 3 (second neighbor of 6),
+        // and stays on 3.
+        assert_eq!(die, D6::from(3));
+        assert_eq!(DiceTower::new(3).baffles(), 3);
+    }
+
+    #[test]
+    fn tower_unknown_solid() {
+        let roller = SequenceRoller::new([2]);
+
+        assert_eq!(DiceTower::new(1).roll(&D3::from(1), &roller), D3::from(3));
+    }
+
+    /// Drops a die showing its first face through the tower `rolls` times, counting each face.
+    fn counts<D, const SIDES: usize>(die: &D, baffles: usize, rolls: usize) -> [usize; SIDES]
+    where
+        D: Positional + Clone,
+    {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let tower = DiceTower::new(baffles);
+        let mut counts = [0; SIDES];
+        for _ in 0..rolls {
+            counts[tower.roll(die, &roller).position()] += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn tower_d8_reaches_every_face() {
+        for baffles in [7, 8] {
+            let counts: [_; 8] = counts(&D8::new(), baffles, 8000);
+
+            assert!(
+                counts.iter().all(|&c| (850..1150).contains(&c)),
+                "{baffles}: {counts:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn tower_d2_reaches_every_face() {
+        for baffles in [1, 2] {
+            let counts: [_; 2] = counts(&NumericDie::<u8, 2>::new(), baffles, 2000);
+
+            assert!(
+                counts.iter().all(|&c| (850..1150).contains(&c)),
+                "{baffles}: {counts:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn tower_is_roughly_fair() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let tower = DiceTower::new(8);
+        let mut counts = [0; 6];
+        for _ in 0..6000 {
+            counts[tower.roll(&D6::from(1), &roller).value() as usize - 1] += 1;
+        }
+
+        assert!(
+            counts.iter().all(|&c| (850..1150).contains(&c)),
+            "{counts:?}"
+        );
+    }
+}
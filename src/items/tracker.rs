@@ -0,0 +1,197 @@
+use crate::traits::{Step, StepMut};
+
+/// A resource, such as ammunition, stress, or spell slots, that is kept within fixed bounds.
+///
+/// Unlike a die, stepping a tracker past either bound _saturates_ instead of wrapping around.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::Tracker;
+/// use tomb::traits::StepMut;
+///
+/// // A revolver with 6 rounds, fully loaded.
+/// let mut ammo = Tracker::new(0, 6).with_value(6);
+///
+/// assert_eq!(ammo.decrease(4), 0);
+/// assert_eq!(ammo.value(), 2);
+///
+/// // Only 2 of the 3 rounds could be fired.
+/// assert_eq!(ammo.decrease(3), 1);
+/// assert!(ammo.is_min());
+///
+/// ammo.back_mut();
+/// assert_eq!(ammo.value(), 0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Tracker {
+    value: i32,
+    min: i32,
+    max: i32,
+}
+
+impl Tracker {
+    /// Creates a tracker within `min..=max`, starting at the minimum.
+    ///
+    /// # Panics
+    ///
+    /// If `min` is greater than `max`.
+    pub const fn new(min: i32, max: i32) -> Self {
+        assert!(min <= max);
+        Self {
+            value: min,
+            min,
+            max,
+        }
+    }
+
+    /// Returns the tracker set to the given value, clamped to the bounds.
+    pub const fn with_value(self, value: i32) -> Self {
+        Self {
+            value: clamp(value, self.min, self.max),
+            min: self.min,
+            max: self.max,
+        }
+    }
+
+    /// Returns the current value.
+    pub const fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Returns the lower bound.
+    pub const fn min(&self) -> i32 {
+        self.min
+    }
+
+    /// Returns the upper bound.
+    pub const fn max(&self) -> i32 {
+        self.max
+    }
+
+    /// Returns whether the value is at the lower bound.
+    pub const fn is_min(&self) -> bool {
+        self.value == self.min
+    }
+
+    /// Returns whether the value is at the upper bound.
+    pub const fn is_max(&self) -> bool {
+        self.value == self.max
+    }
+
+    /// Sets the value, clamped to the bounds.
+    pub fn set(&mut self, value: i32) {
+        *self = self.with_value(value);
+    }
+
+    /// Increases the value, returning how much could not be added without passing the maximum.
+    pub fn increase(&mut self, amount: u32) -> u32 {
+        let target = self.value as i64 + amount as i64;
+        self.value = target.min(self.max as i64) as i32;
+        (target - self.value as i64) as u32
+    }
+
+    /// Decreases the value, returning how much could not be removed without passing the minimum.
+    pub fn decrease(&mut self, amount: u32) -> u32 {
+        let target = self.value as i64 - amount as i64;
+        self.value = target.max(self.min as i64) as i32;
+        (self.value as i64 - target) as u32
+    }
+}
+
+const fn clamp(value: i32, min: i32, max: i32) -> i32 {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+impl Step for Tracker {
+    /// Returns the tracker increased by 1, unless already at the maximum.
+    fn next(&self) -> Self {
+        let mut next = *self;
+        next.next_mut();
+        next
+    }
+
+    /// Returns the tracker decreased by 1, unless already at the minimum.
+    fn back(&self) -> Self {
+        let mut back = *self;
+        back.back_mut();
+        back
+    }
+}
+
+impl StepMut for Tracker {
+    /// Increases the value by 1, unless already at the maximum.
+    fn next_mut(&mut self) {
+        self.increase(1);
+    }
+
+    /// Decreases the value by 1, unless already at the minimum.
+    fn back_mut(&mut self) {
+        self.decrease(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracker_new() {
+        let tracker = Tracker::new(-2, 3);
+
+        assert_eq!(tracker.value(), -2);
+        assert_eq!(tracker.min(), -2);
+        assert_eq!(tracker.max(), 3);
+        assert!(tracker.is_min());
+        assert!(!tracker.is_max());
+    }
+
+    #[test]
+    #[should_panic]
+    fn tracker_invalid_bounds() {
+        Tracker::new(1, 0);
+    }
+
+    #[test]
+    fn tracker_with_value_clamped() {
+        assert_eq!(Tracker::new(0, 9).with_value(12).value(), 9);
+        assert_eq!(Tracker::new(0, 9).with_value(-1).value(), 0);
+
+        let mut tracker = Tracker::new(0, 9);
+        tracker.set(4);
+        assert_eq!(tracker.value(), 4);
+    }
+
+    #[test]
+    fn tracker_increase_saturates() {
+        let mut stress = Tracker::new(0, 9).with_value(7);
+
+        assert_eq!(stress.increase(2), 0);
+        assert_eq!(stress.increase(3), 3);
+        assert!(stress.is_max());
+    }
+
+    #[test]
+    fn tracker_extreme_bounds() {
+        let mut tracker = Tracker::new(i32::MIN, i32::MAX);
+
+        assert_eq!(tracker.decrease(u32::MAX), u32::MAX);
+        tracker.set(i32::MAX);
+        assert_eq!(tracker.increase(u32::MAX), u32::MAX);
+    }
+
+    #[test]
+    fn tracker_step_saturates() {
+        let slots = Tracker::new(0, 1);
+
+        assert_eq!(slots.back(), slots);
+        assert_eq!(slots.next().value(), 1);
+        assert_eq!(slots.next().next().value(), 1);
+    }
+}
@@ -0,0 +1,278 @@
+use crate::traits::{Rotate, RotateMut, Step, StepMut};
+
+/// Players seated around a table, taking turns in one direction around the wheel.
+///
+/// The direction of play can be reversed (as in _Uno_), and the wheel can be advanced by more than
+/// one player at a time with [`Rotate`] and [`RotateMut`], i.e. to skip a player.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::TurnWheel;
+/// use tomb::traits::{RotateMut, StepMut};
+///
+/// let mut wheel = TurnWheel::new(["Ann", "Bob", "Cat", "Dan"]);
+/// assert_eq!(wheel.current(), Some(&"Ann"));
+///
+/// wheel.next_mut();
+/// assert_eq!(wheel.current(), Some(&"Bob"));
+///
+/// wheel.reverse();
+/// wheel.next_mut();
+/// assert_eq!(wheel.current(), Some(&"Ann"));
+///
+/// // Skip over "Dan".
+/// wheel.skip();
+/// assert_eq!(wheel.current(), Some(&"Cat"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TurnWheel<T> {
+    players: Vec<T>,
+    current: usize,
+    reversed: bool,
+}
+
+impl<T> TurnWheel<T> {
+    /// Creates a wheel of players in seating order, where the first player goes first.
+    pub fn new<I>(players: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            players: players.into_iter().collect(),
+            current: 0,
+            reversed: false,
+        }
+    }
+
+    /// Returns every player, in seating order.
+    pub fn players(&self) -> &[T] {
+        &self.players
+    }
+
+    /// Returns how many players are seated.
+    pub fn len(&self) -> usize {
+        self.players.len()
+    }
+
+    /// Returns whether no players are seated.
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    /// Returns the player whose turn it is, or `None` if no players are seated.
+    pub fn current(&self) -> Option<&T> {
+        self.players.get(self.current)
+    }
+
+    /// Returns the player whose turn is next, or `None` if no players are seated.
+    pub fn peek(&self) -> Option<&T> {
+        self.players.get(self.offset(1))
+    }
+
+    /// Returns whether play is going in reverse seating order.
+    pub const fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Reverses the direction of play.
+    pub fn reverse(&mut self) {
+        self.reversed = !self.reversed;
+    }
+
+    /// Skips the next player, advancing the turn by two.
+    pub fn skip(&mut self) {
+        self.advance(2);
+    }
+
+    /// Seats a player so that they play next, in the current direction of play.
+    pub fn insert_next(&mut self, player: T) {
+        if self.players.is_empty() {
+            self.players.push(player);
+        } else if self.reversed {
+            self.players.insert(self.current, player);
+            self.current += 1;
+        } else {
+            self.players.insert(self.current + 1, player);
+        }
+    }
+
+    /// Removes the player whose turn it is, and passes the turn to the next player.
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.players.is_empty() {
+            return None;
+        }
+        let player = self.players.remove(self.current);
+        if self.players.is_empty() {
+            self.current = 0;
+        } else if self.reversed {
+            self.current = self
+                .current
+                .checked_sub(1)
+                .unwrap_or(self.players.len() - 1);
+        } else if self.current == self.players.len() {
+            self.current = 0;
+        }
+        Some(player)
+    }
+
+    fn offset(&self, amount: isize) -> usize {
+        if self.players.is_empty() {
+            return 0;
+        }
+        let amount = if self.reversed { -amount } else { amount };
+        (self.current as isize + amount).rem_euclid(self.players.len() as isize) as usize
+    }
+
+    fn advance(&mut self, amount: isize) {
+        self.current = self.offset(amount);
+    }
+}
+
+impl<T> Default for TurnWheel<T> {
+    fn default() -> Self {
+        Self::new([])
+    }
+}
+
+impl<T> FromIterator<T> for TurnWheel<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl<T> Step for TurnWheel<T>
+where
+    T: Clone,
+{
+    /// Returns the wheel with the turn passed to the next player.
+    fn next(&self) -> Self {
+        let mut next = self.clone();
+        next.next_mut();
+        next
+    }
+
+    /// Returns the wheel with the turn passed back to the previous player.
+    fn back(&self) -> Self {
+        let mut back = self.clone();
+        back.back_mut();
+        back
+    }
+}
+
+impl<T> StepMut for TurnWheel<T> {
+    /// Passes the turn to the next player, in the current direction of play.
+    fn next_mut(&mut self) {
+        self.advance(1);
+    }
+
+    /// Passes the turn back to the previous player, in the current direction of play.
+    fn back_mut(&mut self) {
+        self.advance(-1);
+    }
+}
+
+impl<T> Rotate for TurnWheel<T>
+where
+    T: Clone,
+{
+    fn rotate(&self, amount: isize) -> Self {
+        let mut next = self.clone();
+        next.rotate_mut(amount);
+        next
+    }
+}
+
+impl<T> RotateMut for TurnWheel<T> {
+    fn rotate_mut(&mut self, amount: isize) {
+        self.advance(amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turn_wheel_default() {
+        let mut wheel: TurnWheel<u8> = Default::default();
+        wheel.next_mut();
+        wheel.skip();
+
+        assert!(wheel.is_empty());
+        assert_eq!(wheel.current(), None);
+        assert_eq!(wheel.peek(), None);
+        assert_eq!(wheel.remove_current(), None);
+    }
+
+    #[test]
+    fn turn_wheel_wraps() {
+        let wheel: TurnWheel<_> = [1, 2, 3].into_iter().collect();
+
+        assert_eq!(wheel.back().current(), Some(&3));
+        assert_eq!(wheel.next().next().next().current(), Some(&1));
+        assert_eq!(wheel.peek(), Some(&2));
+        assert_eq!(wheel.len(), 3);
+    }
+
+    #[test]
+    fn turn_wheel_rotate() {
+        let wheel = TurnWheel::new([1, 2, 3, 4]);
+
+        assert_eq!(wheel.rotate(2).current(), Some(&3));
+        assert_eq!(wheel.rotate(-1).current(), Some(&4));
+        assert_eq!(wheel.rotate(9).current(), Some(&2));
+    }
+
+    #[test]
+    fn turn_wheel_reversed() {
+        let mut wheel = TurnWheel::new([1, 2, 3, 4]);
+        wheel.reverse();
+
+        assert!(wheel.is_reversed());
+        assert_eq!(wheel.peek(), Some(&4));
+
+        wheel.rotate_mut(2);
+        assert_eq!(wheel.current(), Some(&3));
+
+        wheel.back_mut();
+        assert_eq!(wheel.current(), Some(&4));
+    }
+
+    #[test]
+    fn turn_wheel_insert_next() {
+        let mut wheel = TurnWheel::new([1, 2, 3]);
+        wheel.insert_next(9);
+        assert_eq!(wheel.players(), &[1, 9, 2, 3]);
+        assert_eq!(wheel.peek(), Some(&9));
+
+        wheel.reverse();
+        wheel.insert_next(8);
+        assert_eq!(wheel.players(), &[8, 1, 9, 2, 3]);
+        assert_eq!(wheel.current(), Some(&1));
+        assert_eq!(wheel.peek(), Some(&8));
+    }
+
+    #[test]
+    fn turn_wheel_insert_empty() {
+        let mut wheel = TurnWheel::default();
+        wheel.insert_next(1);
+
+        assert_eq!(wheel.current(), Some(&1));
+    }
+
+    #[test]
+    fn turn_wheel_remove_current() {
+        let mut wheel = TurnWheel::new([1, 2, 3]);
+        wheel.back_mut();
+
+        assert_eq!(wheel.remove_current(), Some(3));
+        assert_eq!(wheel.current(), Some(&1));
+
+        wheel.reverse();
+        assert_eq!(wheel.remove_current(), Some(1));
+        assert_eq!(wheel.current(), Some(&2));
+        assert_eq!(wheel.remove_current(), Some(2));
+        assert!(wheel.is_empty());
+    }
+}
@@ -28,9 +28,26 @@
 //! assert_eq!(d20.value(), 10);
 //! ```
 
+#[cfg(feature = "bincode")]
+pub mod codec;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod geometry;
 pub mod items;
+mod macros;
+pub mod mechanics;
+#[cfg(feature = "net")]
+pub mod net;
+#[cfg(feature = "fastrand")]
+pub mod quick;
+pub mod stats;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+pub mod tables;
 pub mod traits;
 
+pub use error::Error;
 pub use items::{NumericDie, RngRoller, D20, D6};
 pub use traits::{Roll, RollMut};
 
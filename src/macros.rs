@@ -0,0 +1,122 @@
+/// Creates a [`NumericDie`](crate::NumericDie) with any number of sides, or an array of them.
+///
+/// Each die starts at `1`, like [`NumericDie::new`](crate::NumericDie::new), and values are `u8`
+/// unless a wider type is given, i.e. `d!(u16: 1000)`; a number of sides that does not fit the type
+/// is a compile error.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::d;
+/// use tomb::items::NumericDie;
+///
+/// let d7: NumericDie<u8, 7> = d!(7);
+/// assert_eq!(d7.value(), 1);
+///
+/// let pool = d!(6; 4);
+/// assert_eq!(pool.len(), 4);
+///
+/// let d1000 = d!(u16: 1000);
+/// assert_eq!(d1000, NumericDie::<u16, 1000>::new());
+/// ```
+///
+/// Too many sides for the type:
+///
+/// ```compile_fail
+/// let d300 = tomb::d!(300);
+/// ```
+#[macro_export]
+macro_rules! d {
+    ($t:ty: $sides:expr; $count:expr) => {
+        [$crate::d!($t: $sides); $count]
+    };
+    ($t:ty: $sides:expr) => {{
+        const { ::std::assert!($sides as u128 <= <$t>::MAX as u128) };
+        $crate::items::NumericDie::<$t, { $sides }>::new()
+    }};
+    ($sides:expr; $count:expr) => {
+        $crate::d!(u8: $sides; $count)
+    };
+    ($sides:expr) => {
+        $crate::d!(u8: $sides)
+    };
+}
+
+/// Rolls dice notation, i.e. `roll!(roller, 4d6kh3 + 2)`, returning a [`NotationRoll`].
+///
+/// The notation is parsed at compile time, so invalid notation is a compile error; see
+/// [`Notation`] for the supported syntax. The roller is any [`SideSelector`].
+///
+/// [`Notation`]: crate::mechanics::Notation
+/// [`NotationRoll`]: crate::mechanics::NotationRoll
+/// [`SideSelector`]: crate::traits::SideSelector
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::RngRoller;
+/// use tomb::roll;
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let attack = roll!(roller, 1d20 + 5);
+///
+/// assert_eq!(attack.rolls(), &[10]);
+/// assert_eq!(attack.total(), 15);
+/// ```
+///
+/// Invalid notation:
+///
+/// ```compile_fail
+/// # use fastrand::Rng;
+/// # use tomb::items::RngRoller;
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let roll = tomb::roll!(roller, 3x6);
+/// ```
+#[macro_export]
+macro_rules! roll {
+    ($roller:expr, $($notation:tt)+) => {
+        $crate::mechanics::Notation::roll(
+            &const {
+                match $crate::mechanics::Notation::parse(::std::stringify!($($notation)+)) {
+                    ::std::result::Result::Ok(notation) => notation,
+                    ::std::result::Result::Err(_) => ::std::panic!("invalid dice notation"),
+                }
+            },
+            &$roller,
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::items::{NumericDie, D20, D6};
+
+    #[test]
+    fn d_single() {
+        assert_eq!(d!(20), D20::new());
+        assert_eq!(d!(u32: 70_000), NumericDie::<u32, 70_000>::new());
+    }
+
+    #[test]
+    fn d_pool() {
+        assert_eq!(d!(6; 3), [D6::new(); 3]);
+        assert_eq!(d!(u16: 256; 2), [NumericDie::<u16, 256>::new(); 2]);
+    }
+
+    #[test]
+    fn roll_notation() {
+        use crate::traits::SideSelector;
+
+        struct Highest;
+
+        impl SideSelector for Highest {
+            fn select(&self, sides: usize) -> usize {
+                sides - 1
+            }
+        }
+
+        assert_eq!(roll!(Highest, 4d6kh3 + 2).total(), 20);
+        assert_eq!(roll!(Highest, d20 - 1).total(), 19);
+    }
+}
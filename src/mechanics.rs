@@ -0,0 +1,47 @@
+//! Contains rules that resolve rolls into outcomes for common tabletop mechanics.
+//!
+//! Most mechanics are built on top of a [`Check`], a die combined with a flat modifier (i.e.
+//! `1d20+5`), which is rolled using any [`crate::traits::Roll`] implementation.
+//!
+//! # Examples
+//!
+//! ```
+//! use fastrand::Rng;
+//! use tomb::items::{D20, RngRoller};
+//! use tomb::mechanics::{Check, Opposed, TieBreak, Winner};
+//!
+//! let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+//! let attacker = Check::new(D20::new(), 5);
+//! let defender = Check::new(D20::new(), 3);
+//!
+//! let contest = Opposed::new(TieBreak::Defender).roll(&roller, &attacker, &defender);
+//! assert_eq!(contest.attacker(), 15);
+//! assert_eq!(contest.defender(), 16);
+//! assert_eq!(contest.winner(), Winner::Defender);
+//! ```
+
+mod breakdown;
+mod check;
+mod craps;
+mod initiative;
+mod ironsworn;
+mod matches;
+mod notation;
+mod opposed;
+mod oracle;
+mod pbta;
+mod shade;
+mod yahtzee;
+
+pub use breakdown::*;
+pub use check::*;
+pub use craps::*;
+pub use initiative::*;
+pub use ironsworn::*;
+pub use matches::*;
+pub use notation::*;
+pub use opposed::*;
+pub use oracle::*;
+pub use pbta::*;
+pub use shade::*;
+pub use yahtzee::*;
@@ -0,0 +1,251 @@
+use std::fmt::Display;
+
+/// A die in a [`Breakdown`], and whether it counts towards the total.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BreakdownDie {
+    face: usize,
+    dropped: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    rerolled: bool,
+}
+
+impl BreakdownDie {
+    /// Creates a die showing the given face, which is either kept or dropped.
+    pub const fn new(face: usize, dropped: bool) -> Self {
+        Self {
+            face,
+            dropped,
+            rerolled: false,
+        }
+    }
+
+    /// Creates a die showing the given face that was rolled again, i.e. a `1` when rerolling ones.
+    ///
+    /// A rerolled die does not count towards the total, and is followed by its reroll.
+    pub const fn rerolled(face: usize) -> Self {
+        Self {
+            face,
+            dropped: false,
+            rerolled: true,
+        }
+    }
+
+    /// Returns the natural face of the die.
+    pub const fn face(&self) -> usize {
+        self.face
+    }
+
+    /// Returns whether the die was dropped, i.e. by keeping only the highest dice.
+    pub const fn is_dropped(&self) -> bool {
+        self.dropped
+    }
+
+    /// Returns whether the die was rolled again, and replaced by its reroll.
+    pub const fn is_rerolled(&self) -> bool {
+        self.rerolled
+    }
+
+    /// Returns whether the die counts towards the total, i.e. was neither dropped nor rerolled.
+    pub const fn is_kept(&self) -> bool {
+        !self.dropped && !self.rerolled
+    }
+}
+
+/// Explains how the total of a roll was reached, i.e. `3d6+2 → [2, 5, 6] + 2 = 15`.
+///
+/// The structured form can be used to present a roll (i.e. in a UI), and the [`Display`] form lists
+/// the expression, every die, with dropped dice in parentheses and rerolled dice followed by `↻`,
+/// the modifier, and the total.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::mechanics::{Notation, NotationRoll};
+///
+/// let notation: Notation = "4d6kh3+2".parse().unwrap();
+/// let breakdown = NotationRoll::new(notation, vec![2, 6, 1, 4]).explain();
+///
+/// assert_eq!(breakdown.total(), 14);
+/// assert!(breakdown.dice()[2].is_dropped());
+/// assert_eq!(breakdown.to_string(), "4d6kh3+2 → [2, 6, (1), 4] + 2 = 14");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "BreakdownRepr")
+)]
+pub struct Breakdown {
+    expression: String,
+    dice: Vec<BreakdownDie>,
+    modifier: i64,
+    total: i64,
+}
+
+impl Breakdown {
+    /// Creates an explanation for the given expression, dice, and modifier.
+    ///
+    /// The total is the sum of the faces of every die that was neither dropped nor rerolled, plus
+    /// the modifier.
+    pub fn new(expression: impl Into<String>, dice: Vec<BreakdownDie>, modifier: i64) -> Self {
+        let total = dice
+            .iter()
+            .filter(|d| d.is_kept())
+            .map(|d| d.face as i64)
+            .sum::<i64>()
+            + modifier;
+        Self {
+            expression: expression.into(),
+            dice,
+            modifier,
+            total,
+        }
+    }
+
+    /// Returns the expression that was rolled, i.e. `3d6+2`.
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// Returns every die, in the order rolled.
+    pub fn dice(&self) -> &[BreakdownDie] {
+        &self.dice
+    }
+
+    /// Returns the flat modifier added to the total.
+    pub const fn modifier(&self) -> i64 {
+        self.modifier
+    }
+
+    /// Returns the total.
+    pub const fn total(&self) -> i64 {
+        self.total
+    }
+}
+
+/// The serialized form of a [`Breakdown`], where the total is recomputed when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct BreakdownRepr {
+    expression: String,
+    dice: Vec<BreakdownDie>,
+    modifier: i64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<BreakdownRepr> for Breakdown {
+    type Error = crate::Error;
+
+    /// Creates an explanation from the serialized fields, failing if the total overflows.
+    fn try_from(repr: BreakdownRepr) -> Result<Self, Self::Error> {
+        let mut total = repr.modifier;
+        for die in repr.dice.iter().filter(|d| d.is_kept()) {
+            total = i64::try_from(die.face)
+                .ok()
+                .and_then(|face| total.checked_add(face))
+                .ok_or(crate::Error::OutOfRange {
+                    value: die.face,
+                    min: 0,
+                    max: usize::try_from(i64::MAX.saturating_sub(total)).unwrap_or(0),
+                })?;
+        }
+        Ok(Self {
+            expression: repr.expression,
+            dice: repr.dice,
+            modifier: repr.modifier,
+            total,
+        })
+    }
+}
+
+impl Display for Breakdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} → [", self.expression)?;
+        for (i, die) in self.dice.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            if die.dropped {
+                write!(f, "({})", die.face)?;
+            } else if die.rerolled {
+                write!(f, "{}↻", die.face)?;
+            } else {
+                write!(f, "{}", die.face)?;
+            }
+        }
+        f.write_str("]")?;
+        match self.modifier {
+            0 => {}
+            m if m > 0 => write!(f, " + {m}")?,
+            m => write!(f, " - {}", m.unsigned_abs())?,
+        }
+        write!(f, " = {}", self.total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breakdown_without_modifier() {
+        let breakdown = Breakdown::new("2d6", vec![BreakdownDie::new(3, false); 2], 0);
+
+        assert_eq!(breakdown.to_string(), "2d6 → [3, 3] = 6");
+    }
+
+    #[test]
+    fn breakdown_negative_modifier() {
+        let breakdown = Breakdown::new("1d20-2", vec![BreakdownDie::new(12, false)], -2);
+
+        assert_eq!(breakdown.expression(), "1d20-2");
+        assert_eq!(breakdown.modifier(), -2);
+        assert_eq!(breakdown.to_string(), "1d20-2 → [12] - 2 = 10");
+    }
+
+    #[test]
+    fn breakdown_dropped() {
+        let dice = vec![BreakdownDie::new(17, true), BreakdownDie::new(4, false)];
+        let breakdown = Breakdown::new("2d20kl1", dice, 0);
+
+        assert_eq!(breakdown.total(), 4);
+        assert_eq!(breakdown.dice()[0].face(), 17);
+        assert_eq!(breakdown.to_string(), "2d20kl1 → [(17), 4] = 4");
+    }
+
+    #[test]
+    fn breakdown_rerolled() {
+        let dice = vec![
+            BreakdownDie::rerolled(1),
+            BreakdownDie::new(4, false),
+            BreakdownDie::new(5, false),
+        ];
+        let breakdown = Breakdown::new("2d6r1", dice, 0);
+
+        assert!(breakdown.dice()[0].is_rerolled());
+        assert!(!breakdown.dice()[0].is_kept());
+        assert_eq!(breakdown.total(), 9);
+        assert_eq!(breakdown.to_string(), "2d6r1 → [1↻, 4, 5] = 9");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn breakdown_serde_recomputes_total() {
+        let json = r#"{"expression":"2d6","dice":[{"face":3,"dropped":false},{"face":5,"dropped":true}],"modifier":1,"total":100}"#;
+        let breakdown: Breakdown = serde_json::from_str(json).unwrap();
+
+        assert_eq!(breakdown.total(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn breakdown_serde_overflow() {
+        let json = format!(
+            r#"{{"expression":"1d6","dice":[{{"face":1,"dropped":false}}],"modifier":{}}}"#,
+            i64::MAX
+        );
+
+        assert!(serde_json::from_str::<Breakdown>(&json).is_err());
+    }
+}
@@ -0,0 +1,188 @@
+use std::fmt::Display;
+
+use super::{Breakdown, BreakdownDie};
+use crate::items::MarkdownDisplay;
+use crate::traits::{Critical, Positional, Roll, Rotate};
+
+/// A die combined with a flat modifier, i.e. `1d20+5`.
+///
+/// The _total_ of a check is the natural face of the die (see [`Critical::natural`]) plus the
+/// modifier, which may be negative.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::D20;
+/// use tomb::mechanics::Check;
+///
+/// let check = Check::new(D20::from(12), -2);
+/// assert_eq!(check.total(), 10);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Check<D> {
+    die: D,
+    modifier: i32,
+}
+
+impl<D> Check<D>
+where
+    D: Critical + Rotate,
+{
+    /// Creates a new check from the given die and flat modifier.
+    pub const fn new(die: D, modifier: i32) -> Self {
+        Self { die, modifier }
+    }
+
+    /// Returns a reference to the die being checked.
+    pub const fn die(&self) -> &D {
+        &self.die
+    }
+
+    /// Returns the flat modifier applied to the die.
+    pub const fn modifier(&self) -> i32 {
+        self.modifier
+    }
+
+    /// Returns the natural face of the die plus the modifier.
+    pub fn total(&self) -> i32 {
+        self.die.natural() as i32 + self.modifier
+    }
+
+    /// Returns a formatter for the check as Markdown, i.e. `1d20+5: [**20**] + 5 = 25`.
+    pub fn markdown(&self) -> MarkdownDisplay<'_, D> {
+        MarkdownDisplay::new(std::slice::from_ref(&self.die)).with_modifier(self.modifier)
+    }
+
+    /// Explains how the total was reached, i.e. `1d20+5 → [12] + 5 = 17`.
+    pub fn explain(&self) -> Breakdown {
+        let expression = match self.modifier {
+            0 => format!("1d{}", D::sides()),
+            m if m > 0 => format!("1d{}+{m}", D::sides()),
+            m => format!("1d{}{m}", D::sides()),
+        };
+        Breakdown::new(
+            expression,
+            vec![BreakdownDie::new(self.die.natural(), false)],
+            self.modifier as i64,
+        )
+    }
+
+    /// Rolls the die using the provided roller, returning a new check with the same modifier.
+    #[must_use]
+    pub fn roll<R>(&self, roller: &R) -> Self
+    where
+        R: Roll,
+        D: Positional,
+    {
+        Self::new(roller.roll(&self.die), self.modifier)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, D> arbitrary::Arbitrary<'a> for Check<D>
+where
+    D: arbitrary::Arbitrary<'a> + Critical + Rotate,
+{
+    /// Creates a check of any die, with a modifier within the range of an `i16`.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(D::arbitrary(u)?, i16::arbitrary(u)? as i32))
+    }
+}
+
+impl<D> Display for Check<D>
+where
+    D: Critical + Rotate,
+{
+    /// Formats the check as an expression and its total, i.e. `1d20+5: 12 + 5 = 17`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (natural, modifier) = (self.die.natural(), self.modifier);
+        match modifier {
+            0 => write!(f, "1d{}: {}", D::sides(), natural),
+            m if m > 0 => write!(
+                f,
+                "1d{}+{m}: {natural} + {m} = {}",
+                D::sides(),
+                self.total()
+            ),
+            m => write!(
+                f,
+                "1d{}{m}: {natural} - {} = {}",
+                D::sides(),
+                m.unsigned_abs(),
+                self.total()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{NopRoller, D20};
+
+    #[test]
+    fn check_total() {
+        let check = Check::new(D20::from(12), 5);
+
+        assert_eq!(check.die(), &D20::from(12));
+        assert_eq!(check.modifier(), 5);
+        assert_eq!(check.total(), 17);
+    }
+
+    #[test]
+    fn check_total_negative() {
+        let check = Check::new(D20::from(1), -3);
+
+        assert_eq!(check.total(), -2);
+    }
+
+    #[test]
+    fn check_roll_keeps_modifier() {
+        let check = Check::new(D20::from(7), 2);
+        let rolled = check.roll(&NopRoller);
+
+        assert_eq!(rolled, check);
+    }
+
+    #[test]
+    fn check_display() {
+        assert_eq!(
+            Check::new(D20::from(12), 5).to_string(),
+            "1d20+5: 12 + 5 = 17"
+        );
+        assert_eq!(
+            Check::new(D20::from(12), -2).to_string(),
+            "1d20-2: 12 - 2 = 10"
+        );
+        assert_eq!(Check::new(D20::from(12), 0).to_string(), "1d20: 12");
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn check_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let check = Check::<D20>::arbitrary(&mut Unstructured::new(&[4, 255, 255])).unwrap();
+        assert_eq!(check.total(), check.die().value() as i32 + check.modifier());
+    }
+
+    #[test]
+    fn check_markdown() {
+        let check = Check::new(D20::from(20), 5);
+
+        assert_eq!(check.markdown().to_string(), "1d20+5: [**20**] + 5 = 25");
+    }
+
+    #[test]
+    fn check_explain() {
+        let check = Check::new(D20::from(12), -2);
+
+        assert_eq!(check.explain().total(), check.total() as i64);
+        assert_eq!(check.explain().to_string(), "1d20-2 → [12] - 2 = 10");
+        assert_eq!(
+            Check::new(D20::from(3), 0).explain().to_string(),
+            "1d20 → [3] = 3"
+        );
+    }
+}
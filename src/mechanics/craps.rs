@@ -0,0 +1,211 @@
+use crate::items::D6;
+use crate::traits::RollMut;
+
+/// The outcome of a _come-out_ roll in craps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComeOut {
+    /// A `7` or `11`, which immediately wins.
+    Natural(u8),
+
+    /// A `2`, `3`, or `12`, which immediately loses.
+    Craps(u8),
+
+    /// Any other total, which becomes the point.
+    Point(u8),
+}
+
+impl ComeOut {
+    /// Returns the come-out outcome of a `2d6` total, between `2..=12`.
+    ///
+    /// # Panics
+    ///
+    /// If the total is out of range.
+    pub fn from_total(total: u8) -> Self {
+        assert!((2..=12).contains(&total));
+        match total {
+            7 | 11 => Self::Natural(total),
+            2 | 3 | 12 => Self::Craps(total),
+            _ => Self::Point(total),
+        }
+    }
+}
+
+/// The phase of a round of craps (from the perspective of a pass line bet).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    /// Waiting for a come-out roll.
+    ComeOut,
+
+    /// A point was established, and rolls continue until the point or a `7`.
+    Point(u8),
+
+    /// The round was won, either by a natural or by making the point.
+    Won,
+
+    /// The round was lost, either by craps or by rolling a `7` before the point.
+    Lost,
+}
+
+/// A round of craps, resolved across successive `2d6` rolls.
+///
+/// Once a round is [`Phase::Won`] or [`Phase::Lost`], the next roll starts a new round.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::D6;
+/// use tomb::mechanics::{Craps, Phase};
+///
+/// let mut craps = Craps::new();
+/// assert_eq!(craps.resolve([D6::from(2), D6::from(2)]), Phase::Point(4));
+/// assert_eq!(craps.resolve([D6::from(5), D6::from(1)]), Phase::Point(4));
+/// assert_eq!(craps.resolve([D6::from(3), D6::from(1)]), Phase::Won);
+/// assert_eq!(craps.rolls(), 3);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Craps {
+    phase: Phase,
+    rolls: usize,
+}
+
+impl Craps {
+    /// Creates a new round waiting for a come-out roll.
+    pub const fn new() -> Self {
+        Self {
+            phase: Phase::ComeOut,
+            rolls: 0,
+        }
+    }
+
+    /// Returns the current phase.
+    pub const fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// Returns how many times the dice were rolled in the current round.
+    pub const fn rolls(&self) -> usize {
+        self.rolls
+    }
+
+    /// Returns whether the current round was either won or lost.
+    pub const fn is_resolved(&self) -> bool {
+        matches!(self.phase, Phase::Won | Phase::Lost)
+    }
+
+    /// Rolls `2d6` using the provided roller and advances the round.
+    pub fn roll<R>(&mut self, roller: &R) -> Phase
+    where
+        R: RollMut,
+    {
+        let mut dice = [D6::new(), D6::new()];
+        for die in &mut dice {
+            roller.roll_mut(die);
+        }
+        self.resolve(dice)
+    }
+
+    /// Advances the round given two already rolled dice.
+    pub fn resolve(&mut self, dice: [D6; 2]) -> Phase {
+        if self.is_resolved() {
+            *self = Self::new();
+        }
+        let total = dice[0].value() + dice[1].value();
+        self.rolls += 1;
+        self.phase = match self.phase {
+            Phase::Point(point) if total == point => Phase::Won,
+            Phase::Point(_) if total == 7 => Phase::Lost,
+            Phase::Point(point) => Phase::Point(point),
+            _ => match ComeOut::from_total(total) {
+                ComeOut::Natural(_) => Phase::Won,
+                ComeOut::Craps(_) => Phase::Lost,
+                ComeOut::Point(point) => Phase::Point(point),
+            },
+        };
+        self.phase
+    }
+}
+
+impl Default for Craps {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::NopRoller;
+
+    fn dice(a: u8, b: u8) -> [D6; 2] {
+        [D6::from(a), D6::from(b)]
+    }
+
+    #[test]
+    fn come_out_from_total() {
+        assert_eq!(ComeOut::from_total(7), ComeOut::Natural(7));
+        assert_eq!(ComeOut::from_total(11), ComeOut::Natural(11));
+        assert_eq!(ComeOut::from_total(2), ComeOut::Craps(2));
+        assert_eq!(ComeOut::from_total(3), ComeOut::Craps(3));
+        assert_eq!(ComeOut::from_total(12), ComeOut::Craps(12));
+        assert_eq!(ComeOut::from_total(4), ComeOut::Point(4));
+        assert_eq!(ComeOut::from_total(10), ComeOut::Point(10));
+    }
+
+    #[test]
+    #[should_panic]
+    fn come_out_from_total_out_of_range() {
+        ComeOut::from_total(13);
+    }
+
+    #[test]
+    fn craps_default() {
+        let craps: Craps = Default::default();
+
+        assert_eq!(craps.phase(), Phase::ComeOut);
+        assert_eq!(craps.rolls(), 0);
+        assert!(!craps.is_resolved());
+    }
+
+    #[test]
+    fn craps_natural() {
+        let mut craps = Craps::new();
+
+        assert_eq!(craps.resolve(dice(5, 6)), Phase::Won);
+        assert!(craps.is_resolved());
+    }
+
+    #[test]
+    fn craps_craps() {
+        let mut craps = Craps::new();
+
+        assert_eq!(craps.resolve(dice(1, 1)), Phase::Lost);
+        assert!(craps.is_resolved());
+    }
+
+    #[test]
+    fn craps_seven_out() {
+        let mut craps = Craps::new();
+
+        assert_eq!(craps.resolve(dice(4, 4)), Phase::Point(8));
+        assert_eq!(craps.resolve(dice(5, 6)), Phase::Point(8));
+        assert_eq!(craps.resolve(dice(1, 1)), Phase::Point(8));
+        assert_eq!(craps.resolve(dice(3, 4)), Phase::Lost);
+        assert_eq!(craps.rolls(), 4);
+    }
+
+    #[test]
+    fn craps_new_round_after_resolved() {
+        let mut craps = Craps::new();
+        craps.resolve(dice(3, 4));
+
+        assert_eq!(craps.resolve(dice(3, 3)), Phase::Point(6));
+        assert_eq!(craps.rolls(), 1);
+    }
+
+    #[test]
+    fn craps_roll() {
+        let mut craps = Craps::new();
+
+        assert_eq!(craps.roll(&NopRoller), Phase::Lost);
+    }
+}
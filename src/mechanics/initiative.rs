@@ -0,0 +1,286 @@
+use std::cmp::Reverse;
+
+use crate::items::D20;
+use crate::mechanics::Check;
+use crate::traits::{Roll, Step, StepMut};
+
+/// How participants with equal initiative totals are ordered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InitiativeTie {
+    /// Equal totals keep the order participants were added in.
+    #[default]
+    Order,
+
+    /// Equal totals are ordered by the higher modifier, otherwise the order added in.
+    Modifier,
+
+    /// Equal totals are ordered by an additional `1d20`, otherwise the order added in.
+    Reroll,
+}
+
+/// Rolls initiative (`1d20` plus a modifier) for named participants to determine a turn order.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::NopRoller;
+/// use tomb::mechanics::{Initiative, InitiativeTie};
+/// use tomb::traits::StepMut;
+///
+/// let mut order = Initiative::new()
+///     .with("Goblin", 2)
+///     .with("Fighter", 1)
+///     .with("Wizard", 2)
+///     .with_ties(InitiativeTie::Order)
+///     .roll(&NopRoller);
+///
+/// assert_eq!(order.current().unwrap().name(), "Goblin");
+///
+/// order.next_mut();
+/// assert_eq!(order.current().unwrap().name(), "Wizard");
+///
+/// order.next_mut();
+/// order.next_mut();
+/// assert_eq!(order.current().unwrap().name(), "Goblin");
+/// assert_eq!(order.round(), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Initiative {
+    participants: Vec<(String, i32)>,
+    ties: InitiativeTie,
+}
+
+impl Initiative {
+    /// Creates an initiative with no participants, where ties keep the order added in.
+    pub const fn new() -> Self {
+        Self {
+            participants: Vec::new(),
+            ties: InitiativeTie::Order,
+        }
+    }
+
+    /// Returns the initiative with another named participant and modifier added.
+    #[must_use]
+    pub fn with(mut self, name: impl Into<String>, modifier: i32) -> Self {
+        self.add(name, modifier);
+        self
+    }
+
+    /// Returns the initiative with the given tie-breaking rule.
+    #[must_use]
+    pub fn with_ties(mut self, ties: InitiativeTie) -> Self {
+        self.ties = ties;
+        self
+    }
+
+    /// Adds another named participant and modifier.
+    pub fn add(&mut self, name: impl Into<String>, modifier: i32) {
+        self.participants.push((name.into(), modifier));
+    }
+
+    /// Returns the tie-breaking rule.
+    pub const fn ties(&self) -> InitiativeTie {
+        self.ties
+    }
+
+    /// Rolls for each participant using the provided roller, returning the turn order.
+    pub fn roll<R>(&self, roller: &R) -> TurnOrder
+    where
+        R: Roll,
+    {
+        let mut turns: Vec<(Turn, u8)> = self
+            .participants
+            .iter()
+            .map(|(name, modifier)| {
+                let check = Check::new(D20::new(), *modifier).roll(roller);
+                let tiebreak = match self.ties {
+                    InitiativeTie::Reroll => roller.roll(&D20::new()).value(),
+                    _ => 0,
+                };
+                let turn = Turn {
+                    name: name.clone(),
+                    check,
+                };
+                (turn, tiebreak)
+            })
+            .collect();
+        turns.sort_by_key(|(turn, tiebreak)| {
+            let modifier = match self.ties {
+                InitiativeTie::Modifier => turn.check.modifier(),
+                _ => 0,
+            };
+            Reverse((turn.total(), modifier, *tiebreak))
+        });
+        TurnOrder {
+            turns: turns.into_iter().map(|(turn, _)| turn).collect(),
+            current: 0,
+            round: 1,
+        }
+    }
+}
+
+/// A participant's rolled initiative in a [`TurnOrder`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Turn {
+    name: String,
+    check: Check<D20>,
+}
+
+impl Turn {
+    /// Returns the name of the participant.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the rolled check.
+    pub const fn check(&self) -> &Check<D20> {
+        &self.check
+    }
+
+    /// Returns the rolled total.
+    pub fn total(&self) -> i32 {
+        self.check.total()
+    }
+}
+
+/// The order participants take turns in, from highest to lowest initiative, which repeats.
+///
+/// Advancing (see [`Step`] and [`StepMut`]) past the last turn starts the next round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TurnOrder {
+    turns: Vec<Turn>,
+    current: usize,
+    round: usize,
+}
+
+impl TurnOrder {
+    /// Returns every turn, in order.
+    pub fn turns(&self) -> &[Turn] {
+        &self.turns
+    }
+
+    /// Returns the current turn, or `None` if there are no participants.
+    pub fn current(&self) -> Option<&Turn> {
+        self.turns.get(self.current)
+    }
+
+    /// Returns the current round, starting at `1`.
+    pub const fn round(&self) -> usize {
+        self.round
+    }
+}
+
+impl Step for TurnOrder {
+    /// Returns the order advanced to the next turn.
+    fn next(&self) -> Self {
+        let mut next = self.clone();
+        next.next_mut();
+        next
+    }
+
+    /// Returns the order moved back to the previous turn.
+    fn back(&self) -> Self {
+        let mut back = self.clone();
+        back.back_mut();
+        back
+    }
+}
+
+impl StepMut for TurnOrder {
+    /// Advances to the next turn, starting the next round after the last turn.
+    fn next_mut(&mut self) {
+        if self.turns.is_empty() {
+            return;
+        }
+        self.current += 1;
+        if self.current == self.turns.len() {
+            self.current = 0;
+            self.round += 1;
+        }
+    }
+
+    /// Moves back to the previous turn, returning to the previous round before the first turn.
+    ///
+    /// Never moves back before the first turn of the first round.
+    fn back_mut(&mut self) {
+        if self.current > 0 {
+            self.current -= 1;
+        } else if self.round > 1 && !self.turns.is_empty() {
+            self.current = self.turns.len() - 1;
+            self.round -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+    use crate::items::{NopRoller, RngRoller};
+
+    fn names(order: &TurnOrder) -> Vec<&str> {
+        order.turns().iter().map(|t| t.name()).collect()
+    }
+
+    #[test]
+    fn initiative_default() {
+        let initiative: Initiative = Default::default();
+        let mut order = initiative.roll(&NopRoller);
+
+        assert_eq!(initiative.ties(), InitiativeTie::Order);
+        assert_eq!(order.current(), None);
+
+        order.next_mut();
+        order.back_mut();
+        assert_eq!(order.round(), 1);
+    }
+
+    #[test]
+    fn initiative_sorted_by_total() {
+        let order = Initiative::new()
+            .with("a", -1)
+            .with("b", 3)
+            .with("c", 0)
+            .roll(&NopRoller);
+
+        assert_eq!(names(&order), ["b", "c", "a"]);
+        assert_eq!(order.turns()[0].total(), 4);
+        assert_eq!(order.turns()[0].check().modifier(), 3);
+    }
+
+    #[test]
+    fn initiative_ties_by_modifier() {
+        let mut initiative = Initiative::new().with_ties(InitiativeTie::Modifier);
+        initiative.add("a", 1);
+        initiative.add("b", 1);
+        let order = initiative.roll(&NopRoller);
+
+        assert_eq!(names(&order), ["a", "b"]);
+    }
+
+    #[test]
+    fn initiative_roll() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let order = Initiative::new()
+            .with("a", 0)
+            .with("b", 0)
+            .with("c", 0)
+            .with_ties(InitiativeTie::Reroll)
+            .roll(&roller);
+
+        let totals: Vec<_> = order.turns().iter().map(|t| t.total()).collect();
+        assert!(totals.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn turn_order_step() {
+        let order = Initiative::new().with("a", 1).with("b", 0).roll(&NopRoller);
+        let next = order.next();
+
+        assert_eq!(next.current().unwrap().name(), "b");
+        assert_eq!(next.next().round(), 2);
+        assert_eq!(next.next().back(), next);
+        assert_eq!(order.back(), order);
+    }
+}
@@ -0,0 +1,202 @@
+use super::Outcome;
+use crate::items::{D10, D6};
+use crate::traits::RollMut;
+
+/// The highest possible action score, regardless of the stat and adds.
+const MAXIMUM_SCORE: i32 = 10;
+
+/// An _Ironsworn_ action roll, rolled as `1d6+stat+adds` against two `d10` challenge dice.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::RngRoller;
+/// use tomb::mechanics::{Action, Outcome};
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let roll = Action::new(2).with_adds(1).roll(&roller);
+///
+/// assert_eq!(roll.score(), roll.action().value() as i32 + 3);
+/// assert_eq!(roll.outcome(), Outcome::WeakHit);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Action {
+    stat: i32,
+    adds: i32,
+}
+
+impl Action {
+    /// Creates a new action that adds the given stat to the action die.
+    pub const fn new(stat: i32) -> Self {
+        Self { stat, adds: 0 }
+    }
+
+    /// Returns an action with the given adds (bonuses) applied on top of the stat.
+    pub const fn with_adds(self, adds: i32) -> Self {
+        Self {
+            stat: self.stat,
+            adds,
+        }
+    }
+
+    /// Returns the stat added to the action die.
+    pub const fn stat(&self) -> i32 {
+        self.stat
+    }
+
+    /// Returns the adds added to the action die.
+    pub const fn adds(&self) -> i32 {
+        self.adds
+    }
+
+    /// Rolls the action die and both challenge dice using the provided roller.
+    pub fn roll<R>(&self, roller: &R) -> ActionRoll
+    where
+        R: RollMut,
+    {
+        let mut action = D6::new();
+        roller.roll_mut(&mut action);
+        let mut challenge = [D10::new(), D10::new()];
+        for die in &mut challenge {
+            roller.roll_mut(die);
+        }
+        self.resolve(action, challenge)
+    }
+
+    /// Resolves the action given an already rolled action die and challenge dice.
+    pub fn resolve(&self, action: D6, challenge: [D10; 2]) -> ActionRoll {
+        ActionRoll {
+            action,
+            challenge,
+            modifier: self.stat + self.adds,
+        }
+    }
+}
+
+/// The result of rolling an [`Action`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActionRoll {
+    action: D6,
+    challenge: [D10; 2],
+    modifier: i32,
+}
+
+impl ActionRoll {
+    /// Returns the rolled action die.
+    pub const fn action(&self) -> &D6 {
+        &self.action
+    }
+
+    /// Returns both rolled challenge dice.
+    pub const fn challenge(&self) -> &[D10; 2] {
+        &self.challenge
+    }
+
+    /// Returns the combined stat and adds.
+    pub const fn modifier(&self) -> i32 {
+        self.modifier
+    }
+
+    /// Returns the action die plus the modifier, which can never exceed `10`.
+    pub fn score(&self) -> i32 {
+        (self.action.value() as i32 + self.modifier).min(MAXIMUM_SCORE)
+    }
+
+    /// Returns the outcome, i.e. how many challenge dice the action score beat.
+    ///
+    /// A score must be _greater_ than a challenge die to beat it; ties go to the challenge die.
+    pub fn outcome(&self) -> Outcome {
+        let score = self.score();
+        let beats = self
+            .challenge
+            .iter()
+            .filter(|d| score > d.value() as i32)
+            .count();
+        match beats {
+            2 => Outcome::StrongHit,
+            1 => Outcome::WeakHit,
+            _ => Outcome::Miss,
+        }
+    }
+
+    /// Returns whether both challenge dice show the same value.
+    pub fn is_match(&self) -> bool {
+        self.challenge[0] == self.challenge[1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::NopRoller;
+
+    #[test]
+    fn action_default() {
+        let a: Action = Default::default();
+
+        assert_eq!(a, Action::new(0));
+        assert_eq!(a.stat(), 0);
+        assert_eq!(a.adds(), 0);
+    }
+
+    #[test]
+    fn action_strong_hit() {
+        let roll = Action::new(2).resolve(D6::from(4), [D10::from(3), D10::from(5)]);
+
+        assert_eq!(roll.score(), 6);
+        assert_eq!(roll.outcome(), Outcome::StrongHit);
+        assert!(!roll.is_match());
+    }
+
+    #[test]
+    fn action_weak_hit() {
+        let roll = Action::new(2).resolve(D6::from(4), [D10::from(3), D10::from(9)]);
+
+        assert_eq!(roll.outcome(), Outcome::WeakHit);
+    }
+
+    #[test]
+    fn action_weak_hit_tie_goes_to_challenge() {
+        let roll = Action::new(2).resolve(D6::from(4), [D10::from(3), D10::from(6)]);
+        assert_eq!(roll.score(), 6);
+
+        assert_eq!(roll.outcome(), Outcome::WeakHit);
+    }
+
+    #[test]
+    fn action_miss() {
+        let roll = Action::new(1).resolve(D6::from(1), [D10::from(2), D10::from(8)]);
+
+        assert_eq!(roll.outcome(), Outcome::Miss);
+    }
+
+    #[test]
+    fn action_score_capped() {
+        let roll = Action::new(4)
+            .with_adds(3)
+            .resolve(D6::from(6), [D10::from(10), D10::from(9)]);
+
+        assert_eq!(roll.modifier(), 7);
+        assert_eq!(roll.score(), 10);
+        assert_eq!(roll.outcome(), Outcome::WeakHit);
+    }
+
+    #[test]
+    fn action_match() {
+        let roll = Action::new(3).resolve(D6::from(6), [D10::from(7), D10::from(7)]);
+
+        assert!(roll.is_match());
+        assert_eq!(roll.outcome(), Outcome::StrongHit);
+    }
+
+    #[test]
+    fn action_roll() {
+        let roll = Action::new(1).roll(&NopRoller);
+
+        assert_eq!(roll.action(), &D6::from(1));
+        assert_eq!(roll.challenge(), &[D10::from(1), D10::from(1)]);
+        assert_eq!(roll.outcome(), Outcome::StrongHit);
+        assert!(roll.is_match());
+    }
+}
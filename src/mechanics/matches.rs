@@ -0,0 +1,174 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+/// A group of two or more dice showing the same face, i.e. a pair or a triple.
+///
+/// In the _One-Roll Engine_, a match is described by its _width_ (how many dice) and its _height_
+/// (the face shown), i.e. three dice showing `5` is a `3x5`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Match<T> {
+    face: T,
+    width: usize,
+}
+
+impl<T> Match<T> {
+    /// Returns the face shown by every die in the match, also known as the _height_.
+    pub const fn face(&self) -> &T {
+        &self.face
+    }
+
+    /// Returns how many dice are in the match, i.e. `2` for a pair, `3` for a triple.
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+}
+
+/// Rolled faces of a pool grouped into matches and unmatched dice (_waste_).
+///
+/// Matches are ordered from widest to narrowest, and then from highest to lowest face.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::D10;
+/// use tomb::mechanics::Matches;
+///
+/// let pool = [5, 2, 5, 9, 5, 2, 1].map(D10::from);
+/// let matches: Matches<u8> = pool.iter().map(|d| d.value()).collect();
+///
+/// assert_eq!(matches.len(), 2);
+/// assert_eq!(matches.widest().map(|m| (m.width(), *m.face())), Some((3, 5)));
+/// assert_eq!(matches.waste(), &[9, 1]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matches<T> {
+    matches: Vec<Match<T>>,
+    waste: Vec<T>,
+}
+
+impl<T> Matches<T>
+where
+    T: Ord,
+{
+    /// Groups the given faces into matches.
+    pub fn new<I>(faces: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut counts = BTreeMap::new();
+        for face in faces {
+            *counts.entry(face).or_insert(0) += 1;
+        }
+        let mut matches = Vec::new();
+        let mut waste = Vec::new();
+        for (face, width) in counts.into_iter().rev() {
+            if width > 1 {
+                matches.push(Match { face, width });
+            } else {
+                waste.push(face);
+            }
+        }
+        matches.sort_by_key(|m| Reverse(m.width));
+        Self { matches, waste }
+    }
+
+    /// Returns every match, ordered from widest to narrowest and then highest to lowest face.
+    pub fn matches(&self) -> &[Match<T>] {
+        &self.matches
+    }
+
+    /// Returns every face that was not part of a match, from highest to lowest.
+    pub fn waste(&self) -> &[T] {
+        &self.waste
+    }
+
+    /// Returns the widest match, preferring the highest face if tied.
+    pub fn widest(&self) -> Option<&Match<T>> {
+        self.matches.first()
+    }
+
+    /// Returns the match with the highest face, preferring the widest match if tied.
+    pub fn highest(&self) -> Option<&Match<T>> {
+        self.matches
+            .iter()
+            .rev()
+            .max_by(|a, b| a.face.cmp(&b.face).then(a.width.cmp(&b.width)))
+    }
+
+    /// Returns how many matches were found.
+    pub fn len(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// Returns whether no matches were found.
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+}
+
+impl<T> FromIterator<T> for Matches<T>
+where
+    T: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_empty() {
+        let matches = Matches::<u8>::new([]);
+
+        assert!(matches.is_empty());
+        assert_eq!(matches.len(), 0);
+        assert_eq!(matches.widest(), None);
+        assert_eq!(matches.highest(), None);
+        assert!(matches.waste().is_empty());
+    }
+
+    #[test]
+    fn matches_no_matches() {
+        let matches = Matches::new([1, 4, 6]);
+
+        assert!(matches.is_empty());
+        assert_eq!(matches.waste(), &[6, 4, 1]);
+    }
+
+    #[test]
+    fn matches_pairs_and_triples() {
+        let matches = Matches::new([2, 6, 2, 3, 6, 6, 3, 1]);
+
+        assert_eq!(
+            matches.matches(),
+            &[
+                Match { face: 6, width: 3 },
+                Match { face: 3, width: 2 },
+                Match { face: 2, width: 2 },
+            ]
+        );
+        assert_eq!(matches.waste(), &[1]);
+    }
+
+    #[test]
+    fn matches_widest_and_highest() {
+        let matches = Matches::new([2, 2, 2, 2, 9, 9, 5, 5]);
+
+        let widest = matches.widest().unwrap();
+        assert_eq!((widest.width(), *widest.face()), (4, 2));
+
+        let highest = matches.highest().unwrap();
+        assert_eq!((highest.width(), *highest.face()), (2, 9));
+    }
+
+    #[test]
+    fn matches_from_iter() {
+        let matches: Matches<char> = "abcab".chars().collect();
+
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches.waste(), &['c']);
+    }
+}
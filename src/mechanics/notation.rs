@@ -0,0 +1,666 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::{Breakdown, BreakdownDie};
+use crate::stats::{Distribution, Expression};
+use crate::traits::SideSelector;
+use crate::Error;
+
+/// Which dice of a [`Notation`] count towards the total.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Keep {
+    /// Every die is kept.
+    #[default]
+    All,
+
+    /// Only the highest dice are kept, i.e. `4d6kh3`.
+    Highest(usize),
+
+    /// Only the lowest dice are kept, i.e. `2d20kl1`.
+    Lowest(usize),
+}
+
+/// A pool of dice with the same number of sides, and a flat modifier, i.e. `4d6kh3+2`.
+///
+/// Notation can be parsed at compile time (see [`Notation::parse`] and [`crate::roll!`]), or from
+/// a string, i.e. a configuration file or chat command, with [`str::parse`]. The supported syntax
+/// is `[count]d<sides>[(kh|kl|dh|dl)<n>][(+|-)<modifier>...]`, where `d%` is `d100`, dropping dice
+/// is written as keeping the rest, and whitespace is ignored.
+///
+/// Parsed notation is often untrusted (i.e. a chat command), so at most [`Notation::MAX_COUNT`]
+/// dice with at most [`Notation::MAX_SIDES`] sides can be parsed.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::mechanics::{Keep, Notation};
+///
+/// let notation: Notation = "4d6dl1 + 2".parse().unwrap();
+///
+/// assert_eq!(notation.count(), 4);
+/// assert_eq!(notation.sides(), 6);
+/// assert_eq!(notation.keep(), Keep::Highest(3));
+/// assert_eq!(notation.modifier(), 2);
+/// assert_eq!(notation.to_string(), "4d6kh3+2");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "NotationRepr")
+)]
+pub struct Notation {
+    count: usize,
+    sides: usize,
+    keep: Keep,
+    modifier: i32,
+}
+
+impl Notation {
+    /// The most dice that [`Notation::parse`] accepts, i.e. `1000d6`.
+    pub const MAX_COUNT: usize = 1_000;
+
+    /// The most sides that [`Notation::parse`] accepts, i.e. `1d10000`.
+    pub const MAX_SIDES: usize = 10_000;
+
+    /// Creates notation for `count` dice with the given sides, keeping every die.
+    ///
+    /// # Panics
+    ///
+    /// If the die has no sides.
+    pub const fn new(count: usize, sides: usize) -> Self {
+        assert!(sides > 0);
+        Self {
+            count,
+            sides,
+            keep: Keep::All,
+            modifier: 0,
+        }
+    }
+
+    /// Returns a copy of the notation, keeping only the given dice.
+    ///
+    /// Keeping at least as many dice as there are is the same as [`Keep::All`].
+    #[must_use]
+    pub const fn with_keep(mut self, keep: Keep) -> Self {
+        self.keep = match keep {
+            Keep::Highest(n) | Keep::Lowest(n) if n >= self.count => Keep::All,
+            keep => keep,
+        };
+        self
+    }
+
+    /// Returns a copy of the notation, with the given flat modifier.
+    #[must_use]
+    pub const fn with_modifier(mut self, modifier: i32) -> Self {
+        self.modifier = modifier;
+        self
+    }
+
+    /// Parses notation, i.e. `4d6kh3+2`, which is usable in const contexts.
+    ///
+    /// # Errors
+    ///
+    /// If the notation is invalid, or has more than [`Notation::MAX_COUNT`] dice or more than
+    /// [`Notation::MAX_SIDES`] sides, returns [`Error::InvalidNotation`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::mechanics::Notation;
+    /// use tomb::Error;
+    ///
+    /// const FIREBALL: Notation = match Notation::parse("8d6") {
+    ///     Ok(notation) => notation,
+    ///     Err(_) => panic!(),
+    /// };
+    /// assert_eq!(FIREBALL, Notation::new(8, 6));
+    ///
+    /// assert_eq!(
+    ///     Notation::parse("2d"),
+    ///     Err(Error::InvalidNotation { position: 2 })
+    /// );
+    /// ```
+    pub const fn parse(notation: &str) -> Result<Self, Error> {
+        let bytes = notation.as_bytes();
+        let mut i = skip_whitespace(bytes, 0);
+
+        let count = match number(bytes, i) {
+            Some((count, _)) if count > Self::MAX_COUNT => {
+                return Err(Error::InvalidNotation { position: i });
+            }
+            Some((count, next)) => {
+                i = skip_whitespace(bytes, next);
+                count
+            }
+            None => 1,
+        };
+
+        if i >= bytes.len() || !bytes[i].eq_ignore_ascii_case(&b'd') {
+            return Err(Error::InvalidNotation { position: i });
+        }
+        i = skip_whitespace(bytes, i + 1);
+
+        let sides = if i < bytes.len() && bytes[i] == b'%' {
+            i += 1;
+            100
+        } else {
+            match number(bytes, i) {
+                Some((sides, next)) if sides > 0 && sides <= Self::MAX_SIDES => {
+                    i = next;
+                    sides
+                }
+                _ => return Err(Error::InvalidNotation { position: i }),
+            }
+        };
+        i = skip_whitespace(bytes, i);
+
+        let mut keep = Keep::All;
+        if i + 1 < bytes.len() {
+            let (kind, which) = (
+                bytes[i].to_ascii_lowercase(),
+                bytes[i + 1].to_ascii_lowercase(),
+            );
+            if (kind == b'k' || kind == b'd') && (which == b'h' || which == b'l') {
+                let Some((n, next)) = number(bytes, skip_whitespace(bytes, i + 2)) else {
+                    return Err(Error::InvalidNotation {
+                        position: skip_whitespace(bytes, i + 2),
+                    });
+                };
+                let rest = count.saturating_sub(n);
+                keep = match (kind, which) {
+                    (b'k', b'h') => Keep::Highest(n),
+                    (b'k', _) => Keep::Lowest(n),
+                    (_, b'h') => Keep::Lowest(rest),
+                    _ => Keep::Highest(rest),
+                };
+                i = skip_whitespace(bytes, next);
+            }
+        }
+
+        let mut modifier: i32 = 0;
+        while i < bytes.len() {
+            let negative = match bytes[i] {
+                b'+' => false,
+                b'-' => true,
+                _ => return Err(Error::InvalidNotation { position: i }),
+            };
+            i = skip_whitespace(bytes, i + 1);
+            let Some((n, next)) = number(bytes, i) else {
+                return Err(Error::InvalidNotation { position: i });
+            };
+            let total = if n > i32::MAX as usize {
+                None
+            } else if negative {
+                modifier.checked_sub(n as i32)
+            } else {
+                modifier.checked_add(n as i32)
+            };
+            modifier = match total {
+                Some(total) => total,
+                None => return Err(Error::InvalidNotation { position: i }),
+            };
+            i = skip_whitespace(bytes, next);
+        }
+
+        Ok(Self::new(count, sides)
+            .with_keep(keep)
+            .with_modifier(modifier))
+    }
+
+    /// Returns how many dice are rolled.
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns how many sides each die has.
+    pub const fn sides(&self) -> usize {
+        self.sides
+    }
+
+    /// Returns which dice are kept.
+    pub const fn keep(&self) -> Keep {
+        self.keep
+    }
+
+    /// Returns the flat modifier added to the total.
+    pub const fn modifier(&self) -> i32 {
+        self.modifier
+    }
+
+    /// Returns the dice of the notation as an expression, without the modifier.
+    pub const fn expression(&self) -> Expression {
+        match self.keep {
+            Keep::All => Expression::dice(self.count, self.sides),
+            Keep::Highest(n) => Expression::keep_highest(self.count, self.sides, n),
+            Keep::Lowest(n) => Expression::keep_lowest(self.count, self.sides, n),
+        }
+    }
+
+    /// Computes the exact distribution of the total.
+    pub fn distribution(&self) -> Distribution {
+        self.expression().distribution() + self.modifier as i64
+    }
+
+    /// Rolls every die, selecting each face with the given selector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastrand::Rng;
+    /// use tomb::items::RngRoller;
+    /// use tomb::mechanics::Notation;
+    ///
+    /// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+    /// let notation: Notation = "4d6kh3+2".parse().unwrap();
+    /// let roll = notation.roll(&roller);
+    ///
+    /// assert_eq!(roll.rolls(), &[3, 4, 2, 5]);
+    /// assert_eq!(roll.total(), 14);
+    /// ```
+    pub fn roll<S>(&self, selector: &S) -> NotationRoll
+    where
+        S: SideSelector,
+    {
+        let rolls = (0..self.count)
+            .map(|_| selector.select(self.sides) + 1)
+            .collect();
+        NotationRoll::new(*self, rolls)
+    }
+}
+
+impl Display for Notation {
+    /// Formats the notation in its shortest form, i.e. `4d6kh3+2`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}d{}", self.count, self.sides)?;
+        match self.keep {
+            Keep::All => {}
+            Keep::Highest(n) => write!(f, "kh{n}")?,
+            Keep::Lowest(n) => write!(f, "kl{n}")?,
+        }
+        match self.modifier {
+            0 => Ok(()),
+            m if m > 0 => write!(f, "+{m}"),
+            m => write!(f, "{m}"),
+        }
+    }
+}
+
+impl FromStr for Notation {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// The serialized form of [`Notation`], which is validated when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct NotationRepr {
+    count: usize,
+    sides: usize,
+    keep: Keep,
+    modifier: i32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<NotationRepr> for Notation {
+    type Error = Error;
+
+    /// Creates notation from the serialized fields, failing if there are more than
+    /// [`Notation::MAX_COUNT`] dice, or the dice have no sides or more than [`Notation::MAX_SIDES`]
+    /// sides, like [`Notation::parse`].
+    fn try_from(repr: NotationRepr) -> Result<Self, Self::Error> {
+        if repr.count > Self::MAX_COUNT {
+            return Err(Error::OutOfRange {
+                value: repr.count,
+                min: 0,
+                max: Self::MAX_COUNT,
+            });
+        }
+        if repr.sides == 0 || repr.sides > Self::MAX_SIDES {
+            return Err(Error::OutOfRange {
+                value: repr.sides,
+                min: 1,
+                max: Self::MAX_SIDES,
+            });
+        }
+        Ok(Self::new(repr.count, repr.sides)
+            .with_keep(repr.keep)
+            .with_modifier(repr.modifier))
+    }
+}
+
+/// The result of rolling [`Notation`], with the face of every die, in the order rolled.
+///
+/// When deserialized, which dice are kept is recomputed from the notation and the faces.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "NotationRollRepr")
+)]
+pub struct NotationRoll {
+    notation: Notation,
+    rolls: Vec<usize>,
+    kept: Vec<bool>,
+}
+
+impl NotationRoll {
+    /// Creates a result from the given faces.
+    ///
+    /// # Panics
+    ///
+    /// If the number of faces does not match the notation, or a face is out of range.
+    pub fn new(notation: Notation, rolls: Vec<usize>) -> Self {
+        assert_eq!(rolls.len(), notation.count);
+        assert!(rolls.iter().all(|&r| (1..=notation.sides).contains(&r)));
+
+        // Ties are broken by keeping the earliest dice.
+        let mut order: Vec<usize> = (0..rolls.len()).collect();
+        let keep = match notation.keep {
+            Keep::All => rolls.len(),
+            Keep::Highest(n) => {
+                order.sort_by_key(|&i| std::cmp::Reverse(rolls[i]));
+                n
+            }
+            Keep::Lowest(n) => {
+                order.sort_by_key(|&i| rolls[i]);
+                n
+            }
+        };
+        let mut kept = vec![false; rolls.len()];
+        for &i in order.iter().take(keep) {
+            kept[i] = true;
+        }
+        Self {
+            notation,
+            rolls,
+            kept,
+        }
+    }
+
+    /// Returns the notation that was rolled.
+    pub const fn notation(&self) -> &Notation {
+        &self.notation
+    }
+
+    /// Returns the face of every die, in the order rolled.
+    pub fn rolls(&self) -> &[usize] {
+        &self.rolls
+    }
+
+    /// Returns whether the die at the given index counts towards the total.
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn is_kept(&self, index: usize) -> bool {
+        self.kept[index]
+    }
+
+    /// Returns the faces of the dice that count towards the total, in the order rolled.
+    pub fn kept(&self) -> impl Iterator<Item = usize> + '_ {
+        self.rolls
+            .iter()
+            .zip(&self.kept)
+            .filter(|(_, &kept)| kept)
+            .map(|(&roll, _)| roll)
+    }
+
+    /// Returns the sum of the kept dice plus the modifier.
+    pub fn total(&self) -> i64 {
+        self.kept().sum::<usize>() as i64 + self.notation.modifier as i64
+    }
+
+    /// Explains how the total was reached, i.e. `4d6kh3 → [2, 6, (1), 4] = 12`.
+    pub fn explain(&self) -> Breakdown {
+        let dice = self
+            .rolls
+            .iter()
+            .zip(&self.kept)
+            .map(|(&face, &kept)| BreakdownDie::new(face, !kept))
+            .collect();
+        Breakdown::new(
+            self.notation.to_string(),
+            dice,
+            self.notation.modifier as i64,
+        )
+    }
+}
+
+/// The serialized form of a [`NotationRoll`], which is validated when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct NotationRollRepr {
+    notation: Notation,
+    rolls: Vec<usize>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<NotationRollRepr> for NotationRoll {
+    type Error = Error;
+
+    /// Creates a result from the serialized notation and faces, failing if the number of faces
+    /// does not match the notation, or a face is out of range.
+    fn try_from(repr: NotationRollRepr) -> Result<Self, Self::Error> {
+        let (count, sides) = (repr.notation.count, repr.notation.sides);
+        if repr.rolls.len() != count {
+            return Err(Error::OutOfRange {
+                value: repr.rolls.len(),
+                min: count,
+                max: count,
+            });
+        }
+        if let Some(&face) = repr.rolls.iter().find(|&&r| !(1..=sides).contains(&r)) {
+            return Err(Error::OutOfRange {
+                value: face,
+                min: 1,
+                max: sides,
+            });
+        }
+        Ok(Self::new(repr.notation, repr.rolls))
+    }
+}
+
+/// Returns the index of the first non-whitespace byte at or after `i`.
+const fn skip_whitespace(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+/// Parses the decimal number starting at `i`, returning it and the index after the last digit.
+///
+/// Returns `None` if there are no digits, or the number overflows.
+const fn number(bytes: &[u8], mut i: usize) -> Option<(usize, usize)> {
+    let start = i;
+    let mut value: usize = 0;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((bytes[i] - b'0') as usize) {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+        i += 1;
+    }
+    if i == start {
+        None
+    } else {
+        Some((value, i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Selects each position in order, starting from the lowest.
+    struct Sequence(std::cell::Cell<usize>, Vec<usize>);
+
+    impl SideSelector for Sequence {
+        fn select(&self, _sides: usize) -> usize {
+            let next = self.1[self.0.get()];
+            self.0.set(self.0.get() + 1);
+            next
+        }
+    }
+
+    #[test]
+    fn notation_parse() {
+        assert_eq!(Notation::parse("d20"), Ok(Notation::new(1, 20)));
+        assert_eq!(Notation::parse(" 3D6 "), Ok(Notation::new(3, 6)));
+        assert_eq!(Notation::parse("2d%"), Ok(Notation::new(2, 100)));
+        assert_eq!(
+            Notation::parse("1d20 + 5 - 2"),
+            Ok(Notation::new(1, 20).with_modifier(3))
+        );
+        assert_eq!(
+            Notation::parse("2d20kl1-1"),
+            Ok(Notation::new(2, 20)
+                .with_keep(Keep::Lowest(1))
+                .with_modifier(-1))
+        );
+        assert_eq!(
+            Notation::parse("4d6dh1"),
+            Ok(Notation::new(4, 6).with_keep(Keep::Lowest(3)))
+        );
+        assert_eq!(Notation::parse("3d6kh5"), Ok(Notation::new(3, 6)));
+    }
+
+    #[test]
+    fn notation_parse_invalid() {
+        for (notation, position) in [
+            ("", 0),
+            ("20", 2),
+            ("d0", 1),
+            ("2d6x", 3),
+            ("2d6kh", 5),
+            ("2d6+", 4),
+            ("1d6+9999999999", 4),
+            ("99999999999999999999999d6", 0),
+            ("1001d6", 0),
+            ("1d10001", 2),
+            ("  1000000000d1000000000", 2),
+        ] {
+            assert_eq!(
+                Notation::parse(notation),
+                Err(Error::InvalidNotation { position }),
+                "{notation}"
+            );
+        }
+    }
+
+    #[test]
+    fn notation_parse_limits() {
+        let largest = format!("{}d{}", Notation::MAX_COUNT, Notation::MAX_SIDES);
+
+        assert_eq!(
+            Notation::parse(&largest),
+            Ok(Notation::new(Notation::MAX_COUNT, Notation::MAX_SIDES))
+        );
+    }
+
+    #[test]
+    fn notation_display_round_trips() {
+        for notation in ["1d20", "4d6kh3+2", "2d20kl1-1", "10d10"] {
+            assert_eq!(notation.parse::<Notation>().unwrap().to_string(), notation);
+        }
+    }
+
+    #[test]
+    fn notation_distribution() {
+        let notation = Notation::new(2, 6).with_modifier(1);
+
+        assert_eq!(notation.distribution().min(), 3);
+        assert_eq!(notation.distribution().mean(), 8.0);
+    }
+
+    #[test]
+    fn notation_roll_keeps_earliest_ties() {
+        let notation = Notation::new(4, 6).with_keep(Keep::Highest(2));
+        let roll = notation.roll(&Sequence(Default::default(), vec![5, 2, 5, 5]));
+
+        assert_eq!(roll.rolls(), &[6, 3, 6, 6]);
+        assert_eq!(roll.kept().collect::<Vec<_>>(), vec![6, 6]);
+        assert!(roll.is_kept(0) && !roll.is_kept(1) && roll.is_kept(2) && !roll.is_kept(3));
+        assert_eq!(roll.total(), 12);
+        assert_eq!(roll.explain().to_string(), "4d6kh2 → [6, (3), 6, (6)] = 12");
+    }
+
+    #[test]
+    fn notation_roll_keep_lowest() {
+        let notation = Notation::new(2, 20)
+            .with_keep(Keep::Lowest(1))
+            .with_modifier(-3);
+        let roll = NotationRoll::new(notation, vec![17, 4]);
+
+        assert_eq!(roll.total(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn notation_roll_out_of_range() {
+        NotationRoll::new(Notation::new(1, 6), vec![7]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn notation_serde() {
+        let notation: Notation = "4d6kh3+2".parse().unwrap();
+        let json = serde_json::to_string(&notation).unwrap();
+
+        assert_eq!(serde_json::from_str::<Notation>(&json).unwrap(), notation);
+        assert_eq!(
+            serde_json::from_str::<Notation>(
+                r#"{"count":2,"sides":20,"keep":{"Highest":5},"modifier":0}"#
+            )
+            .unwrap(),
+            Notation::new(2, 20)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn notation_serde_invalid() {
+        for json in [
+            r#"{"count":1,"sides":0,"keep":"All","modifier":0}"#,
+            r#"{"count":1,"sides":10001,"keep":"All","modifier":0}"#,
+            r#"{"count":1001,"sides":6,"keep":"All","modifier":0}"#,
+        ] {
+            assert!(serde_json::from_str::<Notation>(json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn notation_roll_serde() {
+        let roll = NotationRoll::new("4d6kh2".parse().unwrap(), vec![6, 1, 6, 3]);
+        let json = serde_json::to_string(&roll).unwrap();
+        assert_eq!(serde_json::from_str::<NotationRoll>(&json).unwrap(), roll);
+
+        let notation = r#"{"count":2,"sides":6,"keep":{"Highest":1},"modifier":0}"#;
+        let forged = format!(r#"{{"notation":{notation},"rolls":[2,5],"kept":[true,true]}}"#);
+        let roll = serde_json::from_str::<NotationRoll>(&forged).unwrap();
+        assert_eq!(roll.kept().collect::<Vec<_>>(), vec![5]);
+        assert_eq!(roll.total(), 5);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn notation_roll_serde_invalid() {
+        let notation = r#"{"count":1,"sides":6,"keep":"All","modifier":0}"#;
+        for rolls in ["[9]", "[0]", "[]", "[1,2]"] {
+            let json = format!(r#"{{"notation":{notation},"rolls":{rolls},"kept":[]}}"#);
+            assert!(
+                serde_json::from_str::<NotationRoll>(&json).is_err(),
+                "{json}"
+            );
+        }
+    }
+}
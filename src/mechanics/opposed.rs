@@ -0,0 +1,247 @@
+use std::cmp::Ordering;
+
+use super::Check;
+use crate::traits::{Critical, Positional, Roll, Rotate};
+
+/// Which side of an [`Opposed`] roll wins when both totals are equal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Equal totals are reported as a [`Winner::Tie`].
+    Tie,
+
+    /// Equal totals are won by the attacker.
+    Attacker,
+
+    /// Equal totals are won by the defender, i.e. the status quo prevails.
+    Defender,
+
+    /// Equal totals are won by the side with the higher modifier, otherwise a [`Winner::Tie`].
+    Modifier,
+
+    /// Both sides roll again, up to the given number of times, otherwise a [`Winner::Tie`].
+    Reroll(usize),
+}
+
+/// The side that won an [`Opposed`] roll.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Winner {
+    /// The attacker (or initiator) won.
+    Attacker,
+
+    /// The defender (or responder) won.
+    Defender,
+
+    /// Neither side won.
+    Tie,
+}
+
+/// The result of resolving an [`Opposed`] roll.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Contest {
+    attacker: i32,
+    defender: i32,
+    winner: Winner,
+}
+
+impl Contest {
+    /// Returns the total of the attacker.
+    pub const fn attacker(&self) -> i32 {
+        self.attacker
+    }
+
+    /// Returns the total of the defender.
+    pub const fn defender(&self) -> i32 {
+        self.defender
+    }
+
+    /// Returns which side won.
+    pub const fn winner(&self) -> Winner {
+        self.winner
+    }
+
+    /// Returns the absolute difference between both totals.
+    pub const fn margin(&self) -> u32 {
+        self.attacker.abs_diff(self.defender)
+    }
+}
+
+/// Resolves contested checks, such as skill contests or initiative ties, between two sides.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{D20, NopRoller};
+/// use tomb::mechanics::{Check, Opposed, TieBreak, Winner};
+///
+/// let attacker = Check::new(D20::from(10), 3);
+/// let defender = Check::new(D20::from(12), 1);
+///
+/// let contest = Opposed::new(TieBreak::Defender).roll(&NopRoller, &attacker, &defender);
+/// assert_eq!(contest.winner(), Winner::Defender);
+/// assert_eq!(contest.margin(), 0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Opposed {
+    ties: TieBreak,
+}
+
+impl Opposed {
+    /// Creates a new resolver that uses the given tie-breaking rule.
+    pub const fn new(ties: TieBreak) -> Self {
+        Self { ties }
+    }
+
+    /// Returns the tie-breaking rule.
+    pub const fn ties(&self) -> TieBreak {
+        self.ties
+    }
+
+    /// Rolls both checks using the provided roller, and returns the resolved contest.
+    pub fn roll<R, A, D>(&self, roller: &R, attacker: &Check<A>, defender: &Check<D>) -> Contest
+    where
+        R: Roll,
+        A: Critical + Positional + Rotate,
+        D: Critical + Positional + Rotate,
+    {
+        let mut rerolls = match self.ties {
+            TieBreak::Reroll(limit) => limit,
+            _ => 0,
+        };
+        loop {
+            let a = attacker.roll(roller);
+            let d = defender.roll(roller);
+            if rerolls == 0 || a.total() != d.total() {
+                return self.resolve(&a, &d);
+            }
+            rerolls -= 1;
+        }
+    }
+
+    /// Resolves two already rolled checks into a contest without rolling.
+    pub fn resolve<A, D>(&self, attacker: &Check<A>, defender: &Check<D>) -> Contest
+    where
+        A: Critical + Rotate,
+        D: Critical + Rotate,
+    {
+        let (a, d) = (attacker.total(), defender.total());
+        let winner = match a.cmp(&d) {
+            Ordering::Greater => Winner::Attacker,
+            Ordering::Less => Winner::Defender,
+            Ordering::Equal => match self.ties {
+                TieBreak::Attacker => Winner::Attacker,
+                TieBreak::Defender => Winner::Defender,
+                TieBreak::Modifier => match attacker.modifier().cmp(&defender.modifier()) {
+                    Ordering::Greater => Winner::Attacker,
+                    Ordering::Less => Winner::Defender,
+                    Ordering::Equal => Winner::Tie,
+                },
+                TieBreak::Tie | TieBreak::Reroll(_) => Winner::Tie,
+            },
+        };
+        Contest {
+            attacker: a,
+            defender: d,
+            winner,
+        }
+    }
+}
+
+impl Default for Opposed {
+    fn default() -> Self {
+        Self::new(TieBreak::Tie)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+    use crate::items::{NopRoller, RngRoller, D20};
+
+    fn tied() -> (Check<D20>, Check<D20>) {
+        (Check::new(D20::from(10), 2), Check::new(D20::from(8), 4))
+    }
+
+    #[test]
+    fn opposed_default() {
+        let opposed: Opposed = Default::default();
+
+        assert_eq!(opposed.ties(), TieBreak::Tie);
+    }
+
+    #[test]
+    fn opposed_attacker_wins() {
+        let contest = Opposed::default()
+            .resolve(&Check::new(D20::from(15), 0), &Check::new(D20::from(10), 0));
+
+        assert_eq!(contest.winner(), Winner::Attacker);
+        assert_eq!(contest.attacker(), 15);
+        assert_eq!(contest.defender(), 10);
+        assert_eq!(contest.margin(), 5);
+    }
+
+    #[test]
+    fn opposed_defender_wins() {
+        let contest =
+            Opposed::default().resolve(&Check::new(D20::from(5), 0), &Check::new(D20::from(10), 2));
+
+        assert_eq!(contest.winner(), Winner::Defender);
+        assert_eq!(contest.margin(), 7);
+    }
+
+    #[test]
+    fn opposed_tie() {
+        let (a, d) = tied();
+        let contest = Opposed::new(TieBreak::Tie).resolve(&a, &d);
+
+        assert_eq!(contest.winner(), Winner::Tie);
+        assert_eq!(contest.margin(), 0);
+    }
+
+    #[test]
+    fn opposed_tie_attacker() {
+        let (a, d) = tied();
+        let contest = Opposed::new(TieBreak::Attacker).resolve(&a, &d);
+
+        assert_eq!(contest.winner(), Winner::Attacker);
+    }
+
+    #[test]
+    fn opposed_tie_defender() {
+        let (a, d) = tied();
+        let contest = Opposed::new(TieBreak::Defender).resolve(&a, &d);
+
+        assert_eq!(contest.winner(), Winner::Defender);
+    }
+
+    #[test]
+    fn opposed_tie_modifier() {
+        let (a, d) = tied();
+        let contest = Opposed::new(TieBreak::Modifier).resolve(&a, &d);
+        assert_eq!(contest.winner(), Winner::Defender);
+
+        let contest = Opposed::new(TieBreak::Modifier).resolve(&d, &a);
+        assert_eq!(contest.winner(), Winner::Attacker);
+
+        let contest = Opposed::new(TieBreak::Modifier).resolve(&a, &a);
+        assert_eq!(contest.winner(), Winner::Tie);
+    }
+
+    #[test]
+    fn opposed_reroll_exhausted() {
+        let (a, d) = tied();
+        let contest = Opposed::new(TieBreak::Reroll(3)).roll(&NopRoller, &a, &d);
+
+        assert_eq!(contest.winner(), Winner::Tie);
+    }
+
+    #[test]
+    fn opposed_reroll_resolves() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let (a, d) = tied();
+        let contest = Opposed::new(TieBreak::Reroll(100)).roll(&roller, &a, &d);
+
+        assert_ne!(contest.winner(), Winner::Tie);
+    }
+}
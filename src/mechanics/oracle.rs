@@ -0,0 +1,223 @@
+use crate::items::D100;
+use crate::traits::RollMut;
+
+/// How likely the answer to a question asked of an [`Oracle`] is to be _yes_.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Likelihood {
+    /// A `1%` chance of _yes_.
+    Impossible,
+
+    /// A `10%` chance of _yes_.
+    SmallChance,
+
+    /// A `25%` chance of _yes_.
+    Unlikely,
+
+    /// A `50%` chance of _yes_, the default likelihood.
+    #[default]
+    FiftyFifty,
+
+    /// A `75%` chance of _yes_.
+    Likely,
+
+    /// A `90%` chance of _yes_.
+    AlmostCertain,
+
+    /// A custom chance, between `0..=100`, of _yes_.
+    Chance(u8),
+}
+
+impl Likelihood {
+    /// Returns the percent chance, between `0..=100`, of _yes_.
+    pub const fn chance(&self) -> u8 {
+        match self {
+            Self::Impossible => 1,
+            Self::SmallChance => 10,
+            Self::Unlikely => 25,
+            Self::FiftyFifty => 50,
+            Self::Likely => 75,
+            Self::AlmostCertain => 90,
+            Self::Chance(chance) => {
+                if *chance > 100 {
+                    100
+                } else {
+                    *chance
+                }
+            }
+        }
+    }
+}
+
+/// An answer to a yes/no question, including whether it was qualified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// An exceptional _yes_, with an additional benefit.
+    YesAnd,
+
+    /// A _yes_.
+    Yes,
+
+    /// A _yes_, but with a complication.
+    YesBut,
+
+    /// A _no_, but with a consolation.
+    NoBut,
+
+    /// A _no_.
+    No,
+
+    /// An exceptional _no_, with an additional setback.
+    NoAnd,
+}
+
+impl Answer {
+    /// Returns whether the answer was _yes_, regardless of the qualifier.
+    pub const fn is_yes(&self) -> bool {
+        matches!(self, Self::YesAnd | Self::Yes | Self::YesBut)
+    }
+}
+
+/// Answers yes/no questions by rolling a `d100` against a [`Likelihood`].
+///
+/// A roll at or under the chance is a _yes_, and otherwise a _no_. The most extreme fifth of each
+/// side (i.e. the lowest and highest rolls) is an _and_, and the fifth of each side closest to the
+/// chance is a _but_.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::D100;
+/// use tomb::mechanics::{Answer, Likelihood, Oracle};
+///
+/// let oracle = Oracle::new();
+///
+/// assert_eq!(oracle.resolve(&D100::from(5), Likelihood::FiftyFifty), Answer::YesAnd);
+/// assert_eq!(oracle.resolve(&D100::from(30), Likelihood::FiftyFifty), Answer::Yes);
+/// assert_eq!(oracle.resolve(&D100::from(55), Likelihood::FiftyFifty), Answer::NoBut);
+/// assert_eq!(oracle.resolve(&D100::from(55), Likelihood::Likely), Answer::Yes);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Oracle;
+
+impl Oracle {
+    /// Creates a new oracle.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Rolls a `d100` using the provided roller, and answers with the given likelihood.
+    pub fn ask<R>(&self, roller: &R, likelihood: Likelihood) -> Answer
+    where
+        R: RollMut,
+    {
+        let mut die = D100::new();
+        roller.roll_mut(&mut die);
+        self.resolve(&die, likelihood)
+    }
+
+    /// Answers with the given likelihood given an already rolled `d100`.
+    pub fn resolve(&self, die: &D100, likelihood: Likelihood) -> Answer {
+        let roll = die.value();
+        let chance = likelihood.chance();
+        let yes = chance / 5;
+        let no = (100 - chance) / 5;
+        if roll <= chance {
+            if roll <= yes {
+                Answer::YesAnd
+            } else if roll > chance - yes {
+                Answer::YesBut
+            } else {
+                Answer::Yes
+            }
+        } else if roll > 100 - no {
+            Answer::NoAnd
+        } else if roll <= chance + no {
+            Answer::NoBut
+        } else {
+            Answer::No
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::NopRoller;
+
+    fn answers(likelihood: Likelihood) -> Vec<Answer> {
+        (1..=100)
+            .map(|roll| Oracle::new().resolve(&D100::from(roll), likelihood))
+            .collect()
+    }
+
+    fn count(answers: &[Answer], answer: Answer) -> usize {
+        answers.iter().filter(|&&a| a == answer).count()
+    }
+
+    #[test]
+    fn likelihood_chances() {
+        assert_eq!(Likelihood::default(), Likelihood::FiftyFifty);
+        assert_eq!(Likelihood::Impossible.chance(), 1);
+        assert_eq!(Likelihood::SmallChance.chance(), 10);
+        assert_eq!(Likelihood::Unlikely.chance(), 25);
+        assert_eq!(Likelihood::FiftyFifty.chance(), 50);
+        assert_eq!(Likelihood::Likely.chance(), 75);
+        assert_eq!(Likelihood::AlmostCertain.chance(), 90);
+        assert_eq!(Likelihood::Chance(33).chance(), 33);
+        assert_eq!(Likelihood::Chance(200).chance(), 100);
+    }
+
+    #[test]
+    fn answer_is_yes() {
+        assert!(Answer::YesAnd.is_yes());
+        assert!(Answer::Yes.is_yes());
+        assert!(Answer::YesBut.is_yes());
+        assert!(!Answer::NoBut.is_yes());
+        assert!(!Answer::No.is_yes());
+        assert!(!Answer::NoAnd.is_yes());
+    }
+
+    #[test]
+    fn oracle_fifty_fifty_bands() {
+        let answers = answers(Likelihood::FiftyFifty);
+
+        assert_eq!(count(&answers, Answer::YesAnd), 10);
+        assert_eq!(count(&answers, Answer::Yes), 30);
+        assert_eq!(count(&answers, Answer::YesBut), 10);
+        assert_eq!(count(&answers, Answer::NoBut), 10);
+        assert_eq!(count(&answers, Answer::No), 30);
+        assert_eq!(count(&answers, Answer::NoAnd), 10);
+    }
+
+    #[test]
+    fn oracle_likely_bands() {
+        let answers = answers(Likelihood::Likely);
+
+        assert_eq!(answers.iter().filter(|a| a.is_yes()).count(), 75);
+        assert_eq!(count(&answers, Answer::YesAnd), 15);
+        assert_eq!(count(&answers, Answer::NoAnd), 5);
+    }
+
+    #[test]
+    fn oracle_impossible_bands() {
+        let answers = answers(Likelihood::Impossible);
+
+        assert_eq!(answers[0], Answer::Yes);
+        assert_eq!(answers.iter().filter(|a| a.is_yes()).count(), 1);
+    }
+
+    #[test]
+    fn oracle_certain_bands() {
+        let answers = answers(Likelihood::Chance(100));
+
+        assert!(answers.iter().all(|a| a.is_yes()));
+        assert_eq!(answers[99], Answer::YesBut);
+    }
+
+    #[test]
+    fn oracle_ask() {
+        let answer = Oracle::new().ask(&NopRoller, Likelihood::Unlikely);
+
+        assert_eq!(answer, Answer::YesAnd);
+    }
+}
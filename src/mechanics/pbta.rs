@@ -0,0 +1,198 @@
+use crate::items::D6;
+use crate::traits::RollMut;
+
+/// The outcome band of a resolved move or action.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Outcome {
+    /// The action failed, often with a consequence.
+    Miss,
+
+    /// The action succeeded, but with a cost, complication, or reduced effect.
+    WeakHit,
+
+    /// The action succeeded fully.
+    StrongHit,
+}
+
+impl Outcome {
+    /// Returns the _Powered by the Apocalypse_ band of a total: `6-`, `7-9`, or `10+`.
+    pub const fn from_total(total: i32) -> Self {
+        if total >= 10 {
+            Self::StrongHit
+        } else if total >= 7 {
+            Self::WeakHit
+        } else {
+            Self::Miss
+        }
+    }
+
+    /// Returns whether the outcome was a hit of any kind.
+    pub const fn is_hit(&self) -> bool {
+        !matches!(self, Self::Miss)
+    }
+}
+
+/// A _Powered by the Apocalypse_ move, rolled as `2d6+stat`.
+///
+/// Any _forward_ bonus (or penalty), i.e. "take +1 forward", applies on top of the stat.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::RngRoller;
+/// use tomb::mechanics::{Move, Outcome};
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let roll = Move::new(1).with_forward(1).roll(&roller);
+///
+/// assert_eq!(roll.modifier(), 2);
+/// assert_eq!(roll.total(), roll.dice()[0].value() as i32 + roll.dice()[1].value() as i32 + 2);
+/// assert_eq!(roll.outcome(), Outcome::from_total(roll.total()));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Move {
+    stat: i32,
+    forward: i32,
+}
+
+impl Move {
+    /// Creates a new move that adds the given stat to the roll.
+    pub const fn new(stat: i32) -> Self {
+        Self { stat, forward: 0 }
+    }
+
+    /// Returns a move with the given forward bonus (or penalty) applied on top of the stat.
+    pub const fn with_forward(self, forward: i32) -> Self {
+        Self {
+            stat: self.stat,
+            forward,
+        }
+    }
+
+    /// Returns the stat added to the roll.
+    pub const fn stat(&self) -> i32 {
+        self.stat
+    }
+
+    /// Returns the forward bonus (or penalty) added to the roll.
+    pub const fn forward(&self) -> i32 {
+        self.forward
+    }
+
+    /// Rolls `2d6` using the provided roller and resolves the move.
+    pub fn roll<R>(&self, roller: &R) -> MoveRoll
+    where
+        R: RollMut,
+    {
+        let mut dice = [D6::new(), D6::new()];
+        for die in &mut dice {
+            roller.roll_mut(die);
+        }
+        self.resolve(dice)
+    }
+
+    /// Resolves the move given two already rolled dice.
+    pub fn resolve(&self, dice: [D6; 2]) -> MoveRoll {
+        MoveRoll {
+            dice,
+            modifier: self.stat + self.forward,
+        }
+    }
+}
+
+/// The result of rolling a [`Move`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveRoll {
+    dice: [D6; 2],
+    modifier: i32,
+}
+
+impl MoveRoll {
+    /// Returns the two rolled dice.
+    pub const fn dice(&self) -> &[D6; 2] {
+        &self.dice
+    }
+
+    /// Returns the combined stat and forward modifier.
+    pub const fn modifier(&self) -> i32 {
+        self.modifier
+    }
+
+    /// Returns the sum of both dice plus the modifier.
+    pub fn total(&self) -> i32 {
+        self.dice.iter().map(|d| d.value() as i32).sum::<i32>() + self.modifier
+    }
+
+    /// Returns the outcome band of the total.
+    pub fn outcome(&self) -> Outcome {
+        Outcome::from_total(self.total())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::NopRoller;
+
+    #[test]
+    fn outcome_bands() {
+        assert_eq!(Outcome::from_total(-1), Outcome::Miss);
+        assert_eq!(Outcome::from_total(6), Outcome::Miss);
+        assert_eq!(Outcome::from_total(7), Outcome::WeakHit);
+        assert_eq!(Outcome::from_total(9), Outcome::WeakHit);
+        assert_eq!(Outcome::from_total(10), Outcome::StrongHit);
+        assert_eq!(Outcome::from_total(14), Outcome::StrongHit);
+    }
+
+    #[test]
+    fn outcome_is_hit() {
+        assert!(!Outcome::Miss.is_hit());
+        assert!(Outcome::WeakHit.is_hit());
+        assert!(Outcome::StrongHit.is_hit());
+    }
+
+    #[test]
+    fn move_default() {
+        let m: Move = Default::default();
+
+        assert_eq!(m, Move::new(0));
+        assert_eq!(m.stat(), 0);
+        assert_eq!(m.forward(), 0);
+    }
+
+    #[test]
+    fn move_resolve_miss() {
+        let roll = Move::new(-1).resolve([D6::from(3), D6::from(4)]);
+
+        assert_eq!(roll.total(), 6);
+        assert_eq!(roll.outcome(), Outcome::Miss);
+    }
+
+    #[test]
+    fn move_resolve_weak_hit() {
+        let roll = Move::new(2).resolve([D6::from(3), D6::from(4)]);
+
+        assert_eq!(roll.total(), 9);
+        assert_eq!(roll.outcome(), Outcome::WeakHit);
+    }
+
+    #[test]
+    fn move_resolve_forward() {
+        let roll = Move::new(2)
+            .with_forward(1)
+            .resolve([D6::from(3), D6::from(4)]);
+
+        assert_eq!(roll.modifier(), 3);
+        assert_eq!(roll.total(), 10);
+        assert_eq!(roll.outcome(), Outcome::StrongHit);
+    }
+
+    #[test]
+    fn move_roll() {
+        let roll = Move::new(1).roll(&NopRoller);
+
+        assert_eq!(roll.dice(), &[D6::from(1), D6::from(1)]);
+        assert_eq!(roll.total(), 3);
+    }
+}
@@ -0,0 +1,315 @@
+use crate::items::D6;
+use crate::traits::RollMut;
+
+/// The shade of an ability in _Burning Wheel_, which determines what counts as a success.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Shade {
+    /// Successes on a `4` or higher, the default shade.
+    #[default]
+    Black,
+
+    /// Successes on a `3` or higher.
+    Grey,
+
+    /// Successes on a `2` or higher.
+    White,
+}
+
+impl Shade {
+    /// Returns the lowest face that counts as a success.
+    pub const fn target(&self) -> u8 {
+        match self {
+            Self::Black => 4,
+            Self::Grey => 3,
+            Self::White => 2,
+        }
+    }
+
+    /// Returns whether the given die counts as a success.
+    pub const fn is_success(&self, die: &D6) -> bool {
+        die.value() >= self.target()
+    }
+}
+
+/// A pool of `d6` rolled against a [`Shade`], as in _Burning Wheel_ and _Mouse Guard_.
+///
+/// An _open-ended_ test rerolls every `6`, adding each reroll to the pool; rerolls that are also a
+/// `6` are rerolled again. So that a roller that _always_ produces a `6` can't reroll forever, at
+/// most [`ShadeTest::max_rerolls`] rerolls are made, after which the roll is
+/// [capped](ShadeRoll::is_capped).
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::D6;
+/// use tomb::mechanics::{Shade, ShadeTest};
+///
+/// let pool = [1, 3, 4, 6].map(D6::from).to_vec();
+///
+/// assert_eq!(ShadeTest::new(4).resolve(pool.clone()).successes(), 2);
+/// assert_eq!(ShadeTest::new(4).with_shade(Shade::Grey).resolve(pool).successes(), 3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShadeTest {
+    exponent: usize,
+    shade: Shade,
+    open_ended: bool,
+    max_rerolls: usize,
+}
+
+impl ShadeTest {
+    /// The most open-ended rerolls made by default, far more than a fair roller would ever need.
+    pub const MAX_REROLLS: usize = 1_000;
+
+    /// Creates a new black shaded test that rolls the given number of dice.
+    pub const fn new(exponent: usize) -> Self {
+        Self {
+            exponent,
+            shade: Shade::Black,
+            open_ended: false,
+            max_rerolls: Self::MAX_REROLLS,
+        }
+    }
+
+    /// Returns a test that rolls against the given shade.
+    pub const fn with_shade(self, shade: Shade) -> Self {
+        Self {
+            exponent: self.exponent,
+            shade,
+            open_ended: self.open_ended,
+            max_rerolls: self.max_rerolls,
+        }
+    }
+
+    /// Returns a test that does (or does not) reroll every `6`.
+    pub const fn with_open_ended(self, open_ended: bool) -> Self {
+        Self {
+            exponent: self.exponent,
+            shade: self.shade,
+            open_ended,
+            max_rerolls: self.max_rerolls,
+        }
+    }
+
+    /// Returns a test that makes at most the given number of open-ended rerolls.
+    pub const fn with_max_rerolls(self, max_rerolls: usize) -> Self {
+        Self {
+            exponent: self.exponent,
+            shade: self.shade,
+            open_ended: self.open_ended,
+            max_rerolls,
+        }
+    }
+
+    /// Returns how many dice are rolled.
+    pub const fn exponent(&self) -> usize {
+        self.exponent
+    }
+
+    /// Returns the shade rolled against.
+    pub const fn shade(&self) -> Shade {
+        self.shade
+    }
+
+    /// Returns whether every `6` is rerolled.
+    pub const fn is_open_ended(&self) -> bool {
+        self.open_ended
+    }
+
+    /// Returns the most open-ended rerolls made, [`ShadeTest::MAX_REROLLS`] by default.
+    pub const fn max_rerolls(&self) -> usize {
+        self.max_rerolls
+    }
+
+    /// Rolls the pool using the provided roller, including any open-ended rerolls.
+    ///
+    /// If [`ShadeTest::max_rerolls`] rerolls are made and a `6` is still left to reroll, rerolling
+    /// stops and the roll is [capped](ShadeRoll::is_capped).
+    pub fn roll<R>(&self, roller: &R) -> ShadeRoll
+    where
+        R: RollMut,
+    {
+        let mut dice = vec![D6::new(); self.exponent];
+        for die in &mut dice {
+            roller.roll_mut(die);
+        }
+        let mut sixes = 0;
+        if self.open_ended {
+            sixes = dice.iter().filter(|d| d.value() == 6).count();
+            for _ in 0..self.max_rerolls {
+                if sixes == 0 {
+                    break;
+                }
+                let mut die = D6::new();
+                roller.roll_mut(&mut die);
+                if die.value() != 6 {
+                    sixes -= 1;
+                }
+                dice.push(die);
+            }
+        }
+        ShadeRoll {
+            capped: sixes > 0,
+            ..self.resolve(dice)
+        }
+    }
+
+    /// Resolves the test given already rolled dice, including any rerolls.
+    pub fn resolve(&self, dice: Vec<D6>) -> ShadeRoll {
+        ShadeRoll {
+            dice,
+            shade: self.shade,
+            capped: false,
+        }
+    }
+}
+
+/// The result of rolling a [`ShadeTest`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShadeRoll {
+    dice: Vec<D6>,
+    shade: Shade,
+    capped: bool,
+}
+
+impl ShadeRoll {
+    /// Returns every rolled die, including any open-ended rerolls.
+    pub fn dice(&self) -> &[D6] {
+        &self.dice
+    }
+
+    /// Returns the shade rolled against.
+    pub const fn shade(&self) -> Shade {
+        self.shade
+    }
+
+    /// Returns whether open-ended rerolls stopped at [`ShadeTest::max_rerolls`], with a `6` still
+    /// left to reroll.
+    pub const fn is_capped(&self) -> bool {
+        self.capped
+    }
+
+    /// Returns how many dice counted as a success.
+    pub fn successes(&self) -> usize {
+        self.dice
+            .iter()
+            .filter(|d| self.shade.is_success(d))
+            .count()
+    }
+
+    /// Returns whether the successes met or exceeded the given obstacle.
+    pub fn is_success(&self, obstacle: usize) -> bool {
+        self.successes() >= obstacle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::items::{NopRoller, SequenceRoller};
+    use crate::traits::{Positional, Roll, Rotate, RotateMut};
+
+    /// Rolls a sequence of fixed faces, for predictable open-ended rerolls.
+    struct FixedRoller(Cell<usize>, Vec<u8>);
+
+    impl RollMut for FixedRoller {
+        fn roll_mut<T>(&self, rotate: &mut T)
+        where
+            T: RotateMut + Positional,
+        {
+            let index = self.0.get();
+            self.0.set(index + 1);
+            rotate.set_position(self.1[index] as usize - 1);
+        }
+    }
+
+    impl Roll for FixedRoller {
+        fn roll<T>(&self, _rotate: &T) -> T
+        where
+            T: Rotate + Positional,
+        {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn shade_targets() {
+        assert_eq!(Shade::default(), Shade::Black);
+        assert_eq!(Shade::Black.target(), 4);
+        assert_eq!(Shade::Grey.target(), 3);
+        assert_eq!(Shade::White.target(), 2);
+    }
+
+    #[test]
+    fn shade_is_success() {
+        assert!(!Shade::Black.is_success(&D6::from(3)));
+        assert!(Shade::Grey.is_success(&D6::from(3)));
+        assert!(!Shade::White.is_success(&D6::from(1)));
+    }
+
+    #[test]
+    fn shade_test_options() {
+        let test = ShadeTest::new(3)
+            .with_shade(Shade::White)
+            .with_open_ended(true);
+
+        assert_eq!(test.exponent(), 3);
+        assert_eq!(test.shade(), Shade::White);
+        assert!(test.is_open_ended());
+        assert_eq!(test.max_rerolls(), ShadeTest::MAX_REROLLS);
+    }
+
+    #[test]
+    fn shade_test_obstacle() {
+        let roll = ShadeTest::new(3).resolve([4, 5, 2].map(D6::from).to_vec());
+
+        assert_eq!(roll.shade(), Shade::Black);
+        assert_eq!(roll.successes(), 2);
+        assert!(roll.is_success(2));
+        assert!(!roll.is_success(3));
+    }
+
+    #[test]
+    fn shade_test_roll() {
+        let roll = ShadeTest::new(4).roll(&NopRoller);
+
+        assert_eq!(roll.dice().len(), 4);
+        assert_eq!(roll.successes(), 0);
+    }
+
+    #[test]
+    fn shade_test_roll_open_ended() {
+        let roller = FixedRoller(Cell::new(0), vec![6, 2, 6, 6, 3, 1]);
+        let roll = ShadeTest::new(3).with_open_ended(true).roll(&roller);
+
+        assert_eq!(roll.dice(), &[6, 2, 6, 6, 3, 1].map(D6::from));
+        assert_eq!(roll.successes(), 3);
+        assert!(!roll.is_capped());
+    }
+
+    #[test]
+    fn shade_test_roll_open_ended_capped() {
+        let roller = SequenceRoller::new([5]);
+        let roll = ShadeTest::new(2).with_open_ended(true).roll(&roller);
+
+        assert_eq!(roll.dice().len(), 2 + ShadeTest::MAX_REROLLS);
+        assert!(roll.is_capped());
+
+        let roll = ShadeTest::new(2)
+            .with_open_ended(true)
+            .with_max_rerolls(3)
+            .roll(&roller);
+        assert_eq!(roll.dice(), &[D6::from(6); 5]);
+        assert!(roll.is_capped());
+    }
+
+    #[test]
+    fn shade_test_roll_closed_ended() {
+        let roller = FixedRoller(Cell::new(0), vec![6, 2, 6]);
+        let roll = ShadeTest::new(3).roll(&roller);
+
+        assert_eq!(roll.dice(), &[6, 2, 6].map(D6::from));
+    }
+}
@@ -0,0 +1,293 @@
+use crate::items::D6;
+
+/// A scoring category of a _Yahtzee_ score card.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Category {
+    /// The sum of all dice showing `1`.
+    Ones,
+
+    /// The sum of all dice showing `2`.
+    Twos,
+
+    /// The sum of all dice showing `3`.
+    Threes,
+
+    /// The sum of all dice showing `4`.
+    Fours,
+
+    /// The sum of all dice showing `5`.
+    Fives,
+
+    /// The sum of all dice showing `6`.
+    Sixes,
+
+    /// The sum of all dice, if at least three show the same value.
+    ThreeOfAKind,
+
+    /// The sum of all dice, if at least four show the same value.
+    FourOfAKind,
+
+    /// `25`, if three dice show one value and the other two show another value.
+    FullHouse,
+
+    /// `30`, if at least four dice form a sequence.
+    SmallStraight,
+
+    /// `40`, if all five dice form a sequence.
+    LargeStraight,
+
+    /// `50`, if all five dice show the same value.
+    Yahtzee,
+
+    /// The sum of all dice.
+    Chance,
+}
+
+impl Category {
+    /// Every category, in score card order.
+    pub const ALL: [Category; 13] = [
+        Category::Ones,
+        Category::Twos,
+        Category::Threes,
+        Category::Fours,
+        Category::Fives,
+        Category::Sixes,
+        Category::ThreeOfAKind,
+        Category::FourOfAKind,
+        Category::FullHouse,
+        Category::SmallStraight,
+        Category::LargeStraight,
+        Category::Yahtzee,
+        Category::Chance,
+    ];
+
+    /// Returns whether the category is part of the upper section (i.e. `Ones` through `Sixes`).
+    pub const fn is_upper(&self) -> bool {
+        matches!(
+            self,
+            Self::Ones | Self::Twos | Self::Threes | Self::Fours | Self::Fives | Self::Sixes
+        )
+    }
+}
+
+/// Five rolled dice, scored against the categories of a _Yahtzee_ score card.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::D6;
+/// use tomb::mechanics::{Category, YahtzeeRoll};
+///
+/// let roll = YahtzeeRoll::new(&[2, 3, 2, 3, 2].map(D6::from));
+///
+/// assert_eq!(roll.score(Category::Twos), 6);
+/// assert_eq!(roll.score(Category::FullHouse), 25);
+/// assert_eq!(roll.best(&Category::ALL), Some((Category::FullHouse, 25)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YahtzeeRoll {
+    counts: [u8; 6],
+}
+
+impl YahtzeeRoll {
+    /// Creates a new roll from five dice.
+    pub fn new(dice: &[D6; 5]) -> Self {
+        let mut counts = [0; 6];
+        for die in dice {
+            counts[die.value() as usize - 1] += 1;
+        }
+        Self { counts }
+    }
+
+    /// Returns how many dice show the given value, between `1..=6`.
+    ///
+    /// # Panics
+    ///
+    /// If the value is out of range.
+    pub fn count(&self, value: u8) -> u8 {
+        assert!((1..=6).contains(&value));
+        self.counts[value as usize - 1]
+    }
+
+    /// Returns the sum of all dice.
+    pub fn sum(&self) -> u32 {
+        self.counts
+            .iter()
+            .zip(1..)
+            .map(|(&count, value)| count as u32 * value)
+            .sum()
+    }
+
+    /// Returns the score of the given category.
+    pub fn score(&self, category: Category) -> u32 {
+        let most = self.counts.iter().copied().max().unwrap_or_default();
+        match category {
+            Category::Ones => self.upper(1),
+            Category::Twos => self.upper(2),
+            Category::Threes => self.upper(3),
+            Category::Fours => self.upper(4),
+            Category::Fives => self.upper(5),
+            Category::Sixes => self.upper(6),
+            Category::ThreeOfAKind if most >= 3 => self.sum(),
+            Category::FourOfAKind if most >= 4 => self.sum(),
+            Category::FullHouse if self.counts.contains(&3) && self.counts.contains(&2) => 25,
+            Category::SmallStraight if self.straight() >= 4 => 30,
+            Category::LargeStraight if self.straight() == 5 => 40,
+            Category::Yahtzee if most == 5 => 50,
+            Category::Chance => self.sum(),
+            _ => 0,
+        }
+    }
+
+    /// Returns the score of every category, in score card order.
+    pub fn scores(&self) -> [(Category, u32); 13] {
+        Category::ALL.map(|category| (category, self.score(category)))
+    }
+
+    /// Returns the highest scoring category of the available categories, and its score.
+    ///
+    /// If multiple categories score the same, the first in score card order is recommended.
+    pub fn best(&self, available: &[Category]) -> Option<(Category, u32)> {
+        Category::ALL
+            .iter()
+            .filter(|category| available.contains(category))
+            .map(|&category| (category, self.score(category)))
+            .rev()
+            .max_by_key(|&(_, score)| score)
+    }
+
+    fn upper(&self, value: u8) -> u32 {
+        self.count(value) as u32 * value as u32
+    }
+
+    fn straight(&self) -> usize {
+        let mut longest = 0;
+        let mut current = 0;
+        for &count in &self.counts {
+            current = if count > 0 { current + 1 } else { 0 };
+            longest = longest.max(current);
+        }
+        longest
+    }
+}
+
+impl From<[D6; 5]> for YahtzeeRoll {
+    fn from(dice: [D6; 5]) -> Self {
+        Self::new(&dice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roll(values: [u8; 5]) -> YahtzeeRoll {
+        YahtzeeRoll::from(values.map(D6::from))
+    }
+
+    #[test]
+    fn category_is_upper() {
+        assert!(Category::Ones.is_upper());
+        assert!(Category::Sixes.is_upper());
+        assert!(!Category::ThreeOfAKind.is_upper());
+        assert!(!Category::Chance.is_upper());
+    }
+
+    #[test]
+    fn yahtzee_count_and_sum() {
+        let r = roll([1, 1, 4, 5, 6]);
+
+        assert_eq!(r.count(1), 2);
+        assert_eq!(r.count(2), 0);
+        assert_eq!(r.sum(), 17);
+    }
+
+    #[test]
+    #[should_panic]
+    fn yahtzee_count_out_of_range() {
+        roll([1, 1, 4, 5, 6]).count(7);
+    }
+
+    #[test]
+    fn yahtzee_upper_section() {
+        let r = roll([1, 1, 4, 5, 5]);
+
+        assert_eq!(r.score(Category::Ones), 2);
+        assert_eq!(r.score(Category::Twos), 0);
+        assert_eq!(r.score(Category::Fours), 4);
+        assert_eq!(r.score(Category::Fives), 10);
+    }
+
+    #[test]
+    fn yahtzee_of_a_kind() {
+        let r = roll([3, 3, 3, 5, 6]);
+        assert_eq!(r.score(Category::ThreeOfAKind), 20);
+        assert_eq!(r.score(Category::FourOfAKind), 0);
+
+        let r = roll([3, 3, 3, 3, 6]);
+        assert_eq!(r.score(Category::ThreeOfAKind), 18);
+        assert_eq!(r.score(Category::FourOfAKind), 18);
+    }
+
+    #[test]
+    fn yahtzee_full_house() {
+        assert_eq!(roll([2, 2, 5, 5, 5]).score(Category::FullHouse), 25);
+        assert_eq!(roll([2, 2, 5, 5, 6]).score(Category::FullHouse), 0);
+        assert_eq!(roll([5, 5, 5, 5, 5]).score(Category::FullHouse), 0);
+    }
+
+    #[test]
+    fn yahtzee_straights() {
+        let r = roll([1, 2, 3, 4, 6]);
+        assert_eq!(r.score(Category::SmallStraight), 30);
+        assert_eq!(r.score(Category::LargeStraight), 0);
+
+        let r = roll([6, 2, 3, 4, 5]);
+        assert_eq!(r.score(Category::SmallStraight), 30);
+        assert_eq!(r.score(Category::LargeStraight), 40);
+
+        let r = roll([1, 2, 3, 5, 6]);
+        assert_eq!(r.score(Category::SmallStraight), 0);
+    }
+
+    #[test]
+    fn yahtzee_yahtzee_and_chance() {
+        let r = roll([4, 4, 4, 4, 4]);
+        assert_eq!(r.score(Category::Yahtzee), 50);
+        assert_eq!(r.score(Category::Chance), 20);
+
+        let r = roll([4, 4, 4, 4, 3]);
+        assert_eq!(r.score(Category::Yahtzee), 0);
+    }
+
+    #[test]
+    fn yahtzee_scores() {
+        let scores = roll([6, 6, 6, 6, 6]).scores();
+
+        assert_eq!(scores[0], (Category::Ones, 0));
+        assert_eq!(scores[5], (Category::Sixes, 30));
+        assert_eq!(scores[11], (Category::Yahtzee, 50));
+    }
+
+    #[test]
+    fn yahtzee_best() {
+        let r = roll([6, 6, 6, 6, 6]);
+
+        assert_eq!(r.best(&Category::ALL), Some((Category::Yahtzee, 50)));
+        assert_eq!(
+            r.best(&[Category::Ones, Category::Sixes]),
+            Some((Category::Sixes, 30))
+        );
+        assert_eq!(r.best(&[]), None);
+    }
+
+    #[test]
+    fn yahtzee_best_ties_in_card_order() {
+        let r = roll([3, 3, 3, 5, 6]);
+
+        assert_eq!(
+            r.best(&[Category::Chance, Category::ThreeOfAKind]),
+            Some((Category::ThreeOfAKind, 20))
+        );
+    }
+}
@@ -0,0 +1,736 @@
+//! Contains a transport-agnostic protocol for peers to agree on the outcome of a shared roll.
+//!
+//! No single peer can be trusted to roll for everyone, so every peer contributes a secret, and the
+//! dice are rolled using all of the secrets combined. To stop a peer from choosing its secret after
+//! seeing the others, a roll has four steps:
+//!
+//! 1. **Request**: A peer asks for a roll, i.e. of `3d6`, with a [`RollRequest`].
+//! 2. **Commit**: Every peer sends a [`commitment`] (a SHA-256 hash) of its secret, bound to the
+//!    roll and the peer, so a commitment can't be replayed in another roll or by another peer.
+//! 3. **Reveal**: Once every commitment is received, every peer sends its secret.
+//! 4. **Acknowledge**: Once every secret is received (and matches its commitment), every peer rolls
+//!    the dice and sends the result, which must match the result of every other peer.
+//!
+//! A [`SharedRoll`] tracks the state of a single roll, and returns the messages to send; how the
+//! messages are sent (and serialized, with the `serde` feature) is left to the application.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::collections::VecDeque;
+//! use tomb::net::{Phase, RollRequest, SharedRoll};
+//!
+//! let request = RollRequest::new(1, vec![6, 6, 6]);
+//!
+//! // Secrets should be generated by a cryptographically secure RNG.
+//! let mut alice = SharedRoll::new(request.clone(), 1, [1, 2], [7; 32]);
+//! let mut bobby = SharedRoll::new(request, 2, [1, 2], [9; 32]);
+//!
+//! // Exchange messages, in the order sent, until neither peer has anything left to send.
+//! let mut outbox = VecDeque::from([alice.commit(), bobby.commit()]);
+//! while let Some(message) = outbox.pop_front() {
+//!     outbox.extend(alice.receive(&message).unwrap());
+//!     outbox.extend(bobby.receive(&message).unwrap());
+//! }
+//!
+//! assert_eq!(alice.phase(), Phase::Agreed);
+//! assert_eq!(alice.result(), bobby.result());
+//! assert_eq!(alice.result().unwrap().len(), 3);
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use sha2::{Digest as _, Sha256};
+
+use crate::Error;
+
+/// Identifies a peer taking part in a roll.
+pub type PeerId = u64;
+
+/// A 32-byte secret or a SHA-256 hash of a secret.
+pub type Digest = [u8; 32];
+
+/// Returns the commitment (SHA-256 hash) of a peer's secret for the roll with the given ID.
+pub fn commitment(id: u64, peer: PeerId, secret: &Digest) -> Digest {
+    Sha256::new()
+        .chain_update(id.to_le_bytes())
+        .chain_update(peer.to_le_bytes())
+        .chain_update(secret)
+        .finalize()
+        .into()
+}
+
+/// Describes a shared roll of dice.
+///
+/// Requests are sent by other peers, so a deserialized request is validated the same way as
+/// [`RollRequest::try_new`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RollRequestRepr")
+)]
+pub struct RollRequest {
+    id: u64,
+    sides: Vec<usize>,
+}
+
+impl RollRequest {
+    /// The most dice that a single request can roll.
+    pub const MAX_DICE: usize = 1_000;
+
+    /// Creates a request with a unique ID to roll dice with the given number of sides.
+    ///
+    /// # Panics
+    ///
+    /// If any die has no sides, or there are more than [`RollRequest::MAX_DICE`] dice; see
+    /// [`RollRequest::try_new`] for a non-panicking alternative.
+    pub fn new(id: u64, sides: Vec<usize>) -> Self {
+        Self::try_new(id, sides).unwrap()
+    }
+
+    /// Creates a request with a unique ID to roll dice with the given number of sides.
+    ///
+    /// # Errors
+    ///
+    /// If any die has no sides, or there are more than [`RollRequest::MAX_DICE`] dice, returns
+    /// [`Error::OutOfRange`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::net::RollRequest;
+    ///
+    /// assert!(RollRequest::try_new(1, vec![6, 6]).is_ok());
+    /// assert!(RollRequest::try_new(1, vec![6, 0]).is_err());
+    /// assert!(RollRequest::try_new(1, vec![6; RollRequest::MAX_DICE + 1]).is_err());
+    /// ```
+    pub fn try_new(id: u64, sides: Vec<usize>) -> Result<Self, Error> {
+        if sides.len() > Self::MAX_DICE {
+            return Err(Error::OutOfRange {
+                value: sides.len(),
+                min: 0,
+                max: Self::MAX_DICE,
+            });
+        }
+        if sides.contains(&0) {
+            return Err(Error::OutOfRange {
+                value: 0,
+                min: 1,
+                max: usize::MAX,
+            });
+        }
+        Ok(Self { id, sides })
+    }
+
+    /// Returns the ID of the roll.
+    pub const fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the number of sides of each die to roll.
+    pub fn sides(&self) -> &[usize] {
+        &self.sides
+    }
+}
+
+/// The serialized form of a [`RollRequest`], which is validated when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RollRequestRepr {
+    id: u64,
+    sides: Vec<usize>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RollRequestRepr> for RollRequest {
+    type Error = Error;
+
+    fn try_from(repr: RollRequestRepr) -> Result<Self, Self::Error> {
+        Self::try_new(repr.id, repr.sides)
+    }
+}
+
+/// A message sent between peers to agree on a roll.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Message {
+    /// Asks every peer to start a roll.
+    Request(RollRequest),
+
+    /// A peer's commitment to its secret.
+    Commit {
+        /// The ID of the roll.
+        id: u64,
+
+        /// The peer that sent the message.
+        peer: PeerId,
+
+        /// The [`commitment`] of the peer's secret.
+        commitment: Digest,
+    },
+
+    /// A peer's secret, sent once every peer has committed.
+    Reveal {
+        /// The ID of the roll.
+        id: u64,
+
+        /// The peer that sent the message.
+        peer: PeerId,
+
+        /// The peer's secret.
+        secret: Digest,
+    },
+
+    /// A peer's result, sent once every peer has revealed.
+    Ack {
+        /// The ID of the roll.
+        id: u64,
+
+        /// The peer that sent the message.
+        peer: PeerId,
+
+        /// The natural face of each die, between `1..=sides`.
+        result: Vec<usize>,
+    },
+}
+
+impl Message {
+    /// Returns the ID of the roll the message is about.
+    pub const fn id(&self) -> u64 {
+        match self {
+            Self::Request(request) => request.id,
+            Self::Commit { id, .. } | Self::Reveal { id, .. } | Self::Ack { id, .. } => *id,
+        }
+    }
+}
+
+/// The current step of a [`SharedRoll`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// Waiting for every peer's commitment.
+    Committing,
+
+    /// Waiting for every peer's secret.
+    Revealing,
+
+    /// Waiting for every peer's result.
+    Acknowledging,
+
+    /// Every peer has the same result.
+    Agreed,
+}
+
+/// An error caused by a peer that did not follow the protocol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProtocolError {
+    /// The peer is not taking part in the roll.
+    UnknownPeer(PeerId),
+
+    /// The peer sent the same step more than once.
+    Duplicate(PeerId),
+
+    /// The peer revealed its secret before committing to it.
+    OutOfOrder(PeerId),
+
+    /// The peer's secret does not match its commitment.
+    CommitmentMismatch(PeerId),
+
+    /// The peer's result does not match this peer's result.
+    Disagreement(PeerId),
+}
+
+impl Display for ProtocolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownPeer(peer) => write!(f, "peer {peer} is not part of the roll"),
+            Self::Duplicate(peer) => write!(f, "peer {peer} sent a duplicate message"),
+            Self::OutOfOrder(peer) => write!(f, "peer {peer} revealed before committing"),
+            Self::CommitmentMismatch(peer) => {
+                write!(
+                    f,
+                    "peer {peer} revealed a secret that does not match its commitment"
+                )
+            }
+            Self::Disagreement(peer) => write!(f, "peer {peer} disagrees on the result"),
+        }
+    }
+}
+
+impl std::error::Error for ProtocolError {}
+
+/// Tracks the state of a single roll shared between peers.
+///
+/// See the [module documentation](self) for an overview of the protocol. Messages from each peer
+/// must be received in the order that peer sent them.
+#[derive(Clone, Debug)]
+pub struct SharedRoll {
+    request: RollRequest,
+    local: PeerId,
+    peers: Vec<PeerId>,
+    secret: Digest,
+    commitments: BTreeMap<PeerId, Digest>,
+    reveals: BTreeMap<PeerId, Digest>,
+    acks: BTreeMap<PeerId, Vec<usize>>,
+    result: Option<Vec<usize>>,
+}
+
+impl SharedRoll {
+    /// Creates a roll as the `local` peer, with every peer (including the local peer) taking part.
+    ///
+    /// The secret must be unpredictable to other peers, i.e. generated by a secure RNG.
+    ///
+    /// # Panics
+    ///
+    /// If the local peer is not one of the peers.
+    pub fn new(
+        request: RollRequest,
+        local: PeerId,
+        peers: impl IntoIterator<Item = PeerId>,
+        secret: Digest,
+    ) -> Self {
+        let mut peers: Vec<PeerId> = peers.into_iter().collect();
+        peers.sort_unstable();
+        peers.dedup();
+        assert!(peers.contains(&local));
+        let mut commitments = BTreeMap::new();
+        commitments.insert(local, commitment(request.id, local, &secret));
+        Self {
+            request,
+            local,
+            peers,
+            secret,
+            commitments,
+            reveals: BTreeMap::new(),
+            acks: BTreeMap::new(),
+            result: None,
+        }
+    }
+
+    /// Returns the roll being agreed on.
+    pub const fn request(&self) -> &RollRequest {
+        &self.request
+    }
+
+    /// Returns every peer taking part in the roll, in order.
+    pub fn peers(&self) -> &[PeerId] {
+        &self.peers
+    }
+
+    /// Returns the current step of the roll.
+    pub fn phase(&self) -> Phase {
+        if self.commitments.len() < self.peers.len() {
+            Phase::Committing
+        } else if self.result.is_none() {
+            Phase::Revealing
+        } else if self.acks.len() < self.peers.len() {
+            Phase::Acknowledging
+        } else {
+            Phase::Agreed
+        }
+    }
+
+    /// Returns the natural face of each die, once every peer has revealed.
+    pub fn result(&self) -> Option<&[usize]> {
+        self.result.as_deref()
+    }
+
+    /// Returns the local peer's commitment, which should be sent to every other peer.
+    pub fn commit(&self) -> Message {
+        Message::Commit {
+            id: self.request.id,
+            peer: self.local,
+            commitment: self.commitments[&self.local],
+        }
+    }
+
+    /// Handles a message from a peer, returning any messages to send to every other peer.
+    ///
+    /// Messages about other rolls, and messages sent by the local peer, are ignored.
+    pub fn receive(&mut self, message: &Message) -> Result<Vec<Message>, ProtocolError> {
+        if message.id() != self.request.id {
+            return Ok(Vec::new());
+        }
+        match message {
+            Message::Request(_) => {}
+            Message::Commit { peer, .. }
+            | Message::Reveal { peer, .. }
+            | Message::Ack { peer, .. }
+                if *peer == self.local => {}
+            Message::Commit {
+                peer, commitment, ..
+            } => {
+                self.check_peer(*peer)?;
+                if self.commitments.contains_key(peer) {
+                    return Err(ProtocolError::Duplicate(*peer));
+                }
+                self.commitments.insert(*peer, *commitment);
+            }
+            Message::Reveal { peer, secret, .. } => {
+                self.check_peer(*peer)?;
+                if self.reveals.contains_key(peer) {
+                    return Err(ProtocolError::Duplicate(*peer));
+                }
+                match self.commitments.get(peer) {
+                    None => return Err(ProtocolError::OutOfOrder(*peer)),
+                    Some(expected) if *expected != commitment(self.request.id, *peer, secret) => {
+                        return Err(ProtocolError::CommitmentMismatch(*peer))
+                    }
+                    Some(_) => {}
+                }
+                self.reveals.insert(*peer, *secret);
+            }
+            Message::Ack { peer, result, .. } => {
+                self.check_peer(*peer)?;
+                if self.acks.contains_key(peer) {
+                    return Err(ProtocolError::Duplicate(*peer));
+                }
+                if self.result.as_ref().is_some_and(|r| r != result) {
+                    return Err(ProtocolError::Disagreement(*peer));
+                }
+                self.acks.insert(*peer, result.clone());
+            }
+        }
+        self.advance()
+    }
+
+    fn check_peer(&self, peer: PeerId) -> Result<(), ProtocolError> {
+        if self.peers.binary_search(&peer).is_ok() {
+            Ok(())
+        } else {
+            Err(ProtocolError::UnknownPeer(peer))
+        }
+    }
+
+    fn advance(&mut self) -> Result<Vec<Message>, ProtocolError> {
+        let mut outgoing = Vec::new();
+        if self.phase() == Phase::Revealing && !self.reveals.contains_key(&self.local) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(id = self.request.id, peer = self.local, "reveal");
+            self.reveals.insert(self.local, self.secret);
+            outgoing.push(Message::Reveal {
+                id: self.request.id,
+                peer: self.local,
+                secret: self.secret,
+            });
+        }
+        if self.phase() == Phase::Revealing && self.reveals.len() == self.peers.len() {
+            let result = self.roll();
+            if let Some((&peer, _)) = self.acks.iter().find(|(_, r)| **r != result) {
+                return Err(ProtocolError::Disagreement(peer));
+            }
+            #[cfg(feature = "tracing")]
+            tracing::debug!(id = self.request.id, ?result, "shared roll result");
+            self.acks.insert(self.local, result.clone());
+            self.result = Some(result.clone());
+            outgoing.push(Message::Ack {
+                id: self.request.id,
+                peer: self.local,
+                result,
+            });
+        }
+        Ok(outgoing)
+    }
+
+    /// Rolls every die using a seed derived from every secret, in peer order.
+    ///
+    /// Each die is rolled by hashing the seed, the index of the die, and an attempt counter, and
+    /// attempts that would bias the result are discarded.
+    fn roll(&self) -> Vec<usize> {
+        let mut seed = Sha256::new();
+        for secret in self.reveals.values() {
+            seed.update(secret);
+        }
+        let seed = seed.finalize();
+        let mut result = Vec::with_capacity(self.request.sides.len());
+        for (index, &sides) in self.request.sides.iter().enumerate() {
+            let sides = sides as u64;
+            let limit = u64::MAX - u64::MAX % sides;
+            for attempt in 0u64.. {
+                let hash = Sha256::new()
+                    .chain_update(seed)
+                    .chain_update((index as u64).to_le_bytes())
+                    .chain_update(attempt.to_le_bytes())
+                    .finalize();
+                let value = u64::from_le_bytes(hash[..8].try_into().unwrap());
+                if value < limit {
+                    result.push((value % sides) as usize + 1);
+                    break;
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::*;
+
+    fn exchange(rolls: &mut [SharedRoll]) -> Result<(), ProtocolError> {
+        let mut outbox: VecDeque<Message> = rolls.iter().map(SharedRoll::commit).collect();
+        while let Some(message) = outbox.pop_front() {
+            for roll in rolls.iter_mut() {
+                outbox.extend(roll.receive(&message)?);
+            }
+        }
+        Ok(())
+    }
+
+    fn rolls(count: u64) -> Vec<SharedRoll> {
+        let request = RollRequest::new(5, vec![20, 6, 6]);
+        (1..=count)
+            .map(|peer| SharedRoll::new(request.clone(), peer, 1..=count, [peer as u8; 32]))
+            .collect()
+    }
+
+    #[test]
+    fn roll_request_new() {
+        let request = RollRequest::new(3, vec![20]);
+
+        assert_eq!(request.id(), 3);
+        assert_eq!(request.sides(), &[20]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn roll_request_no_sides() {
+        RollRequest::new(3, vec![0]);
+    }
+
+    #[test]
+    fn roll_request_try_new() {
+        assert_eq!(
+            RollRequest::try_new(3, vec![6; RollRequest::MAX_DICE]).map(|r| r.sides().len()),
+            Ok(RollRequest::MAX_DICE)
+        );
+        assert_eq!(
+            RollRequest::try_new(3, vec![6; RollRequest::MAX_DICE + 1]),
+            Err(Error::OutOfRange {
+                value: RollRequest::MAX_DICE + 1,
+                min: 0,
+                max: RollRequest::MAX_DICE
+            })
+        );
+        assert_eq!(
+            RollRequest::try_new(3, vec![6, 0]),
+            Err(Error::OutOfRange {
+                value: 0,
+                min: 1,
+                max: usize::MAX
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn message_serde_hostile_request() {
+        let valid = Message::Request(RollRequest::new(3, vec![20, 6]));
+        let json = serde_json::to_string(&valid).unwrap();
+        assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), valid);
+
+        let too_many = format!(
+            r#"{{"Request":{{"id":3,"sides":{:?}}}}}"#,
+            vec![6; RollRequest::MAX_DICE + 1]
+        );
+        for json in [r#"{"Request":{"id":3,"sides":[6,0]}}"#, too_many.as_str()] {
+            assert!(serde_json::from_str::<Message>(json).is_err());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn shared_roll_not_a_peer() {
+        SharedRoll::new(RollRequest::new(1, vec![6]), 3, [1, 2], [0; 32]);
+    }
+
+    #[test]
+    fn shared_roll_agreed() {
+        let mut rolls = rolls(3);
+        exchange(&mut rolls).unwrap();
+
+        let result = rolls[0].result().unwrap();
+        assert!(rolls.iter().all(|r| r.phase() == Phase::Agreed));
+        assert!(rolls.iter().all(|r| r.result() == Some(result)));
+        assert!((1..=20).contains(&result[0]));
+        assert!(result[1..].iter().all(|n| (1..=6).contains(n)));
+    }
+
+    #[test]
+    fn shared_roll_deterministic() {
+        let mut a = rolls(2);
+        let mut b = rolls(2);
+        exchange(&mut a).unwrap();
+        exchange(&mut b).unwrap();
+
+        assert_eq!(a[0].result(), b[0].result());
+    }
+
+    #[test]
+    fn shared_roll_waits_for_commitments() {
+        let mut rolls = rolls(3);
+        let commit = rolls[1].commit();
+
+        assert_eq!(rolls[0].receive(&commit), Ok(Vec::new()));
+        assert_eq!(rolls[0].phase(), Phase::Committing);
+        assert_eq!(rolls[0].result(), None);
+    }
+
+    #[test]
+    fn shared_roll_ignores_other_rolls() {
+        let mut rolls = rolls(2);
+        let other = SharedRoll::new(RollRequest::new(6, vec![6]), 2, [1, 2], [0; 32]);
+
+        assert_eq!(rolls[0].receive(&other.commit()), Ok(Vec::new()));
+        assert_eq!(rolls[0].phase(), Phase::Committing);
+    }
+
+    #[test]
+    fn shared_roll_unknown_peer() {
+        let mut rolls = rolls(2);
+        let message = Message::Commit {
+            id: 5,
+            peer: 9,
+            commitment: [0; 32],
+        };
+
+        assert_eq!(
+            rolls[0].receive(&message),
+            Err(ProtocolError::UnknownPeer(9))
+        );
+    }
+
+    #[test]
+    fn shared_roll_duplicate() {
+        let mut rolls = rolls(3);
+        let commit = rolls[1].commit();
+        rolls[0].receive(&commit).unwrap();
+
+        assert_eq!(rolls[0].receive(&commit), Err(ProtocolError::Duplicate(2)));
+    }
+
+    #[test]
+    fn shared_roll_recommit_after_reveal() {
+        let mut honest = rolls(2);
+        exchange(&mut honest).unwrap();
+
+        let mut rolls = rolls(2);
+        let commit = rolls[1].commit();
+        let outgoing = rolls[0].receive(&commit).unwrap();
+        assert!(matches!(outgoing[..], [Message::Reveal { .. }]));
+
+        // Having seen the other secret, the peer tries to commit to a different secret.
+        let recommit = Message::Commit {
+            id: 5,
+            peer: 2,
+            commitment: commitment(5, 2, &[3; 32]),
+        };
+        let reveal = |secret| Message::Reveal {
+            id: 5,
+            peer: 2,
+            secret,
+        };
+        assert_eq!(
+            rolls[0].receive(&recommit),
+            Err(ProtocolError::Duplicate(2))
+        );
+        assert_eq!(
+            rolls[0].receive(&reveal([3; 32])),
+            Err(ProtocolError::CommitmentMismatch(2))
+        );
+
+        // The original commitment still applies.
+        rolls[0].receive(&reveal([2; 32])).unwrap();
+        assert_eq!(rolls[0].result(), honest[0].result());
+    }
+
+    #[test]
+    fn shared_roll_commitment_is_bound() {
+        let secret = [2; 32];
+
+        assert_ne!(commitment(5, 2, &secret), commitment(6, 2, &secret));
+        assert_ne!(commitment(5, 2, &secret), commitment(5, 3, &secret));
+
+        // A commitment copied from another peer does not match a reveal of the same secret.
+        let mut rolls = rolls(3);
+        let replayed = Message::Commit {
+            id: 5,
+            peer: 3,
+            commitment: commitment(5, 2, &secret),
+        };
+        rolls[0].receive(&replayed).unwrap();
+        let reveal = Message::Reveal {
+            id: 5,
+            peer: 3,
+            secret,
+        };
+        assert_eq!(
+            rolls[0].receive(&reveal),
+            Err(ProtocolError::CommitmentMismatch(3))
+        );
+    }
+
+    #[test]
+    fn shared_roll_reveal_before_commit() {
+        let mut rolls = rolls(2);
+        let message = Message::Reveal {
+            id: 5,
+            peer: 2,
+            secret: [2; 32],
+        };
+
+        assert_eq!(
+            rolls[0].receive(&message),
+            Err(ProtocolError::OutOfOrder(2))
+        );
+    }
+
+    #[test]
+    fn shared_roll_commitment_mismatch() {
+        let mut rolls = rolls(2);
+        let commit = rolls[1].commit();
+        rolls[0].receive(&commit).unwrap();
+        let message = Message::Reveal {
+            id: 5,
+            peer: 2,
+            secret: [3; 32],
+        };
+
+        assert_eq!(
+            rolls[0].receive(&message),
+            Err(ProtocolError::CommitmentMismatch(2))
+        );
+    }
+
+    #[test]
+    fn shared_roll_disagreement() {
+        let rolls = rolls(2);
+        let mut roll = SharedRoll::new(RollRequest::new(5, vec![20, 6, 6]), 1, [1, 2], [1; 32]);
+        roll.receive(&rolls[1].commit()).unwrap();
+        roll.receive(&Message::Reveal {
+            id: 5,
+            peer: 2,
+            secret: [2; 32],
+        })
+        .unwrap();
+        let message = Message::Ack {
+            id: 5,
+            peer: 2,
+            result: vec![0, 0, 0],
+        };
+
+        assert_eq!(roll.phase(), Phase::Acknowledging);
+        assert_eq!(roll.receive(&message), Err(ProtocolError::Disagreement(2)));
+    }
+
+    #[test]
+    fn protocol_error_display() {
+        assert_eq!(
+            ProtocolError::CommitmentMismatch(2).to_string(),
+            "peer 2 revealed a secret that does not match its commitment"
+        );
+    }
+}
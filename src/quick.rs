@@ -0,0 +1,224 @@
+//! Contains convenience functions that roll using a default roller, for scripts and examples.
+//!
+//! Every thread has its own default [`RngRoller`], which is randomly seeded unless [`seed`] is
+//! called, so no roller needs to be created or passed around. In tests, [`with_roller`] overrides
+//! the default roller for the duration of a closure, i.e. to force outcomes.
+//!
+//! # Examples
+//!
+//! ```
+//! use tomb::quick;
+//!
+//! // Optional, to make this example predictable.
+//! quick::seed(7194422452970863838);
+//!
+//! assert_eq!(quick::roll_d6(), 3);
+//! assert_eq!(quick::roll_d20(), 13);
+//! ```
+
+use std::cell::RefCell;
+
+use fastrand::Rng;
+
+use crate::items::{RngRoller, D10, D100, D12, D20, D4, D6, D8};
+use crate::mechanics::{Notation, NotationRoll};
+use crate::traits::{Positional, Roll, Rotate, SideSelector};
+
+/// Selects a position given a number of sides; see [`SideSelector::select`].
+type Select = Box<dyn Fn(usize) -> usize>;
+
+thread_local! {
+    static ROLLER: RefCell<RngRoller> = RefCell::new(RngRoller::new());
+    static OVERRIDES: RefCell<Vec<Select>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The default roller of the current thread, which is the innermost [`with_roller`] override.
+struct ThreadRoller;
+
+impl SideSelector for ThreadRoller {
+    fn select(&self, sides: usize) -> usize {
+        OVERRIDES.with(|overrides| match overrides.borrow().last() {
+            Some(select) => select(sides),
+            None => ROLLER.with(|roller| roller.borrow().select(sides)),
+        })
+    }
+}
+
+impl Roll for ThreadRoller {
+    /// Returns a copy of the entity showing a [selected](SideSelector::select) position.
+    fn roll<T>(&self, rotate: &T) -> T
+    where
+        T: Rotate + Positional,
+    {
+        self.pick(rotate)
+    }
+}
+
+/// Removes the innermost override when dropped, even if the closure panics.
+struct Restore;
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        OVERRIDES.with(|overrides| overrides.borrow_mut().pop());
+    }
+}
+
+/// Calls the function with the given roller as the default roller of the current thread.
+///
+/// Once the function returns (or panics), the previous default roller is used again. Overrides
+/// can be nested, and only apply to the current thread.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::SequenceRoller;
+/// use tomb::quick;
+///
+/// let rolls = quick::with_roller(SequenceRoller::new([19, 0]), || {
+///     (quick::roll_d20(), quick::roll_d20())
+/// });
+///
+/// assert_eq!(rolls, (20, 1));
+/// ```
+pub fn with_roller<R, F, T>(roller: R, f: F) -> T
+where
+    R: SideSelector + 'static,
+    F: FnOnce() -> T,
+{
+    OVERRIDES.with(|overrides| {
+        overrides
+            .borrow_mut()
+            .push(Box::new(move |sides| roller.select(sides)));
+    });
+    let _restore = Restore;
+    f()
+}
+
+/// Replaces the default roller of the current thread with one using the given seed.
+pub fn seed(seed: u64) {
+    ROLLER.with(|roller| *roller.borrow_mut() = RngRoller::from(Rng::with_seed(seed)));
+}
+
+/// Rolls the die using the default roller, returning the result.
+pub fn roll<T>(die: &T) -> T
+where
+    T: Rotate + Positional,
+{
+    ThreadRoller.roll(die)
+}
+
+/// Rolls dice notation using the default roller; see [`Notation::roll`].
+pub fn roll_notation(notation: &Notation) -> NotationRoll {
+    notation.roll(&ThreadRoller)
+}
+
+/// Rolls a D4, returning the value.
+pub fn roll_d4() -> u8 {
+    roll(&D4::new()).value()
+}
+
+/// Rolls a D6, returning the value.
+pub fn roll_d6() -> u8 {
+    roll(&D6::new()).value()
+}
+
+/// Rolls a D8, returning the value.
+pub fn roll_d8() -> u8 {
+    roll(&D8::new()).value()
+}
+
+/// Rolls a D10, returning the value.
+pub fn roll_d10() -> u8 {
+    roll(&D10::new()).value()
+}
+
+/// Rolls a D12, returning the value.
+pub fn roll_d12() -> u8 {
+    roll(&D12::new()).value()
+}
+
+/// Rolls a D20, returning the value.
+pub fn roll_d20() -> u8 {
+    roll(&D20::new()).value()
+}
+
+/// Rolls a D100, returning the value.
+pub fn roll_d100() -> u8 {
+    roll(&D100::new()).value()
+}
+
+/// Flips a fair coin, returning `true` for heads.
+pub fn flip_coin() -> bool {
+    ThreadRoller.select(2) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::SequenceRoller;
+
+    #[test]
+    fn quick_seed_is_reproducible() {
+        seed(42);
+        let first = [roll_d4(), roll_d8(), roll_d10(), roll_d12(), roll_d100()];
+        seed(42);
+        let second = [roll_d4(), roll_d8(), roll_d10(), roll_d12(), roll_d100()];
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn quick_ranges() {
+        for _ in 0..100 {
+            assert!((1..=20).contains(&roll_d20()));
+            assert!((1..=6).contains(&roll_d6()));
+        }
+    }
+
+    #[test]
+    fn quick_flip_coin() {
+        seed(1);
+        let heads = (0..1000).filter(|_| flip_coin()).count();
+
+        assert!((400..600).contains(&heads));
+    }
+
+    #[test]
+    fn quick_roll_notation() {
+        let roll = roll_notation(&Notation::new(3, 6).with_modifier(1));
+
+        assert!((4..=19).contains(&roll.total()));
+    }
+
+    #[test]
+    fn quick_with_roller_nested() {
+        let (outer, inner, restored) = with_roller(SequenceRoller::new([5]), || {
+            let inner = with_roller(SequenceRoller::new([0, 1]), || {
+                (
+                    roll_d6(),
+                    roll_d6(),
+                    flip_coin(),
+                    roll_notation(&Notation::new(2, 6)).total(),
+                )
+            });
+            (roll_d6(), inner, roll_d6())
+        });
+
+        assert_eq!((outer, restored), (6, 6));
+        assert_eq!(inner, (1, 2, true, 3));
+    }
+
+    #[test]
+    fn quick_with_roller_restores_after_panic() {
+        let result = std::panic::catch_unwind(|| {
+            with_roller(SequenceRoller::new([0]), || panic!("oops"));
+        });
+        seed(42);
+        let first = roll_d100();
+        seed(42);
+
+        assert!(result.is_err());
+        assert_eq!(with_roller(SequenceRoller::new([99]), roll_d100), 100);
+        assert_eq!(roll_d100(), first);
+    }
+}
@@ -0,0 +1,40 @@
+//! Contains exact (non-simulated) probability analysis of dice.
+//!
+//! A [`Distribution`] is the probability of every possible total of a dice expression, and is
+//! composed the same way an expression is written: `3d6+2` is `Distribution::dice(3, 6) + 2`.
+//!
+//! # Examples
+//!
+//! ```
+//! use tomb::stats::Distribution;
+//!
+//! // 2d6+2
+//! let dist = Distribution::dice(2, 6) + 2;
+//!
+//! assert_eq!(dist.min(), 4);
+//! assert_eq!(dist.max(), 14);
+//! assert_eq!(dist.mean(), 9.0);
+//! assert!((dist.p(9) - 6.0 / 36.0).abs() < 1e-12);
+//! ```
+
+mod accumulator;
+mod cache;
+mod comparison;
+mod counts;
+mod distribution;
+mod fairness;
+mod histogram;
+mod log;
+mod statistics;
+mod streak;
+
+pub use accumulator::*;
+pub use cache::*;
+pub use comparison::*;
+pub use counts::*;
+pub use distribution::*;
+pub use fairness::*;
+pub use histogram::*;
+pub use log::*;
+pub use statistics::*;
+pub use streak::*;
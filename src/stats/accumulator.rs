@@ -0,0 +1,258 @@
+/// Accumulates running statistics of roll outcomes without storing the outcomes.
+///
+/// Uses _Welford's_ online algorithm, so the count, mean, variance, minimum, and maximum are kept
+/// up to date in constant memory, regardless of how many outcomes are recorded.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::stats::Accumulator;
+///
+/// let mut stats = Accumulator::new();
+/// stats.extend([2, 4, 4, 4, 5, 5, 7, 9]);
+///
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean(), 5.0);
+/// assert_eq!(stats.variance(), 4.0);
+/// assert_eq!(stats.std_dev(), 2.0);
+/// assert_eq!(stats.min(), Some(2));
+/// assert_eq!(stats.max(), Some(9));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "AccumulatorRepr")
+)]
+pub struct Accumulator {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    min: Option<i64>,
+    max: Option<i64>,
+}
+
+impl Accumulator {
+    /// Creates an accumulator with no recorded outcomes.
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Records a single outcome.
+    pub fn record(&mut self, value: i64) {
+        self.count += 1;
+        let delta = value as f64 - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value as f64 - self.mean);
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+    }
+
+    /// Returns how many outcomes were recorded.
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns whether no outcomes were recorded.
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the mean of the recorded outcomes, or `0.0` if none were recorded.
+    pub const fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the (population) variance of the recorded outcomes, or `0.0` if none were recorded.
+    pub fn variance(&self) -> f64 {
+        match self.count {
+            0 => 0.0,
+            count => self.m2 / count as f64,
+        }
+    }
+
+    /// Returns the sample variance of the recorded outcomes, or `0.0` if fewer than two were recorded.
+    pub fn sample_variance(&self) -> f64 {
+        match self.count {
+            0 | 1 => 0.0,
+            count => self.m2 / (count - 1) as f64,
+        }
+    }
+
+    /// Returns the (population) standard deviation of the recorded outcomes.
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Returns the lowest recorded outcome, if any.
+    pub const fn min(&self) -> Option<i64> {
+        self.min
+    }
+
+    /// Returns the highest recorded outcome, if any.
+    pub const fn max(&self) -> Option<i64> {
+        self.max
+    }
+
+    /// Combines the outcomes recorded by another accumulator into this accumulator.
+    ///
+    /// The result is the same as if every outcome was recorded by this accumulator.
+    pub fn merge(&mut self, other: &Accumulator) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other.clone();
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 +=
+            other.m2 + delta * delta * (self.count as f64 * other.count as f64) / count as f64;
+        self.count = count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+}
+
+impl Default for Accumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The serialized form of an [`Accumulator`], which is validated when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct AccumulatorRepr {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    min: Option<i64>,
+    max: Option<i64>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<AccumulatorRepr> for Accumulator {
+    type Error = crate::Error;
+
+    /// Creates an accumulator from the serialized fields, failing if the minimum and maximum are
+    /// not both present exactly when an outcome was recorded.
+    fn try_from(repr: AccumulatorRepr) -> Result<Self, Self::Error> {
+        let (min, max) = match (repr.min, repr.max) {
+            (None, None) => (0, 0),
+            (Some(_), Some(_)) => (1, usize::MAX),
+            _ => (1, 0),
+        };
+        let count = usize::try_from(repr.count).unwrap_or(usize::MAX);
+        if count < min || count > max {
+            return Err(crate::Error::OutOfRange {
+                value: count,
+                min,
+                max,
+            });
+        }
+        Ok(Self {
+            count: repr.count,
+            mean: repr.mean,
+            m2: repr.m2,
+            min: repr.min,
+            max: repr.max,
+        })
+    }
+}
+
+impl Extend<i64> for Accumulator {
+    fn extend<I: IntoIterator<Item = i64>>(&mut self, iter: I) {
+        for value in iter {
+            self.record(value);
+        }
+    }
+}
+
+impl FromIterator<i64> for Accumulator {
+    fn from_iter<I: IntoIterator<Item = i64>>(iter: I) -> Self {
+        let mut accumulator = Self::new();
+        accumulator.extend(iter);
+        accumulator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulator_default() {
+        let stats = Accumulator::default();
+
+        assert!(stats.is_empty());
+        assert_eq!(stats.mean(), 0.0);
+        assert_eq!(stats.variance(), 0.0);
+        assert_eq!(stats.sample_variance(), 0.0);
+        assert_eq!(stats.min(), None);
+        assert_eq!(stats.max(), None);
+    }
+
+    #[test]
+    fn accumulator_single() {
+        let stats: Accumulator = [-3].into_iter().collect();
+
+        assert_eq!(stats.mean(), -3.0);
+        assert_eq!(stats.variance(), 0.0);
+        assert_eq!(stats.sample_variance(), 0.0);
+        assert_eq!(stats.min(), Some(-3));
+        assert_eq!(stats.max(), Some(-3));
+    }
+
+    #[test]
+    fn accumulator_sample_variance() {
+        let stats: Accumulator = [1, 2, 3, 4].into_iter().collect();
+
+        assert_eq!(stats.mean(), 2.5);
+        assert_eq!(stats.variance(), 1.25);
+        assert!((stats.sample_variance() - 5.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn accumulator_merge() {
+        let mut a: Accumulator = [2, 4, 4, 4].into_iter().collect();
+        let b: Accumulator = [5, 5, 7, 9].into_iter().collect();
+        a.merge(&b);
+
+        assert_eq!(a.count(), 8);
+        assert_eq!(a.mean(), 5.0);
+        assert!((a.variance() - 4.0).abs() < 1e-12);
+        assert_eq!(a.min(), Some(2));
+        assert_eq!(a.max(), Some(9));
+    }
+
+    #[test]
+    fn accumulator_merge_empty() {
+        let mut a = Accumulator::new();
+        let b: Accumulator = [1, 6].into_iter().collect();
+        a.merge(&b);
+        a.merge(&Accumulator::new());
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn accumulator_serde_invalid() {
+        for json in [
+            r#"{"count":0,"mean":0.0,"m2":0.0,"min":1,"max":1}"#,
+            r#"{"count":2,"mean":1.0,"m2":0.0,"min":null,"max":null}"#,
+            r#"{"count":2,"mean":1.0,"m2":0.0,"min":1,"max":null}"#,
+        ] {
+            assert!(serde_json::from_str::<Accumulator>(json).is_err(), "{json}");
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use std::collections::{HashMap, VecDeque};
+
+use super::Distribution;
+
+/// A normalized description of a dice expression, used to look up a cached [`Distribution`].
+///
+/// Expressions are only created by constructors, which normalize equivalent expressions to the same
+/// value, i.e. `4d6dl1` is `4d6kh3`, keeping every die is the same as rolling them all, and
+/// rolling (or keeping) no dice is the same regardless of their sides, so each shares a single
+/// cache entry.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::stats::Expression;
+///
+/// assert_eq!(Expression::drop_lowest(4, 6, 1), Expression::keep_highest(4, 6, 3));
+/// assert_eq!(Expression::keep_lowest(2, 20, 5), Expression::dice(2, 20));
+/// assert_eq!(Expression::keep_highest(3, 8, 0), Expression::dice(0, 4));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Expression(Kind);
+
+/// The kinds of [`Expression`], which are private so every expression is normalized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Kind {
+    /// The sum of `count` dice, i.e. `NdX`, where `0dX` is always `0d1` (unless `X` is `0`).
+    Dice { count: usize, sides: usize },
+
+    /// The sum of the highest `keep` of `count` dice, where `keep` is within `1..count`.
+    KeepHighest {
+        count: usize,
+        sides: usize,
+        keep: usize,
+    },
+
+    /// The sum of the lowest `keep` of `count` dice, where `keep` is within `1..count`.
+    KeepLowest {
+        count: usize,
+        sides: usize,
+        keep: usize,
+    },
+
+    /// A single exploding die, with up to `cap` (at least `1`) additional rolls.
+    Exploding { sides: usize, cap: usize },
+}
+
+impl Expression {
+    /// Describes the sum of `count` dice with the given sides; see [`Distribution::dice`].
+    pub const fn dice(count: usize, sides: usize) -> Self {
+        if count == 0 && sides > 0 {
+            Self(Kind::Dice { count, sides: 1 })
+        } else {
+            Self(Kind::Dice { count, sides })
+        }
+    }
+
+    /// Describes the highest `keep` of `count` dice; see [`Distribution::keep_highest`].
+    pub const fn keep_highest(count: usize, sides: usize, keep: usize) -> Self {
+        if keep >= count {
+            Self::dice(count, sides)
+        } else if keep == 0 {
+            Self::dice(0, sides)
+        } else {
+            Self(Kind::KeepHighest { count, sides, keep })
+        }
+    }
+
+    /// Describes the lowest `keep` of `count` dice; see [`Distribution::keep_lowest`].
+    pub const fn keep_lowest(count: usize, sides: usize, keep: usize) -> Self {
+        if keep >= count {
+            Self::dice(count, sides)
+        } else if keep == 0 {
+            Self::dice(0, sides)
+        } else {
+            Self(Kind::KeepLowest { count, sides, keep })
+        }
+    }
+
+    /// Describes `count` dice, dropping the lowest `drop`; see [`Distribution::drop_lowest`].
+    pub const fn drop_lowest(count: usize, sides: usize, drop: usize) -> Self {
+        Self::keep_highest(count, sides, count.saturating_sub(drop))
+    }
+
+    /// Describes `count` dice, dropping the highest `drop`; see [`Distribution::drop_highest`].
+    pub const fn drop_highest(count: usize, sides: usize, drop: usize) -> Self {
+        Self::keep_lowest(count, sides, count.saturating_sub(drop))
+    }
+
+    /// Describes a single exploding die; see [`Distribution::exploding`].
+    pub const fn exploding(sides: usize, cap: usize) -> Self {
+        if cap == 0 {
+            Self::dice(1, sides)
+        } else {
+            Self(Kind::Exploding { sides, cap })
+        }
+    }
+
+    /// Computes the distribution of the expression, without caching.
+    ///
+    /// # Panics
+    ///
+    /// If the die has no sides.
+    pub fn distribution(&self) -> Distribution {
+        match self.0 {
+            Kind::Dice { count, sides } => Distribution::dice(count, sides),
+            Kind::KeepHighest { count, sides, keep } => {
+                Distribution::keep_highest(count, sides, keep)
+            }
+            Kind::KeepLowest { count, sides, keep } => {
+                Distribution::keep_lowest(count, sides, keep)
+            }
+            Kind::Exploding { sides, cap } => Distribution::exploding(sides, cap),
+        }
+    }
+}
+
+/// Remembers computed distributions, so repeated queries of an expression are not recomputed.
+///
+/// By default, every distribution is kept until [cleared]; a capacity can be set, after which the
+/// oldest distribution is forgotten whenever a new one is computed.
+///
+/// [cleared]: DistributionCache::clear
+///
+/// # Examples
+///
+/// ```
+/// use tomb::stats::{DistributionCache, Expression};
+///
+/// let mut cache = DistributionCache::new();
+/// let attack = Expression::dice(2, 6);
+///
+/// assert_eq!(cache.get(attack).mean(), 7.0);
+/// assert_eq!(cache.get(attack).max(), 12);
+/// assert_eq!((cache.hits(), cache.misses()), (1, 1));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DistributionCache {
+    entries: HashMap<Expression, Distribution>,
+    order: VecDeque<Expression>,
+    capacity: Option<usize>,
+    hits: usize,
+    misses: usize,
+}
+
+impl DistributionCache {
+    /// Creates an empty cache without a capacity.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty cache that keeps at most `capacity` distributions.
+    ///
+    /// # Panics
+    ///
+    /// If the capacity is `0`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0);
+        Self {
+            capacity: Some(capacity),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the maximum number of distributions kept, or `None` if unlimited.
+    pub const fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Changes the maximum number of distributions kept, forgetting the oldest if over capacity.
+    ///
+    /// # Panics
+    ///
+    /// If the capacity is `Some(0)`.
+    pub fn set_capacity(&mut self, capacity: Option<usize>) {
+        assert_ne!(capacity, Some(0));
+        self.capacity = capacity;
+        self.evict(0);
+    }
+
+    /// Returns the distribution of the expression, computing it only if not already cached.
+    ///
+    /// # Panics
+    ///
+    /// If the die has no sides.
+    pub fn get(&mut self, expression: Expression) -> &Distribution {
+        if self.entries.contains_key(&expression) {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+            self.evict(1);
+            self.entries.insert(expression, expression.distribution());
+            self.order.push_back(expression);
+        }
+        &self.entries[&expression]
+    }
+
+    /// Returns whether the expression is cached.
+    pub fn contains(&self, expression: &Expression) -> bool {
+        self.entries.contains_key(expression)
+    }
+
+    /// Returns how many distributions are cached.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether no distributions are cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns how many times a cached distribution was returned.
+    pub const fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Returns how many times a distribution was computed.
+    pub const fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Forgets every cached distribution, without resetting the hit and miss counts.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    /// Forgets the oldest distributions until there is room for `additional` within capacity.
+    fn evict(&mut self, additional: usize) {
+        let capacity = self.capacity.unwrap_or(usize::MAX);
+        while self.entries.len() + additional > capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expression_normalizes() {
+        assert_eq!(Expression::keep_highest(3, 6, 3), Expression::dice(3, 6));
+        assert_eq!(Expression::keep_highest(3, 6, 0), Expression::dice(0, 6));
+        assert_eq!(
+            Expression::drop_highest(4, 6, 1),
+            Expression::keep_lowest(4, 6, 3)
+        );
+        assert_eq!(Expression::exploding(6, 0), Expression::dice(1, 6));
+    }
+
+    #[test]
+    fn expression_distribution() {
+        assert_eq!(
+            Expression::drop_lowest(4, 6, 1).distribution(),
+            Distribution::drop_lowest(4, 6, 1)
+        );
+        assert_eq!(
+            Expression::keep_lowest(2, 20, 1).distribution(),
+            Distribution::keep_lowest(2, 20, 1)
+        );
+        assert_eq!(
+            Expression::exploding(6, 2).distribution(),
+            Distribution::exploding(6, 2)
+        );
+    }
+
+    #[test]
+    fn distribution_cache_shares_normalized_entries() {
+        let mut cache = DistributionCache::new();
+        cache.get(Expression::drop_lowest(4, 6, 1));
+        cache.get(Expression::keep_highest(4, 6, 3));
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+    }
+
+    #[test]
+    fn distribution_cache_shares_no_dice() {
+        let mut cache = DistributionCache::new();
+        for expression in [
+            Expression::dice(0, 4),
+            Expression::dice(0, 20),
+            Expression::keep_highest(3, 6, 0),
+            Expression::keep_lowest(0, 8, 2),
+            Expression::drop_lowest(2, 12, 5),
+        ] {
+            assert_eq!(cache.get(expression), &Distribution::constant(0));
+        }
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!((cache.hits(), cache.misses()), (4, 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn expression_no_sides() {
+        Expression::dice(0, 0).distribution();
+    }
+
+    #[test]
+    fn distribution_cache_capacity() {
+        let mut cache = DistributionCache::with_capacity(2);
+        cache.get(Expression::dice(1, 4));
+        cache.get(Expression::dice(1, 6));
+        cache.get(Expression::dice(1, 8));
+
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.contains(&Expression::dice(1, 4)));
+        assert!(cache.contains(&Expression::dice(1, 8)));
+
+        cache.set_capacity(Some(1));
+        assert!(cache.contains(&Expression::dice(1, 8)));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn distribution_cache_zero_capacity() {
+        DistributionCache::with_capacity(0);
+    }
+
+    #[test]
+    fn distribution_cache_clear() {
+        let mut cache = DistributionCache::new();
+        cache.get(Expression::dice(2, 6));
+        cache.clear();
+
+        assert!(cache.is_empty());
+        assert_eq!(cache.misses(), 1);
+    }
+}
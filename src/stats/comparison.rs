@@ -0,0 +1,203 @@
+use std::fmt::Display;
+
+use super::Distribution;
+
+/// A single total in a [`Comparison`], with the probabilities of each compared distribution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComparisonRow {
+    total: i64,
+    probabilities: Vec<f64>,
+    at_least: Vec<f64>,
+}
+
+impl ComparisonRow {
+    /// Returns the total.
+    pub const fn total(&self) -> i64 {
+        self.total
+    }
+
+    /// Returns the probability of exactly this total, for each distribution in order.
+    pub fn probabilities(&self) -> &[f64] {
+        &self.probabilities
+    }
+
+    /// Returns the probability of at least this total, for each distribution in order.
+    pub fn at_least(&self) -> &[f64] {
+        &self.at_least
+    }
+}
+
+/// Compares the distributions of several named dice expressions side by side, as in _AnyDice_.
+///
+/// The comparison can either be printed as a text table, or read as structured rows (one per
+/// total, from the lowest to the highest total of any distribution).
+///
+/// # Examples
+///
+/// ```
+/// use tomb::stats::{Comparison, Distribution};
+///
+/// let comparison = Comparison::new()
+///     .with("1d20", Distribution::die(20))
+///     .with("3d6+1", Distribution::dice(3, 6) + 1);
+///
+/// assert_eq!(comparison.names().collect::<Vec<_>>(), ["1d20", "3d6+1"]);
+/// assert_eq!(comparison.means(), [10.5, 11.5]);
+/// assert_eq!(comparison.rows().len(), 20);
+/// println!("{comparison}");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Comparison {
+    columns: Vec<(String, Distribution)>,
+}
+
+impl Comparison {
+    /// Creates an empty comparison.
+    pub const fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+        }
+    }
+
+    /// Returns the comparison with another named distribution added.
+    pub fn with(mut self, name: impl Into<String>, distribution: Distribution) -> Self {
+        self.push(name, distribution);
+        self
+    }
+
+    /// Adds another named distribution.
+    pub fn push(&mut self, name: impl Into<String>, distribution: Distribution) {
+        self.columns.push((name.into(), distribution));
+    }
+
+    /// Returns the name of each distribution, in order.
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.columns.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Returns each distribution, in order.
+    pub fn distributions(&self) -> impl Iterator<Item = &Distribution> + '_ {
+        self.columns.iter().map(|(_, d)| d)
+    }
+
+    /// Returns the mean of each distribution, in order.
+    pub fn means(&self) -> Vec<f64> {
+        self.distributions().map(|d| d.mean()).collect()
+    }
+
+    /// Returns the standard deviation of each distribution, in order.
+    pub fn std_devs(&self) -> Vec<f64> {
+        self.distributions().map(|d| d.std_dev()).collect()
+    }
+
+    /// Returns one row per total, from the lowest to the highest total of any distribution.
+    pub fn rows(&self) -> Vec<ComparisonRow> {
+        let min = self.distributions().map(|d| d.min()).min();
+        let max = self.distributions().map(|d| d.max()).max();
+        let (Some(min), Some(max)) = (min, max) else {
+            return Vec::new();
+        };
+        (min..=max)
+            .map(|total| ComparisonRow {
+                total,
+                probabilities: self.distributions().map(|d| d.p(total)).collect(),
+                at_least: self.distributions().map(|d| d.at_least(total)).collect(),
+            })
+            .collect()
+    }
+}
+
+impl Display for Comparison {
+    /// Renders a text table with a column of probabilities (and at least probabilities) per name.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self.rows();
+        let total_width = rows
+            .iter()
+            .map(|r| r.total.to_string().len())
+            .chain(["total".len()])
+            .max()
+            .unwrap_or_default();
+        let widths: Vec<usize> = self.names().map(|n| (n.len() + 2).max(7)).collect();
+
+        write!(f, "{:>total_width$}", "total")?;
+        for (name, width) in self.names().zip(&widths) {
+            write!(f, " | {name:>width$} | {:>width$}", format!("{name} ≥"))?;
+        }
+        writeln!(f)?;
+        for row in &rows {
+            write!(f, "{:>total_width$}", row.total)?;
+            for ((p, at_least), width) in row.probabilities.iter().zip(&row.at_least).zip(&widths) {
+                let (p, at_least) = (format!("{:.2}%", p * 100.0), at_least * 100.0);
+                write!(f, " | {p:>width$} | {:>width$}", format!("{at_least:.2}%"))?;
+            }
+            writeln!(f)?;
+        }
+        write!(f, "{:>total_width$}", "mean")?;
+        for (mean, width) in self.means().iter().zip(&widths) {
+            write!(f, " | {:>width$} | {:>width$}", format!("{mean:.2}"), "")?;
+        }
+        writeln!(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn comparison_empty() {
+        let comparison = Comparison::default();
+
+        assert!(comparison.rows().is_empty());
+        assert!(comparison.means().is_empty());
+        assert_eq!(comparison.to_string(), "total\n mean\n");
+    }
+
+    #[test]
+    fn comparison_rows() {
+        let comparison = Comparison::new()
+            .with("1d4", Distribution::die(4))
+            .with("1d2+2", Distribution::die(2) + 2);
+        let rows = comparison.rows();
+
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0].total(), 1);
+        assert_near(rows[0].probabilities()[0], 0.25);
+        assert_near(rows[0].probabilities()[1], 0.0);
+        assert_near(rows[0].at_least()[1], 1.0);
+        assert_eq!(rows[3].total(), 4);
+        assert_near(rows[3].probabilities()[1], 0.5);
+        assert_near(rows[3].at_least()[0], 0.25);
+    }
+
+    #[test]
+    fn comparison_statistics() {
+        let mut comparison = Comparison::new();
+        comparison.push("1d6", Distribution::die(6));
+        comparison.push("2", Distribution::constant(2));
+
+        assert_eq!(comparison.means(), [3.5, 2.0]);
+        assert_near(comparison.std_devs()[1], 0.0);
+    }
+
+    #[test]
+    fn comparison_display() {
+        let comparison = Comparison::new().with("1d2", Distribution::die(2));
+
+        assert_eq!(
+            comparison.to_string(),
+            [
+                "total |     1d2 |   1d2 ≥",
+                "    1 |  50.00% | 100.00%",
+                "    2 |  50.00% |  50.00%",
+                " mean |    1.50 |        ",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use super::Distribution;
+
+/// A number that can exactly count dice outcomes, with overflow detection where applicable.
+///
+/// Implemented for `u64` and `u128`, and for `num_bigint::BigUint` with the `num-bigint` feature,
+/// which never overflows.
+pub trait Count
+where
+    Self: Clone + PartialEq + Sized,
+{
+    /// Returns the equivalent of `0`.
+    fn zero() -> Self;
+
+    /// Returns the equivalent of `1`.
+    fn one() -> Self;
+
+    /// Returns the sum of both numbers, or `None` if the result overflowed.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    /// Returns the difference of both numbers, or `None` if the result overflowed.
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+
+    /// Returns the ratio of both numbers, i.e. `self / total`.
+    fn ratio(&self, total: &Self) -> f64;
+}
+
+macro_rules! count {
+    ($name:ident) => {
+        impl Count for $name {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                $name::checked_add(*self, *other)
+            }
+
+            fn checked_sub(&self, other: &Self) -> Option<Self> {
+                $name::checked_sub(*self, *other)
+            }
+
+            fn ratio(&self, total: &Self) -> f64 {
+                *self as f64 / *total as f64
+            }
+        }
+    };
+}
+
+count!(u64);
+count!(u128);
+
+#[cfg(feature = "num-bigint")]
+impl Count for num_bigint::BigUint {
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn one() -> Self {
+        Self::from(1_u8)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        (self >= other).then(|| self - other)
+    }
+
+    fn ratio(&self, total: &Self) -> f64 {
+        // Keep (at most) the 64 most significant bits of each number, and scale by the difference.
+        fn split(n: &num_bigint::BigUint) -> (f64, i32) {
+            let shift = n.bits().saturating_sub(64);
+            let high = (n >> shift).iter_u64_digits().next().unwrap_or_default();
+            (high as f64, shift as i32)
+        }
+        let (count, count_shift) = split(self);
+        let (total, total_shift) = split(total);
+        count / total * 2.0_f64.powi(count_shift - total_shift)
+    }
+}
+
+/// The exact number of outcomes that produce every possible total of a dice expression.
+///
+/// Unlike a [`Distribution`], which uses floating-point probabilities, counts are exact integers;
+/// the number of outcomes grows quickly (i.e. `100d6` has `6^100` outcomes), so either a type with
+/// enough capacity or an arbitrary precision integer is required.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::stats::Counts;
+///
+/// let counts = Counts::<u64>::dice(3, 6).unwrap();
+///
+/// assert_eq!(counts.count(10), 27);
+/// assert_eq!(counts.total(), &216);
+/// assert!(Counts::<u128>::dice(100, 6).is_none());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Counts<N> {
+    min: i64,
+    counts: Vec<N>,
+    total: N,
+}
+
+impl<N> Counts<N>
+where
+    N: Count,
+{
+    /// Counts the outcomes of the sum of `count` dice with the given sides, i.e. `NdX`.
+    ///
+    /// Each die is added using a sliding window over the previous counts, which is `O(n·m)` where
+    /// `n` is the number of dice and `m` is the number of possible totals. Returns `None` if any
+    /// count overflowed.
+    ///
+    /// # Panics
+    ///
+    /// If the die has no sides.
+    pub fn dice(count: usize, sides: usize) -> Option<Self> {
+        assert!(sides > 0);
+        let mut counts = vec![N::one()];
+        for _ in 0..count {
+            let mut next = Vec::with_capacity(counts.len() + sides - 1);
+            let mut window = N::zero();
+            for total in 0..(counts.len() + sides - 1) {
+                if let Some(entering) = counts.get(total) {
+                    window = window.checked_add(entering)?;
+                }
+                if let Some(leaving) = total.checked_sub(sides).and_then(|i| counts.get(i)) {
+                    window = window.checked_sub(leaving)?;
+                }
+                next.push(window.clone());
+            }
+            counts = next;
+        }
+        let total = counts
+            .iter()
+            .try_fold(N::zero(), |total, count| total.checked_add(count))?;
+        Some(Self {
+            min: count as i64,
+            counts,
+            total,
+        })
+    }
+
+    /// Returns the lowest possible total.
+    pub const fn min(&self) -> i64 {
+        self.min
+    }
+
+    /// Returns the highest possible total.
+    pub fn max(&self) -> i64 {
+        self.min + self.counts.len() as i64 - 1
+    }
+
+    /// Returns how many outcomes produce exactly the given total.
+    pub fn count(&self, total: i64) -> N {
+        usize::try_from(total - self.min)
+            .ok()
+            .and_then(|i| self.counts.get(i))
+            .cloned()
+            .unwrap_or_else(N::zero)
+    }
+
+    /// Returns how many outcomes are possible.
+    pub const fn total(&self) -> &N {
+        &self.total
+    }
+
+    /// Returns every possible total, from lowest to highest, and the number of outcomes of each.
+    pub fn iter(&self) -> impl Iterator<Item = (i64, &N)> + '_ {
+        (self.min..).zip(self.counts.iter())
+    }
+
+    /// Returns the (floating-point) probability distribution of the counted outcomes.
+    pub fn to_distribution(&self) -> Distribution {
+        let probabilities = self.counts.iter().map(|c| c.ratio(&self.total)).collect();
+        Distribution::from_probabilities(self.min, probabilities)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_no_dice() {
+        let counts = Counts::<u64>::dice(0, 6).unwrap();
+
+        assert_eq!((counts.min(), counts.max()), (0, 0));
+        assert_eq!(counts.count(0), 1);
+        assert_eq!(counts.total(), &1);
+    }
+
+    #[test]
+    fn counts_single_die() {
+        let counts = Counts::<u64>::dice(1, 4).unwrap();
+
+        assert_eq!((counts.min(), counts.max()), (1, 4));
+        assert_eq!(counts.iter().map(|(_, &c)| c).collect::<Vec<_>>(), [1; 4]);
+    }
+
+    #[test]
+    fn counts_two_dice() {
+        let counts = Counts::<u64>::dice(2, 6).unwrap();
+
+        assert_eq!((counts.min(), counts.max()), (2, 12));
+        assert_eq!(
+            counts.iter().map(|(_, &c)| c).collect::<Vec<_>>(),
+            [1, 2, 3, 4, 5, 6, 5, 4, 3, 2, 1]
+        );
+        assert_eq!(counts.count(1), 0);
+        assert_eq!(counts.count(13), 0);
+        assert_eq!(counts.total(), &36);
+    }
+
+    #[test]
+    fn counts_matches_distribution() {
+        let counts = Counts::<u128>::dice(10, 6).unwrap();
+        let dist = Distribution::dice(10, 6);
+
+        assert_eq!(counts.to_distribution().min(), dist.min());
+        for (total, p) in counts.to_distribution().iter() {
+            assert!((p - dist.p(total)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn counts_overflow() {
+        assert!(Counts::<u64>::dice(30, 6).is_none());
+        assert!(Counts::<u128>::dice(49, 6).is_some());
+        assert!(Counts::<u128>::dice(50, 6).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn counts_no_sides() {
+        let _ = Counts::<u64>::dice(1, 0);
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn counts_big() {
+        use num_bigint::BigUint;
+
+        let counts = Counts::<BigUint>::dice(100, 6).unwrap();
+        let dist = counts.to_distribution();
+
+        assert_eq!(counts.total(), &BigUint::from(6_u8).pow(100));
+        assert_eq!(counts.count(100), BigUint::from(1_u8));
+        assert_eq!(counts.count(101), BigUint::from(100_u8));
+        assert!((dist.mean() - 350.0).abs() < 1e-9);
+        assert!((dist.p(600) - 6.0_f64.powi(-100)).abs() < 1e-90);
+    }
+}
@@ -0,0 +1,753 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// The exact probability of every possible total of a dice expression.
+///
+/// Distributions are computed without simulation, and are composed using the constructors (i.e.
+/// [`Distribution::dice`] or [`Distribution::keep_highest`]) and arithmetic operators, where adding
+/// two distributions is the distribution of the sum of both (independent) expressions.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::stats::Distribution;
+///
+/// // 4d6, dropping the lowest die.
+/// let dist = Distribution::drop_lowest(4, 6, 1);
+///
+/// assert_eq!(dist.min(), 3);
+/// assert_eq!(dist.max(), 18);
+/// assert!((dist.mean() - 12.2446).abs() < 1e-4);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "DistributionRepr")
+)]
+pub struct Distribution {
+    min: i64,
+    probabilities: Vec<f64>,
+}
+
+impl Distribution {
+    /// Creates a distribution that is always the given value.
+    pub fn constant(value: i64) -> Self {
+        Self {
+            min: value,
+            probabilities: vec![1.0],
+        }
+    }
+
+    /// Creates the distribution of a single die with the given number of sides, i.e. `1dX`.
+    ///
+    /// # Panics
+    ///
+    /// If the die has no sides.
+    pub fn die(sides: usize) -> Self {
+        assert!(sides > 0);
+        Self {
+            min: 1,
+            probabilities: vec![1.0 / sides as f64; sides],
+        }
+    }
+
+    /// Creates the distribution of an event that happens with probability `p`, where the event is
+    /// `1`, and otherwise `0`, i.e. a coin that lands on heads with probability `p`.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not within `0.0..=1.0`.
+    pub fn bernoulli(p: f64) -> Self {
+        assert!((0.0..=1.0).contains(&p));
+        Self {
+            min: 0,
+            probabilities: vec![1.0 - p, p],
+        }
+    }
+
+    /// Creates the distribution of how many of `count` independent events happen, where each event
+    /// happens with probability `p`, i.e. how many of `count` coins land on heads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::stats::Distribution;
+    ///
+    /// // 3 fair coins.
+    /// let dist = Distribution::binomial(3, 0.5);
+    ///
+    /// assert_eq!(dist.p(2), 0.375);
+    /// assert_eq!(dist.mean(), 1.5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not within `0.0..=1.0`.
+    pub fn binomial(count: usize, p: f64) -> Self {
+        Self::bernoulli(p).repeat(count)
+    }
+
+    /// Creates the distribution of the sum of `count` dice with the given sides, i.e. `NdX`.
+    ///
+    /// # Panics
+    ///
+    /// If the die has no sides.
+    pub fn dice(count: usize, sides: usize) -> Self {
+        Self::die(sides).repeat(count)
+    }
+
+    /// Creates the distribution of the sum of the highest `keep` of `count` dice, i.e. `NdXkhK`.
+    ///
+    /// If `keep` is greater than `count`, every die is kept.
+    ///
+    /// # Panics
+    ///
+    /// If the die has no sides.
+    pub fn keep_highest(count: usize, sides: usize, keep: usize) -> Self {
+        assert!(sides > 0);
+        let keep = keep.min(count);
+
+        // Assign dice to faces from the highest face to the lowest, where the first `keep` dice
+        // assigned are the kept dice; `table[assigned][sum]` is the probability of each state.
+        let mut table = vec![vec![0.0; keep * sides + 1]; count + 1];
+        table[0][0] = 1.0;
+        for face in (1..=sides).rev() {
+            let mut next = vec![vec![0.0; keep * sides + 1]; count + 1];
+            for (assigned, sums) in table.iter().enumerate() {
+                for (sum, &p) in sums.iter().enumerate() {
+                    if p == 0.0 {
+                        continue;
+                    }
+                    let mut weight = p;
+                    for showing in 0..=(count - assigned) {
+                        if showing > 0 {
+                            weight *= (count - assigned - showing + 1) as f64 / showing as f64;
+                            weight /= sides as f64;
+                        }
+                        let kept = (assigned + showing).min(keep) - assigned.min(keep);
+                        next[assigned + showing][sum + kept * face] += weight;
+                    }
+                }
+            }
+            table = next;
+        }
+        let probabilities = table.pop().unwrap_or_default();
+        Self::from_probabilities(0, probabilities)
+    }
+
+    /// Creates the distribution of the sum of the lowest `keep` of `count` dice, i.e. `NdXklK`.
+    ///
+    /// If `keep` is greater than `count`, every die is kept.
+    ///
+    /// # Panics
+    ///
+    /// If the die has no sides.
+    pub fn keep_lowest(count: usize, sides: usize, keep: usize) -> Self {
+        // Mirroring every face (i.e. `1` is `X`) turns the lowest dice into the highest dice.
+        let keep = keep.min(count);
+        -Self::keep_highest(count, sides, keep) + (keep * (sides + 1)) as i64
+    }
+
+    /// Creates the distribution of `count` dice, dropping the lowest `drop` dice, i.e. `NdXdlD`.
+    ///
+    /// # Panics
+    ///
+    /// If the die has no sides.
+    pub fn drop_lowest(count: usize, sides: usize, drop: usize) -> Self {
+        Self::keep_highest(count, sides, count.saturating_sub(drop))
+    }
+
+    /// Creates the distribution of `count` dice, dropping the highest `drop` dice, i.e. `NdXdhD`.
+    ///
+    /// # Panics
+    ///
+    /// If the die has no sides.
+    pub fn drop_highest(count: usize, sides: usize, drop: usize) -> Self {
+        Self::keep_lowest(count, sides, count.saturating_sub(drop))
+    }
+
+    /// Creates the distribution of a single exploding die, i.e. `1dX!`.
+    ///
+    /// Every time the highest face is rolled, the die is rolled again and added to the total, up to
+    /// `cap` additional rolls; the final additional roll is never rerolled.
+    ///
+    /// # Panics
+    ///
+    /// If the die has no sides.
+    pub fn exploding(sides: usize, cap: usize) -> Self {
+        assert!(sides > 0);
+        let mut probabilities = vec![0.0; (cap + 1) * sides];
+        let mut p = 1.0;
+        for depth in 0..=cap {
+            p /= sides as f64;
+            let faces = if depth == cap { sides } else { sides - 1 };
+            for face in 0..faces {
+                probabilities[depth * sides + face] += p;
+            }
+        }
+        Self::from_probabilities(1, probabilities)
+    }
+
+    /// Returns the distribution of the sum of `count` independent copies of this distribution.
+    ///
+    /// A count of `0` is the distribution of a constant `0`.
+    pub fn repeat(&self, count: usize) -> Self {
+        let mut result = Self::constant(0);
+        let mut base = self.clone();
+        let mut count = count;
+        while count > 0 {
+            if count & 1 == 1 {
+                result = result.convolve(&base);
+            }
+            count >>= 1;
+            if count > 0 {
+                base = base.convolve(&base);
+            }
+        }
+        result
+    }
+
+    /// Returns the lowest possible total.
+    pub const fn min(&self) -> i64 {
+        self.min
+    }
+
+    /// Returns the highest possible total.
+    pub fn max(&self) -> i64 {
+        self.min + self.probabilities.len() as i64 - 1
+    }
+
+    /// Returns the probability, between `0.0..=1.0`, of exactly the given total.
+    pub fn p(&self, total: i64) -> f64 {
+        usize::try_from(total - self.min)
+            .ok()
+            .and_then(|i| self.probabilities.get(i))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the probability, between `0.0..=1.0`, of a total at most the given total.
+    pub fn cdf(&self, total: i64) -> f64 {
+        self.iter()
+            .take_while(|&(t, _)| t <= total)
+            .map(|(_, p)| p)
+            .sum::<f64>()
+            .min(1.0)
+    }
+
+    /// Returns the probability, between `0.0..=1.0`, of a total at least the given total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::stats::Distribution;
+    ///
+    /// // What's the chance of 15+ on 3d6+2?
+    /// let dist = Distribution::dice(3, 6) + 2;
+    /// assert!((dist.at_least(15) - 56.0 / 216.0).abs() < 1e-12);
+    /// ```
+    pub fn at_least(&self, total: i64) -> f64 {
+        self.iter()
+            .skip_while(|&(t, _)| t < total)
+            .map(|(_, p)| p)
+            .sum::<f64>()
+            .min(1.0)
+    }
+
+    /// Returns the lowest total where the probability of at most that total is at least `p`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::stats::Distribution;
+    ///
+    /// // What total is the 90th percentile of 3d6+2?
+    /// let dist = Distribution::dice(3, 6) + 2;
+    /// assert_eq!(dist.percentile(0.9), 16);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not between `0.0..=1.0`.
+    pub fn percentile(&self, p: f64) -> i64 {
+        // Tolerate rounding errors when summing, otherwise `1.0` might never be reached.
+        const EPSILON: f64 = 1e-12;
+        assert!((0.0..=1.0).contains(&p));
+        let mut cumulative = 0.0;
+        for (total, probability) in self.iter() {
+            cumulative += probability;
+            if cumulative >= p - EPSILON {
+                return total;
+            }
+        }
+        self.max()
+    }
+
+    /// Returns the expected value, or mean, of the distribution.
+    pub fn mean(&self) -> f64 {
+        self.iter().map(|(total, p)| total as f64 * p).sum()
+    }
+
+    /// Returns the variance of the distribution.
+    pub fn variance(&self) -> f64 {
+        let mean = self.mean();
+        self.iter()
+            .map(|(total, p)| (total as f64 - mean).powi(2) * p)
+            .sum()
+    }
+
+    /// Returns the standard deviation of the distribution.
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Returns every possible total, from lowest to highest, and the probability of each.
+    pub fn iter(&self) -> impl Iterator<Item = (i64, f64)> + '_ {
+        (self.min..).zip(self.probabilities.iter().copied())
+    }
+
+    pub(crate) fn from_probabilities(min: i64, mut probabilities: Vec<f64>) -> Self {
+        let leading = probabilities.iter().take_while(|&&p| p == 0.0).count();
+        let trailing = probabilities
+            .iter()
+            .rev()
+            .take_while(|&&p| p == 0.0)
+            .count();
+        if leading == probabilities.len() {
+            return Self::constant(min);
+        }
+        probabilities.truncate(probabilities.len() - trailing);
+        probabilities.drain(..leading);
+        Self {
+            min: min + leading as i64,
+            probabilities,
+        }
+    }
+
+    fn convolve(&self, other: &Self) -> Self {
+        let mut probabilities = vec![0.0; self.probabilities.len() + other.probabilities.len() - 1];
+        for (i, &a) in self.probabilities.iter().enumerate() {
+            for (j, &b) in other.probabilities.iter().enumerate() {
+                probabilities[i + j] += a * b;
+            }
+        }
+        Self {
+            min: self.min + other.min,
+            probabilities,
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Distribution {
+    /// Creates the distribution of an expression of up to `8` dice of up to `20` sides, plus a
+    /// constant modifier, i.e. `3d6+2`.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let count = u.int_in_range(0..=8)?;
+        let sides = u.int_in_range(1..=20)?;
+        let modifier = u.int_in_range(-20..=20)?;
+        Ok(Self::dice(count, sides) + modifier)
+    }
+}
+
+impl Add for Distribution {
+    type Output = Distribution;
+
+    /// Returns the distribution of the sum of both distributions.
+    fn add(self, rhs: Distribution) -> Self::Output {
+        self.convolve(&rhs)
+    }
+}
+
+impl Add<i64> for Distribution {
+    type Output = Distribution;
+
+    /// Returns the distribution with every total increased by the given modifier.
+    fn add(self, rhs: i64) -> Self::Output {
+        Self {
+            min: self.min + rhs,
+            probabilities: self.probabilities,
+        }
+    }
+}
+
+impl Sub for Distribution {
+    type Output = Distribution;
+
+    /// Returns the distribution of the difference of both distributions.
+    fn sub(self, rhs: Distribution) -> Self::Output {
+        self.convolve(&-rhs)
+    }
+}
+
+impl Sub<i64> for Distribution {
+    type Output = Distribution;
+
+    /// Returns the distribution with every total decreased by the given modifier.
+    fn sub(self, rhs: i64) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul<i64> for Distribution {
+    type Output = Distribution;
+
+    /// Returns the distribution with every total multiplied by the given factor.
+    fn mul(self, rhs: i64) -> Self::Output {
+        if rhs < 0 {
+            return -(self * -rhs);
+        }
+        if rhs == 0 {
+            return Self::constant(0);
+        }
+        let step = rhs as usize;
+        let mut probabilities = vec![0.0; (self.probabilities.len() - 1) * step + 1];
+        for (i, &p) in self.probabilities.iter().enumerate() {
+            probabilities[i * step] = p;
+        }
+        Self {
+            min: self.min * rhs,
+            probabilities,
+        }
+    }
+}
+
+impl Neg for Distribution {
+    type Output = Distribution;
+
+    /// Returns the distribution with every total negated.
+    fn neg(self) -> Self::Output {
+        let min = -self.max();
+        let mut probabilities = self.probabilities;
+        probabilities.reverse();
+        Self { min, probabilities }
+    }
+}
+
+/// The serialized form of a [`Distribution`], which is validated when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct DistributionRepr {
+    min: i64,
+    probabilities: Vec<f64>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DistributionRepr> for Distribution {
+    type Error = crate::Error;
+
+    /// Creates a distribution from the serialized fields, failing if there are no totals, or the
+    /// largest total would overflow.
+    fn try_from(repr: DistributionRepr) -> Result<Self, Self::Error> {
+        let len = repr.probabilities.len();
+        let max_len = usize::try_from(i64::MAX.abs_diff(repr.min))
+            .unwrap_or(usize::MAX)
+            .saturating_add(1);
+        if len == 0 || len > max_len {
+            return Err(crate::Error::OutOfRange {
+                value: len,
+                min: 1,
+                max: max_len,
+            });
+        }
+        Ok(Self {
+            min: repr.min,
+            probabilities: repr.probabilities,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    fn assert_sums_to_one(dist: &Distribution) {
+        assert_near(dist.iter().map(|(_, p)| p).sum(), 1.0);
+    }
+
+    #[test]
+    fn distribution_constant() {
+        let dist = Distribution::constant(5);
+
+        assert_eq!(dist.min(), 5);
+        assert_eq!(dist.max(), 5);
+        assert_near(dist.p(5), 1.0);
+        assert_near(dist.mean(), 5.0);
+        assert_near(dist.variance(), 0.0);
+    }
+
+    #[test]
+    fn distribution_die() {
+        let dist = Distribution::die(6);
+
+        assert_eq!(dist.min(), 1);
+        assert_eq!(dist.max(), 6);
+        assert_near(dist.p(3), 1.0 / 6.0);
+        assert_near(dist.p(0), 0.0);
+        assert_near(dist.p(7), 0.0);
+        assert_near(dist.mean(), 3.5);
+        assert_near(dist.variance(), 35.0 / 12.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn distribution_die_no_sides() {
+        let _ = Distribution::die(0);
+    }
+
+    #[test]
+    fn distribution_bernoulli() {
+        let dist = Distribution::bernoulli(0.25);
+
+        assert_eq!((dist.min(), dist.max()), (0, 1));
+        assert_eq!(dist.mean(), 0.25);
+        assert_eq!(dist.variance(), 0.1875);
+    }
+
+    #[test]
+    #[should_panic]
+    fn distribution_bernoulli_out_of_range() {
+        Distribution::bernoulli(1.5);
+    }
+
+    #[test]
+    fn distribution_binomial() {
+        let dist = Distribution::binomial(10, 0.3);
+
+        assert_eq!((dist.min(), dist.max()), (0, 10));
+        assert!((dist.mean() - 3.0).abs() < 1e-12);
+        assert!((dist.variance() - 2.1).abs() < 1e-12);
+        assert_eq!(Distribution::binomial(0, 0.3), Distribution::constant(0));
+    }
+
+    #[test]
+    fn distribution_dice() {
+        let dist = Distribution::dice(2, 6);
+
+        assert_eq!(dist.min(), 2);
+        assert_eq!(dist.max(), 12);
+        assert_near(dist.p(7), 6.0 / 36.0);
+        assert_near(dist.p(2), 1.0 / 36.0);
+        assert_near(dist.mean(), 7.0);
+        assert_near(dist.variance(), 35.0 / 6.0);
+        assert_sums_to_one(&dist);
+    }
+
+    #[test]
+    fn distribution_dice_none() {
+        let dist = Distribution::dice(0, 6);
+
+        assert_eq!(dist, Distribution::constant(0));
+    }
+
+    #[test]
+    fn distribution_repeat_matches_addition() {
+        let repeated = Distribution::die(4).repeat(3);
+        let added = Distribution::die(4) + Distribution::die(4) + Distribution::die(4);
+
+        assert_eq!(repeated.min(), added.min());
+        for (total, p) in repeated.iter() {
+            assert_near(p, added.p(total));
+        }
+    }
+
+    #[test]
+    fn distribution_modifiers() {
+        let dist = Distribution::die(6) + 2;
+        assert_eq!((dist.min(), dist.max()), (3, 8));
+
+        let dist = Distribution::die(6) - 2;
+        assert_eq!((dist.min(), dist.max()), (-1, 4));
+        assert_near(dist.mean(), 1.5);
+    }
+
+    #[test]
+    fn distribution_negate_and_subtract() {
+        let dist = -Distribution::die(4);
+        assert_eq!((dist.min(), dist.max()), (-4, -1));
+
+        let dist = Distribution::die(6) - Distribution::die(6);
+        assert_eq!((dist.min(), dist.max()), (-5, 5));
+        assert_near(dist.p(0), 6.0 / 36.0);
+        assert_near(dist.mean(), 0.0);
+    }
+
+    #[test]
+    fn distribution_cdf() {
+        let dist = Distribution::dice(2, 6);
+
+        assert_near(dist.cdf(1), 0.0);
+        assert_near(dist.cdf(2), 1.0 / 36.0);
+        assert_near(dist.cdf(7), 21.0 / 36.0);
+        assert_near(dist.cdf(12), 1.0);
+        assert_near(dist.cdf(100), 1.0);
+    }
+
+    #[test]
+    fn distribution_at_least() {
+        let dist = Distribution::dice(2, 6);
+
+        assert_near(dist.at_least(-5), 1.0);
+        assert_near(dist.at_least(2), 1.0);
+        assert_near(dist.at_least(7), 21.0 / 36.0);
+        assert_near(dist.at_least(12), 1.0 / 36.0);
+        assert_near(dist.at_least(13), 0.0);
+    }
+
+    #[test]
+    fn distribution_percentile() {
+        let dist = Distribution::dice(2, 6);
+
+        assert_eq!(dist.percentile(0.0), 2);
+        assert_eq!(dist.percentile(0.5), 7);
+        assert_eq!(dist.percentile(21.0 / 36.0), 7);
+        assert_eq!(dist.percentile(0.99), 12);
+        assert_eq!(dist.percentile(1.0), 12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn distribution_percentile_out_of_range() {
+        Distribution::die(6).percentile(1.5);
+    }
+
+    #[test]
+    #[allow(clippy::erasing_op)]
+    fn distribution_multiply() {
+        let dist = Distribution::die(3) * 10;
+        assert_eq!((dist.min(), dist.max()), (10, 30));
+        assert_near(dist.p(20), 1.0 / 3.0);
+        assert_near(dist.p(15), 0.0);
+
+        let dist = Distribution::die(3) * -2;
+        assert_eq!((dist.min(), dist.max()), (-6, -2));
+        assert_near(dist.p(-4), 1.0 / 3.0);
+        assert_near(dist.mean(), -4.0);
+
+        let dist = Distribution::die(3) * 0;
+        assert_eq!(dist, Distribution::constant(0));
+    }
+
+    #[test]
+    fn distribution_std_dev() {
+        let dist = Distribution::die(6);
+
+        assert_near(dist.std_dev(), (35.0_f64 / 12.0).sqrt());
+    }
+
+    #[test]
+    fn distribution_keep_highest() {
+        // 2d20, keep highest (advantage).
+        let dist = Distribution::keep_highest(2, 20, 1);
+
+        assert_eq!((dist.min(), dist.max()), (1, 20));
+        assert_near(dist.p(1), 1.0 / 400.0);
+        assert_near(dist.p(20), 39.0 / 400.0);
+        assert_near(dist.mean(), 13.825);
+        assert_sums_to_one(&dist);
+    }
+
+    #[test]
+    fn distribution_keep_lowest() {
+        // 2d20, keep lowest (disadvantage).
+        let dist = Distribution::keep_lowest(2, 20, 1);
+
+        assert_eq!((dist.min(), dist.max()), (1, 20));
+        assert_near(dist.p(1), 39.0 / 400.0);
+        assert_near(dist.p(20), 1.0 / 400.0);
+        assert_near(dist.mean(), 7.175);
+    }
+
+    #[test]
+    fn distribution_keep_all() {
+        let kept = Distribution::keep_highest(3, 6, 5);
+        let all = Distribution::dice(3, 6);
+
+        for (total, p) in all.iter() {
+            assert_near(kept.p(total), p);
+        }
+    }
+
+    #[test]
+    fn distribution_drop_lowest() {
+        let dist = Distribution::drop_lowest(4, 6, 1);
+
+        assert_eq!((dist.min(), dist.max()), (3, 18));
+        assert_near(dist.p(18), 21.0 / 1296.0);
+        assert_near(dist.p(3), 1.0 / 1296.0);
+        assert_near(dist.mean(), 15869.0 / 1296.0);
+        assert_sums_to_one(&dist);
+    }
+
+    #[test]
+    fn distribution_drop_highest() {
+        let dist = Distribution::drop_highest(4, 6, 1);
+
+        assert_eq!((dist.min(), dist.max()), (3, 18));
+        assert_near(dist.p(3), 21.0 / 1296.0);
+        assert_near(dist.p(18), 1.0 / 1296.0);
+    }
+
+    #[test]
+    fn distribution_exploding() {
+        let dist = Distribution::exploding(6, 2);
+
+        assert_eq!((dist.min(), dist.max()), (1, 18));
+        assert_near(dist.p(5), 1.0 / 6.0);
+        assert_near(dist.p(6), 0.0);
+        assert_near(dist.p(7), 1.0 / 36.0);
+        assert_near(dist.p(12), 0.0);
+        assert_near(dist.p(18), 1.0 / 216.0);
+        assert_sums_to_one(&dist);
+    }
+
+    #[test]
+    fn distribution_exploding_no_cap() {
+        let dist = Distribution::exploding(6, 0);
+
+        assert_eq!(dist, Distribution::die(6));
+    }
+
+    #[test]
+    fn distribution_exploding_one_side() {
+        let dist = Distribution::exploding(1, 3);
+
+        assert_eq!(dist, Distribution::constant(4));
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn distribution_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let dist = Distribution::arbitrary(&mut Unstructured::new(&[3, 5, 22])).unwrap();
+        assert!((dist.iter().map(|(_, p)| p).sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn distribution_serde() {
+        let dist = Distribution::dice(2, 6);
+        let json = serde_json::to_string(&dist).unwrap();
+
+        assert_eq!(serde_json::from_str::<Distribution>(&json).unwrap(), dist);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn distribution_serde_invalid() {
+        for json in [
+            r#"{"min":1,"probabilities":[]}"#,
+            r#"{"min":9223372036854775807,"probabilities":[0.5,0.5]}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<Distribution>(json).is_err(),
+                "{json}"
+            );
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::traits::{Critical, Positional, Roll, Rotate};
+
+/// The result of a chi-squared goodness-of-fit test against a uniform (fair) die.
+///
+/// A low p-value (i.e. below `0.01`) is evidence that the die or roller is biased; a fair roller
+/// will still occasionally produce a low p-value, so repeat suspicious tests with more trials.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{D6, RngRoller};
+/// use tomb::stats::ChiSquared;
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let test = ChiSquared::test(&roller, &D6::new(), 6000);
+///
+/// assert_eq!(test.degrees_of_freedom(), 5);
+/// assert!(test.is_fair(0.01));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChiSquared {
+    statistic: f64,
+    degrees_of_freedom: usize,
+}
+
+impl ChiSquared {
+    /// Rolls the die using the provided roller `trials` times, and tests the observed faces.
+    pub fn test<R, D>(roller: &R, die: &D, trials: usize) -> Self
+    where
+        R: Roll,
+        D: Critical + Positional + Rotate,
+    {
+        let mut observed = vec![0; D::sides()];
+        for _ in 0..trials {
+            observed[roller.roll(die).natural() - 1] += 1;
+        }
+        Self::from_observed(&observed)
+    }
+
+    /// Tests how many times each face was observed against a uniform expectation.
+    ///
+    /// # Panics
+    ///
+    /// If fewer than two faces are provided.
+    pub fn from_observed(observed: &[usize]) -> Self {
+        assert!(observed.len() > 1);
+        let total: usize = observed.iter().sum();
+        let expected = total as f64 / observed.len() as f64;
+        let statistic = if total == 0 {
+            0.0
+        } else {
+            observed
+                .iter()
+                .map(|&o| (o as f64 - expected).powi(2) / expected)
+                .sum()
+        };
+        Self {
+            statistic,
+            degrees_of_freedom: observed.len() - 1,
+        }
+    }
+
+    /// Returns the chi-squared statistic.
+    pub const fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    /// Returns the degrees of freedom, or one less than the number of faces.
+    pub const fn degrees_of_freedom(&self) -> usize {
+        self.degrees_of_freedom
+    }
+
+    /// Returns the probability of a statistic at least this extreme from a fair die.
+    pub fn p_value(&self) -> f64 {
+        upper_regularized_gamma(self.degrees_of_freedom as f64 / 2.0, self.statistic / 2.0)
+    }
+
+    /// Returns whether the p-value is at or above the given significance level, i.e. `0.01`.
+    pub fn is_fair(&self, significance: f64) -> bool {
+        self.p_value() >= significance
+    }
+}
+
+/// Returns the natural logarithm of the gamma function, using the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let sum = COEFFICIENTS[1..]
+        .iter()
+        .zip(1..)
+        .fold(COEFFICIENTS[0], |sum, (c, i)| sum + c / (x + i as f64));
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// Returns the regularized upper incomplete gamma function, `Q(a, x)`.
+fn upper_regularized_gamma(a: f64, x: f64) -> f64 {
+    const EPSILON: f64 = 1e-15;
+    const ITERATIONS: usize = 1000;
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let prefix = (-x + a * x.ln() - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        // Series representation of the lower function, P(a, x).
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..ITERATIONS {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        (1.0 - sum * prefix).clamp(0.0, 1.0)
+    } else {
+        // Continued fraction representation (modified Lentz's method).
+        let tiny = f64::MIN_POSITIVE / EPSILON;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..ITERATIONS {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < EPSILON {
+                break;
+            }
+        }
+        (prefix * h).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{NopRoller, D6};
+
+    fn assert_near(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{a} != {b}");
+    }
+
+    #[test]
+    fn ln_gamma_known_values() {
+        assert_near(ln_gamma(1.0), 0.0);
+        assert_near(ln_gamma(5.0), 24.0_f64.ln());
+        assert_near(ln_gamma(0.5), std::f64::consts::PI.sqrt().ln());
+    }
+
+    #[test]
+    fn upper_regularized_gamma_known_values() {
+        // Critical values of the chi-squared distribution at a significance of 0.05.
+        assert_near(upper_regularized_gamma(0.5, 3.841_459 / 2.0), 0.05);
+        assert_near(upper_regularized_gamma(2.5, 11.070_498 / 2.0), 0.05);
+        assert_near(upper_regularized_gamma(9.5, 30.143_527 / 2.0), 0.05);
+        assert_near(upper_regularized_gamma(2.5, 0.0), 1.0);
+    }
+
+    #[test]
+    fn chi_squared_uniform() {
+        let test = ChiSquared::from_observed(&[10, 10, 10, 10, 10, 10]);
+
+        assert_near(test.statistic(), 0.0);
+        assert_near(test.p_value(), 1.0);
+        assert!(test.is_fair(0.05));
+    }
+
+    #[test]
+    fn chi_squared_biased() {
+        let test = ChiSquared::from_observed(&[20, 5, 5, 5, 5, 20]);
+
+        assert_near(test.statistic(), 30.0);
+        assert_eq!(test.degrees_of_freedom(), 5);
+        assert!(test.p_value() < 0.001);
+        assert!(!test.is_fair(0.01));
+    }
+
+    #[test]
+    fn chi_squared_no_observations() {
+        let test = ChiSquared::from_observed(&[0, 0]);
+
+        assert_near(test.statistic(), 0.0);
+        assert!(test.is_fair(0.05));
+    }
+
+    #[test]
+    #[should_panic]
+    fn chi_squared_single_face() {
+        ChiSquared::from_observed(&[10]);
+    }
+
+    #[test]
+    fn chi_squared_detects_nop_roller() {
+        let test = ChiSquared::test(&NopRoller, &D6::new(), 600);
+
+        assert_near(test.statistic(), 3000.0);
+        assert!(!test.is_fair(0.01));
+    }
+}
@@ -0,0 +1,392 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write};
+use std::io;
+
+/// Tallies roll outcomes, either per value or grouped into bins of equal width.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::stats::Histogram;
+///
+/// let histogram: Histogram = [1, 3, 3, 2, 3].into_iter().collect();
+///
+/// assert_eq!(histogram.count(3), 3);
+/// assert_eq!(histogram.total(), 5);
+/// assert_eq!(
+///     histogram.render(6),
+///     "1 | ##     1\n2 | ##     1\n3 | ###### 3\n",
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "HistogramRepr")
+)]
+pub struct Histogram {
+    width: i64,
+    bins: BTreeMap<i64, usize>,
+}
+
+impl Histogram {
+    /// Creates an empty histogram that tallies each value separately.
+    pub fn new() -> Self {
+        Self::with_bin_width(1)
+    }
+
+    /// Creates an empty histogram that tallies values in bins of the given width.
+    ///
+    /// Bins start at multiples of the width, i.e. with a width of `5`, `0..=4` and `5..=9`.
+    ///
+    /// # Panics
+    ///
+    /// If the width is not positive.
+    pub fn with_bin_width(width: i64) -> Self {
+        assert!(width > 0);
+        Self {
+            width,
+            bins: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the width of each bin.
+    pub const fn bin_width(&self) -> i64 {
+        self.width
+    }
+
+    /// Tallies a single value.
+    pub fn record(&mut self, value: i64) {
+        *self.bins.entry(self.bin(value)).or_insert(0) += 1;
+    }
+
+    /// Returns how many values were tallied in the bin containing the given value.
+    pub fn count(&self, value: i64) -> usize {
+        self.bins.get(&self.bin(value)).copied().unwrap_or_default()
+    }
+
+    /// Returns the fraction, between `0.0..=1.0`, of values tallied in the bin containing the value.
+    pub fn frequency(&self, value: i64) -> f64 {
+        match self.total() {
+            0 => 0.0,
+            total => self.count(value) as f64 / total as f64,
+        }
+    }
+
+    /// Returns how many values were tallied in total.
+    pub fn total(&self) -> usize {
+        self.bins.values().sum()
+    }
+
+    /// Returns whether no values were tallied.
+    pub fn is_empty(&self) -> bool {
+        self.bins.is_empty()
+    }
+
+    /// Returns the start of every bin with at least one value, in order, and its count.
+    pub fn bins(&self) -> impl Iterator<Item = (i64, usize)> + '_ {
+        self.bins.iter().map(|(&start, &count)| (start, count))
+    }
+
+    /// Adds every value tallied by another histogram into this histogram.
+    ///
+    /// # Panics
+    ///
+    /// If the histograms have different bin widths.
+    pub fn merge(&mut self, other: &Histogram) {
+        assert_eq!(self.width, other.width);
+        for (&start, &count) in &other.bins {
+            *self.bins.entry(start).or_insert(0) += count;
+        }
+    }
+
+    /// Renders a text bar chart, with one line per bin, where the longest bar is `width` long.
+    ///
+    /// Empty bins between the lowest and highest bins are included.
+    pub fn render(&self, width: usize) -> String {
+        let (Some((&first, _)), Some((&last, _))) =
+            (self.bins.first_key_value(), self.bins.last_key_value())
+        else {
+            return String::new();
+        };
+        let most = self.bins.values().copied().max().unwrap_or_default();
+        let rows: Vec<(String, usize)> = (first..=last)
+            .step_by(self.width as usize)
+            .map(|start| (self.label(start), self.count(start)))
+            .collect();
+        let label_width = rows.iter().map(|(l, _)| l.len()).max().unwrap_or_default();
+        let mut output = String::new();
+        for (label, count) in rows {
+            let bar = "#".repeat((count * width).div_ceil(most));
+            let _ = writeln!(output, "{label:>label_width$} | {bar:<width$} {count}");
+        }
+        output
+    }
+
+    /// Writes the histogram as CSV, with a header row and then one row per bin, in order.
+    ///
+    /// Like [`Histogram::render`], empty bins between the lowest and highest bins are included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::stats::Histogram;
+    ///
+    /// let histogram: Histogram = [1, 3, 3, 3].into_iter().collect();
+    ///
+    /// let mut csv = Vec::new();
+    /// histogram.write_csv(&mut csv).unwrap();
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(csv).unwrap(),
+    ///     "start,end,count,frequency\n1,1,1,0.25\n2,2,0,0\n3,3,3,0.75\n",
+    /// );
+    /// ```
+    pub fn write_csv<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "start,end,count,frequency")?;
+        let (Some((&first, _)), Some((&last, _))) =
+            (self.bins.first_key_value(), self.bins.last_key_value())
+        else {
+            return Ok(());
+        };
+        for start in (first..=last).step_by(self.width as usize) {
+            writeln!(
+                writer,
+                "{},{},{},{}",
+                start,
+                start + self.width - 1,
+                self.count(start),
+                self.frequency(start)
+            )?;
+        }
+        Ok(())
+    }
+
+    fn bin(&self, value: i64) -> i64 {
+        value.div_euclid(self.width) * self.width
+    }
+
+    fn label(&self, start: i64) -> String {
+        if self.width == 1 {
+            start.to_string()
+        } else {
+            format!("{}-{}", start, start + self.width - 1)
+        }
+    }
+}
+
+/// The serialized form of a [`Histogram`], which is validated when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct HistogramRepr {
+    width: i64,
+    bins: BTreeMap<i64, usize>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<HistogramRepr> for Histogram {
+    type Error = crate::Error;
+
+    /// Creates a histogram from the serialized fields, failing if the width is not positive, a bin
+    /// does not start at a multiple of the width, a bin is empty, or the total would overflow.
+    fn try_from(repr: HistogramRepr) -> Result<Self, Self::Error> {
+        if repr.width <= 0 {
+            return Err(crate::Error::OutOfRange {
+                value: 0,
+                min: 1,
+                max: i64::MAX as usize,
+            });
+        }
+        let mut total = 0usize;
+        for (&start, &count) in &repr.bins {
+            let offset = start.rem_euclid(repr.width);
+            if offset != 0 {
+                return Err(crate::Error::OutOfRange {
+                    value: offset as usize,
+                    min: 0,
+                    max: 0,
+                });
+            }
+            total =
+                total
+                    .checked_add(count)
+                    .filter(|_| count > 0)
+                    .ok_or(crate::Error::OutOfRange {
+                        value: count,
+                        min: 1,
+                        max: usize::MAX - total,
+                    })?;
+        }
+        Ok(Self {
+            width: repr.width,
+            bins: repr.bins,
+        })
+    }
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for Histogram {
+    /// Renders a text bar chart where the longest bar is `40` long.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(40))
+    }
+}
+
+impl Extend<i64> for Histogram {
+    fn extend<I: IntoIterator<Item = i64>>(&mut self, iter: I) {
+        for value in iter {
+            self.record(value);
+        }
+    }
+}
+
+impl FromIterator<i64> for Histogram {
+    fn from_iter<I: IntoIterator<Item = i64>>(iter: I) -> Self {
+        let mut histogram = Self::new();
+        histogram.extend(iter);
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_default() {
+        let histogram: Histogram = Default::default();
+
+        assert!(histogram.is_empty());
+        assert_eq!(histogram.bin_width(), 1);
+        assert_eq!(histogram.total(), 0);
+        assert_eq!(histogram.frequency(1), 0.0);
+        assert_eq!(histogram.render(10), "");
+    }
+
+    #[test]
+    #[should_panic]
+    fn histogram_invalid_width() {
+        Histogram::with_bin_width(0);
+    }
+
+    #[test]
+    fn histogram_record() {
+        let mut histogram = Histogram::new();
+        histogram.record(4);
+        histogram.record(4);
+        histogram.record(-1);
+
+        assert_eq!(histogram.count(4), 2);
+        assert_eq!(histogram.count(-1), 1);
+        assert_eq!(histogram.count(0), 0);
+        assert_eq!(histogram.frequency(4), 2.0 / 3.0);
+        assert_eq!(histogram.bins().collect::<Vec<_>>(), vec![(-1, 1), (4, 2)]);
+    }
+
+    #[test]
+    fn histogram_binned() {
+        let mut histogram = Histogram::with_bin_width(5);
+        histogram.extend([0, 4, 5, 9, 10, -1]);
+
+        assert_eq!(histogram.count(2), 2);
+        assert_eq!(histogram.count(7), 2);
+        assert_eq!(histogram.count(10), 1);
+        assert_eq!(histogram.count(-5), 1);
+        assert_eq!(
+            histogram.bins().collect::<Vec<_>>(),
+            vec![(-5, 1), (0, 2), (5, 2), (10, 1)]
+        );
+    }
+
+    #[test]
+    fn histogram_merge() {
+        let mut a: Histogram = [1, 2].into_iter().collect();
+        let b: Histogram = [2, 3].into_iter().collect();
+        a.merge(&b);
+
+        assert_eq!(a.total(), 4);
+        assert_eq!(a.count(2), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn histogram_merge_different_widths() {
+        let mut a = Histogram::new();
+        a.merge(&Histogram::with_bin_width(2));
+    }
+
+    #[test]
+    fn histogram_render_gaps() {
+        let histogram: Histogram = [8, 10, 10, 10, 10].into_iter().collect();
+
+        assert_eq!(
+            histogram.render(4),
+            " 8 | #    1\n 9 |      0\n10 | #### 4\n"
+        );
+    }
+
+    #[test]
+    fn histogram_render_binned() {
+        let mut histogram = Histogram::with_bin_width(10);
+        histogram.extend([1, 2, 15]);
+
+        assert_eq!(histogram.render(2), "  0-9 | ## 2\n10-19 | #  1\n");
+    }
+
+    #[test]
+    fn histogram_write_csv() {
+        let mut histogram = Histogram::with_bin_width(5);
+        histogram.extend([-1, 7]);
+        let mut csv = Vec::new();
+        histogram.write_csv(&mut csv).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "start,end,count,frequency\n-5,-1,1,0.5\n0,4,0,0\n5,9,1,0.5\n"
+        );
+    }
+
+    #[test]
+    fn histogram_write_csv_empty() {
+        let mut csv = Vec::new();
+        Histogram::new().write_csv(&mut csv).unwrap();
+
+        assert_eq!(csv, b"start,end,count,frequency\n");
+    }
+
+    #[test]
+    fn histogram_display() {
+        let histogram: Histogram = [1].into_iter().collect();
+
+        assert_eq!(histogram.to_string(), format!("1 | {} 1\n", "#".repeat(40)));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn histogram_serde() {
+        let mut histogram = Histogram::with_bin_width(5);
+        histogram.extend([-1, 7, 8]);
+        let json = serde_json::to_string(&histogram).unwrap();
+
+        assert_eq!(serde_json::from_str::<Histogram>(&json).unwrap(), histogram);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn histogram_serde_invalid() {
+        for json in [
+            r#"{"width":0,"bins":{}}"#,
+            r#"{"width":-5,"bins":{"-5":1}}"#,
+            r#"{"width":5,"bins":{"3":1}}"#,
+            r#"{"width":1,"bins":{"3":0}}"#,
+            r#"{"width":1,"bins":{"1":18446744073709551615,"2":1}}"#,
+        ] {
+            assert!(serde_json::from_str::<Histogram>(json).is_err(), "{json}");
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use std::io;
+
+use crate::traits::Critical;
+
+/// A single roll recorded in a [`RollLog`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RollRecordRepr")
+)]
+pub struct RollRecord {
+    label: String,
+    sides: usize,
+    natural: usize,
+}
+
+impl RollRecord {
+    /// Creates a record of a die with the given number of sides landing on a natural face.
+    ///
+    /// # Panics
+    ///
+    /// If the natural face is not within `1..=sides`.
+    pub fn new(label: impl Into<String>, sides: usize, natural: usize) -> Self {
+        let record = Self {
+            label: label.into(),
+            sides,
+            natural,
+        };
+        assert!(record.is_valid());
+        record
+    }
+
+    /// Returns what the roll was for, i.e. `"attack"`.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns how many sides the rolled die has.
+    pub const fn sides(&self) -> usize {
+        self.sides
+    }
+
+    /// Returns the natural face, between `1..=sides`, the die landed on.
+    pub const fn natural(&self) -> usize {
+        self.natural
+    }
+
+    const fn is_valid(&self) -> bool {
+        self.natural >= 1 && self.natural <= self.sides
+    }
+}
+
+/// The serialized form of a [`RollRecord`], which is validated when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RollRecordRepr {
+    label: String,
+    sides: usize,
+    natural: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RollRecordRepr> for RollRecord {
+    type Error = crate::Error;
+
+    /// Creates a record from the serialized fields, failing if the natural face is impossible.
+    fn try_from(repr: RollRecordRepr) -> Result<Self, Self::Error> {
+        let record = Self {
+            label: repr.label,
+            sides: repr.sides,
+            natural: repr.natural,
+        };
+        if record.is_valid() {
+            Ok(record)
+        } else {
+            Err(crate::Error::OutOfRange {
+                value: record.natural,
+                min: 1,
+                max: record.sides,
+            })
+        }
+    }
+}
+
+/// An ordered history of rolls, which can be exported and imported to replay or audit the rolls.
+///
+/// With the `json` feature, the log is exported as a versioned JSON document:
+///
+/// ```json
+/// {"records":[{"label":"attack","natural":17,"sides":20}],"version":1}
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{D20, D6};
+/// use tomb::stats::RollLog;
+///
+/// let mut log = RollLog::new();
+/// log.record("attack", &D20::from(17));
+/// log.record("damage", &D6::from(4));
+///
+/// assert_eq!(log.len(), 2);
+/// assert_eq!(log.records()[0].natural(), 17);
+/// assert_eq!(log.labeled("damage").count(), 1);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollLog {
+    records: Vec<RollRecord>,
+}
+
+impl RollLog {
+    /// The version of the exported JSON document.
+    pub const VERSION: u32 = 1;
+
+    /// Creates an empty log.
+    pub const fn new() -> Self {
+        Self {
+            records: Vec::new(),
+        }
+    }
+
+    /// Records the face a die landed on.
+    pub fn record<D: Critical>(&mut self, label: impl Into<String>, die: &D) {
+        self.push(RollRecord::new(label, D::sides(), die.natural()));
+    }
+
+    /// Adds a record to the end of the log.
+    pub fn push(&mut self, record: RollRecord) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            label = record.label(),
+            sides = record.sides(),
+            natural = record.natural(),
+            "record roll"
+        );
+        self.records.push(record);
+    }
+
+    /// Returns every record, in the order they were recorded.
+    pub fn records(&self) -> &[RollRecord] {
+        &self.records
+    }
+
+    /// Returns every record with the given label, in the order they were recorded.
+    pub fn labeled<'a>(&'a self, label: &'a str) -> impl Iterator<Item = &'a RollRecord> + 'a {
+        self.records.iter().filter(move |r| r.label == label)
+    }
+
+    /// Returns how many rolls were recorded.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns whether no rolls were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Writes the log as CSV, with a header row and then one row per roll, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::items::D20;
+    /// use tomb::stats::RollLog;
+    ///
+    /// let mut log = RollLog::new();
+    /// log.record("attack, with advantage", &D20::from(17));
+    ///
+    /// let mut csv = Vec::new();
+    /// log.write_csv(&mut csv).unwrap();
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(csv).unwrap(),
+    ///     "index,label,sides,natural\n0,\"attack, with advantage\",20,17\n",
+    /// );
+    /// ```
+    pub fn write_csv<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "index,label,sides,natural")?;
+        for (index, record) in self.records.iter().enumerate() {
+            write!(writer, "{index},")?;
+            if record.label.contains([',', '"', '\n', '\r']) {
+                write!(writer, "\"{}\"", record.label.replace('"', "\"\""))?;
+            } else {
+                writer.write_all(record.label.as_bytes())?;
+            }
+            writeln!(writer, ",{},{}", record.sides, record.natural)?;
+        }
+        Ok(())
+    }
+
+    /// Exports the log as a versioned JSON document.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> String {
+        serde_json::json!({
+            "version": Self::VERSION,
+            "records": self.records,
+        })
+        .to_string()
+    }
+
+    /// Imports a log from a versioned JSON document.
+    ///
+    /// Fails if the document is malformed, is of an unsupported version, or has a record with a
+    /// natural face that is impossible for the die.
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> Result<Self, RollLogError> {
+        let document: serde_json::Value = serde_json::from_str(json)?;
+        match document.get("version").and_then(serde_json::Value::as_u64) {
+            Some(version) if version == u64::from(Self::VERSION) => {}
+            version => return Err(RollLogError::UnsupportedVersion(version)),
+        }
+        let records: Vec<RollRecordRepr> = serde_json::from_value(document["records"].clone())?;
+        let records = records
+            .into_iter()
+            .enumerate()
+            .map(|(index, r)| {
+                RollRecord::try_from(r).map_err(|_| RollLogError::InvalidRecord(index))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { records })
+    }
+}
+
+impl Extend<RollRecord> for RollLog {
+    fn extend<I: IntoIterator<Item = RollRecord>>(&mut self, iter: I) {
+        self.records.extend(iter);
+    }
+}
+
+impl FromIterator<RollRecord> for RollLog {
+    fn from_iter<I: IntoIterator<Item = RollRecord>>(iter: I) -> Self {
+        Self {
+            records: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// An error that occurred importing a [`RollLog`].
+#[cfg(feature = "json")]
+#[derive(Debug)]
+pub enum RollLogError {
+    /// The document is not valid JSON, or does not match the schema.
+    Json(serde_json::Error),
+
+    /// The document's version is missing or not supported.
+    UnsupportedVersion(Option<u64>),
+
+    /// The record at the index has a natural face that is impossible for the die.
+    InvalidRecord(usize),
+}
+
+#[cfg(feature = "json")]
+impl std::fmt::Display for RollLogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json(error) => write!(f, "invalid roll log: {error}"),
+            Self::UnsupportedVersion(Some(version)) => {
+                write!(f, "unsupported roll log version {version}")
+            }
+            Self::UnsupportedVersion(None) => f.write_str("missing roll log version"),
+            Self::InvalidRecord(index) => write!(f, "invalid roll log record at {index}"),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl std::error::Error for RollLogError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for RollLogError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{D20, D6};
+
+    #[test]
+    fn roll_record_new() {
+        let record = RollRecord::new("attack", 20, 17);
+
+        assert_eq!(record.label(), "attack");
+        assert_eq!(record.sides(), 20);
+        assert_eq!(record.natural(), 17);
+    }
+
+    #[test]
+    #[should_panic]
+    fn roll_record_impossible() {
+        RollRecord::new("attack", 6, 7);
+    }
+
+    #[test]
+    fn roll_log_default() {
+        let log: RollLog = Default::default();
+
+        assert!(log.is_empty());
+        assert_eq!(log.len(), 0);
+    }
+
+    #[test]
+    fn roll_log_record() {
+        let mut log = RollLog::new();
+        log.record("attack", &D20::from(17));
+        log.record("damage", &D6::from(4));
+        log.record("attack", &D20::from(2));
+
+        assert_eq!(
+            log.labeled("attack")
+                .map(RollRecord::natural)
+                .collect::<Vec<_>>(),
+            vec![17, 2]
+        );
+        assert_eq!(log.records()[1], RollRecord::new("damage", 6, 4));
+    }
+
+    #[test]
+    fn roll_log_write_csv() {
+        let log: RollLog = [
+            RollRecord::new("attack", 20, 17),
+            RollRecord::new("say \"hi\"", 6, 1),
+        ]
+        .into_iter()
+        .collect();
+        let mut csv = Vec::new();
+        log.write_csv(&mut csv).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "index,label,sides,natural\n0,attack,20,17\n1,\"say \"\"hi\"\"\",6,1\n"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn roll_log_json() {
+        let log: RollLog = [RollRecord::new("attack", 20, 17)].into_iter().collect();
+        let json = log.to_json();
+
+        assert_eq!(
+            json,
+            r#"{"records":[{"label":"attack","natural":17,"sides":20}],"version":1}"#
+        );
+        assert_eq!(RollLog::from_json(&json).unwrap(), log);
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn roll_log_json_errors() {
+        assert!(matches!(
+            RollLog::from_json("{"),
+            Err(RollLogError::Json(_))
+        ));
+        assert!(matches!(
+            RollLog::from_json(r#"{"version":2,"records":[]}"#),
+            Err(RollLogError::UnsupportedVersion(Some(2)))
+        ));
+        assert!(matches!(
+            RollLog::from_json(r#"{"records":[]}"#),
+            Err(RollLogError::UnsupportedVersion(None))
+        ));
+        assert!(matches!(
+            RollLog::from_json(r#"{"version":1,"records":[{"label":"x","sides":6,"natural":7}]}"#),
+            Err(RollLogError::InvalidRecord(0))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn roll_record_serde_invalid() {
+        let json = r#"{"label":"x","sides":6,"natural":0}"#;
+
+        assert!(serde_json::from_str::<RollRecord>(json).is_err());
+        assert!(serde_json::from_str::<RollLog>(&format!(r#"{{"records":[{json}]}}"#)).is_err());
+    }
+}
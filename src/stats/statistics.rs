@@ -0,0 +1,219 @@
+use super::Distribution;
+use crate::items::{CompositeDie, NumericDie};
+use crate::mechanics::Check;
+use crate::traits::{Critical, Numeric, Rotate};
+
+/// A trait for elements with a known (exact) [`Distribution`] of totals.
+///
+/// Implemented for numeric dice, checks, pools (slices and arrays of dice), and distributions.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::D6;
+/// use tomb::stats::Statistics;
+///
+/// let pool = [D6::new(), D6::new(), D6::new()];
+///
+/// assert_eq!(D6::new().mean(), 3.5);
+/// assert_eq!(pool.mean(), 10.5);
+/// assert_eq!(pool.variance(), 8.75);
+/// ```
+pub trait Statistics {
+    /// Returns the exact distribution of every possible total.
+    fn distribution(&self) -> Distribution;
+
+    /// Returns the expected value, or mean.
+    fn mean(&self) -> f64 {
+        self.distribution().mean()
+    }
+
+    /// Returns the variance.
+    fn variance(&self) -> f64 {
+        self.distribution().variance()
+    }
+
+    /// Returns the standard deviation.
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl Statistics for Distribution {
+    fn distribution(&self) -> Distribution {
+        self.clone()
+    }
+}
+
+impl<T, const MAXIMUM: usize> Statistics for NumericDie<T, MAXIMUM>
+where
+    T: Numeric,
+{
+    fn distribution(&self) -> Distribution {
+        Distribution::die(MAXIMUM)
+    }
+
+    fn mean(&self) -> f64 {
+        (MAXIMUM as f64 + 1.0) / 2.0
+    }
+
+    fn variance(&self) -> f64 {
+        (MAXIMUM as f64 * MAXIMUM as f64 - 1.0) / 12.0
+    }
+}
+
+impl<const SIDES: usize> Statistics for CompositeDie<SIDES> {
+    fn distribution(&self) -> Distribution {
+        Distribution::die(SIDES) * 10 + Distribution::die(SIDES)
+    }
+}
+
+impl<D> Statistics for Check<D>
+where
+    D: Critical + Rotate + Statistics,
+{
+    fn distribution(&self) -> Distribution {
+        self.die().distribution() + self.modifier() as i64
+    }
+}
+
+impl<D> Statistics for [D]
+where
+    D: Statistics,
+{
+    fn distribution(&self) -> Distribution {
+        self.iter()
+            .fold(Distribution::constant(0), |sum, d| sum + d.distribution())
+    }
+
+    fn mean(&self) -> f64 {
+        self.iter().map(|d| d.mean()).sum()
+    }
+
+    fn variance(&self) -> f64 {
+        self.iter().map(|d| d.variance()).sum()
+    }
+}
+
+impl<D, const N: usize> Statistics for [D; N]
+where
+    D: Statistics,
+{
+    fn distribution(&self) -> Distribution {
+        self.as_slice().distribution()
+    }
+
+    fn mean(&self) -> f64 {
+        self.as_slice().mean()
+    }
+
+    fn variance(&self) -> f64 {
+        self.as_slice().variance()
+    }
+}
+
+/// Returns the probability that the total of an expression meets or beats a difficulty class.
+///
+/// Equivalent to `expr.distribution().at_least(dc)`.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{D20, D6};
+/// use tomb::mechanics::Check;
+/// use tomb::stats::probability_of_success;
+///
+/// // 1d20+5 against a DC of 15.
+/// let check = Check::new(D20::new(), 5);
+/// assert!((probability_of_success(&check, 15) - 0.55).abs() < 1e-12);
+///
+/// // 2d6 against a DC of 10.
+/// let pool = [D6::new(), D6::new()];
+/// assert!((probability_of_success(&pool, 10) - 6.0 / 36.0).abs() < 1e-12);
+/// ```
+pub fn probability_of_success<E>(expr: &E, dc: i64) -> f64
+where
+    E: Statistics + ?Sized,
+{
+    expr.distribution().at_least(dc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{D20, D6, D66};
+
+    fn assert_near(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn statistics_distribution() {
+        let dist = Distribution::dice(2, 6);
+
+        assert_eq!(Statistics::distribution(&dist), dist);
+        assert_near(Statistics::mean(&dist), 7.0);
+        assert_near(dist.std_dev(), (35.0_f64 / 6.0).sqrt());
+    }
+
+    #[test]
+    fn statistics_numeric_die() {
+        let d20 = D20::new();
+
+        assert_near(d20.mean(), 10.5);
+        assert_near(d20.variance(), 33.25);
+        assert_near(d20.mean(), d20.distribution().mean());
+        assert_near(d20.variance(), d20.distribution().variance());
+    }
+
+    #[test]
+    fn statistics_composite_die() {
+        let d66 = D66::new();
+        let dist = d66.distribution();
+
+        assert_eq!((dist.min(), dist.max()), (11, 66));
+        assert_near(dist.p(17), 0.0);
+        assert_near(dist.p(23), 1.0 / 36.0);
+        assert_near(d66.mean(), 38.5);
+    }
+
+    #[test]
+    fn statistics_check() {
+        let check = Check::new(D20::new(), 5);
+
+        assert_near(check.mean(), 15.5);
+        assert_near(check.variance(), 33.25);
+        assert_eq!(check.distribution().min(), 6);
+    }
+
+    #[test]
+    fn statistics_pool() {
+        let pool: &[D6] = &[D6::new(); 3];
+
+        assert_near(pool.mean(), 10.5);
+        assert_near(pool.variance(), 8.75);
+        assert_near(pool.std_dev(), 8.75_f64.sqrt());
+        assert_eq!(pool.distribution().max(), 18);
+    }
+
+    #[test]
+    fn statistics_empty_pool() {
+        let pool: [D6; 0] = [];
+
+        assert_near(pool.mean(), 0.0);
+        assert_eq!(pool.distribution(), Distribution::constant(0));
+    }
+
+    #[test]
+    fn statistics_probability_of_success() {
+        let check = Check::new(D20::new(), -2);
+
+        assert_near(probability_of_success(&check, 19), 0.0);
+        assert_near(probability_of_success(&check, 18), 0.05);
+        assert_near(probability_of_success(&check, -1), 1.0);
+        assert_near(
+            probability_of_success(&Distribution::dice(3, 6), 18),
+            1.0 / 216.0,
+        );
+    }
+}
@@ -0,0 +1,177 @@
+/// A run of consecutive rolls in a roll history that all satisfy some condition.
+///
+/// Useful for detecting unlikely sequences, such as several critical hits in a row, or for
+/// flagging a roller that produces sequences too unlikely to be fair.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::D20;
+/// use tomb::stats::Streak;
+/// use tomb::traits::Critical;
+///
+/// let history = [20, 3, 20, 20, 20, 11].map(D20::from);
+/// let streak = Streak::longest(&history, |d| d.is_critical()).unwrap();
+///
+/// assert_eq!(streak.start(), 2);
+/// assert_eq!(streak.len(), 3);
+///
+/// // The chance of at least 3 natural 20s in a row over 6 rolls is about 0.05%.
+/// let p = Streak::probability(3, history.len(), 1.0 / 20.0);
+/// assert!((p - 0.000_48).abs() < 0.000_01);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Streak {
+    start: usize,
+    len: usize,
+}
+
+impl Streak {
+    /// Returns every streak of the history (in order) where each roll satisfies the predicate.
+    ///
+    /// Each streak is as long as possible, i.e. streaks never overlap or touch.
+    pub fn find<T, F>(history: &[T], mut predicate: F) -> Vec<Streak>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut streaks = Vec::new();
+        let mut current: Option<Streak> = None;
+        for (index, roll) in history.iter().enumerate() {
+            match (predicate(roll), current.as_mut()) {
+                (true, Some(streak)) => streak.len += 1,
+                (true, None) => {
+                    current = Some(Streak {
+                        start: index,
+                        len: 1,
+                    })
+                }
+                (false, _) => streaks.extend(current.take()),
+            }
+        }
+        streaks.extend(current);
+        streaks
+    }
+
+    /// Returns the longest streak where each roll satisfies the predicate, if any.
+    ///
+    /// If there are multiple streaks of the same length, the first is returned.
+    pub fn longest<T, F>(history: &[T], predicate: F) -> Option<Streak>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        Self::find(history, predicate)
+            .into_iter()
+            .reduce(|a, b| if b.len > a.len { b } else { a })
+    }
+
+    /// Returns the probability of at least one streak of `len` or more over `trials` rolls.
+    ///
+    /// Each roll is independent and satisfies the condition with the probability `p`.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not within `0.0..=1.0`.
+    pub fn probability(len: usize, trials: usize, p: f64) -> f64 {
+        assert!((0.0..=1.0).contains(&p));
+        if len == 0 {
+            return 1.0;
+        }
+
+        // The probability of the current streak being a given length, without a streak of `len`.
+        let mut states = vec![0.0; len];
+        states[0] = 1.0;
+        let mut found = 0.0;
+        for _ in 0..trials {
+            let missed: f64 = states.iter().sum::<f64>() * (1.0 - p);
+            found += states[len - 1] * p;
+            states.copy_within(0..len - 1, 1);
+            states[1..].iter_mut().for_each(|s| *s *= p);
+            states[0] = missed;
+        }
+        found
+    }
+
+    /// Returns the index of the first roll in the streak.
+    pub const fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the index after the last roll in the streak.
+    pub const fn end(&self) -> usize {
+        self.start + self.len
+    }
+
+    /// Returns how many rolls are in the streak.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether there are no rolls in the streak, which is never true of a found streak.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn streak_find() {
+        let history = [1, 1, 0, 1, 0, 0, 1, 1, 1];
+        let streaks = Streak::find(&history, |&r| r == 1);
+
+        assert_eq!(
+            streaks,
+            vec![
+                Streak { start: 0, len: 2 },
+                Streak { start: 3, len: 1 },
+                Streak { start: 6, len: 3 },
+            ]
+        );
+        assert_eq!(streaks[2].end(), 9);
+        assert!(streaks.iter().all(|s| !s.is_empty()));
+    }
+
+    #[test]
+    fn streak_find_none() {
+        assert!(Streak::find(&[0, 0], |&r| r == 1).is_empty());
+        assert_eq!(Streak::longest(&[0, 0], |&r| r == 1), None);
+    }
+
+    #[test]
+    fn streak_longest_first() {
+        let history = [0, 1, 1, 0, 1, 1];
+
+        assert_eq!(
+            Streak::longest(&history, |&r| r == 1),
+            Some(Streak { start: 1, len: 2 })
+        );
+    }
+
+    #[test]
+    fn streak_probability() {
+        // 2 heads in a row over 3 flips: HHT, HHH, THH.
+        assert!((Streak::probability(2, 3, 0.5) - 3.0 / 8.0).abs() < 1e-12);
+
+        // A single success over several rolls.
+        assert!((Streak::probability(1, 3, 0.5) - 7.0 / 8.0).abs() < 1e-12);
+
+        // Every roll must succeed.
+        assert!((Streak::probability(4, 4, 0.5) - 1.0 / 16.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn streak_probability_edges() {
+        assert_eq!(Streak::probability(0, 0, 0.5), 1.0);
+        assert_eq!(Streak::probability(3, 2, 1.0), 0.0);
+        assert_eq!(Streak::probability(2, 5, 0.0), 0.0);
+        assert!((Streak::probability(2, 5, 1.0) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn streak_probability_invalid() {
+        Streak::probability(1, 1, 1.5);
+    }
+}
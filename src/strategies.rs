@@ -0,0 +1,126 @@
+//! Contains [`proptest`] strategies that generate valid dice, pools, and expressions.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use tomb::items::D6;
+//! use tomb::strategies::{numeric_die, pool};
+//!
+//! proptest! {
+//!     fn sum_is_within_range(dice in pool(numeric_die::<u8, 6>(), 1..=5)) {
+//!         let sum: u8 = dice.iter().map(D6::value).sum();
+//!         prop_assert!(sum >= dice.len() as u8 && sum <= dice.len() as u8 * 6);
+//!     }
+//! }
+//! # sum_is_within_range();
+//! ```
+
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+use proptest::collection::{vec, SizeRange};
+use proptest::prelude::*;
+
+use crate::items::{CompositeDie, DiceChain, DieStep, NumericDie};
+use crate::mechanics::Check;
+use crate::stats::Distribution;
+use crate::traits::{Critical, Numeric, Rotate};
+
+/// Returns a strategy that generates a die showing any value between `1..=MAXIMUM`.
+pub fn numeric_die<T, const MAXIMUM: usize>() -> impl Strategy<Value = NumericDie<T, MAXIMUM>>
+where
+    T: Numeric + Debug,
+{
+    (1..=MAXIMUM).prop_map(|value| NumericDie::from(T::from_usize(value)))
+}
+
+/// Returns a strategy that generates a composite die showing any outcome.
+///
+/// # Panics
+///
+/// If `SIDES` is greater than `9`.
+pub fn composite_die<const SIDES: usize>() -> impl Strategy<Value = CompositeDie<SIDES>> {
+    let faces = 1..=SIDES as u8;
+    (faces.clone(), faces).prop_map(|(tens, units)| CompositeDie::with_faces(tens, units))
+}
+
+/// Returns a strategy that generates any step of the standard die step chain.
+pub fn die_step() -> impl Strategy<Value = DieStep> {
+    proptest::sample::select(DieStep::CHAIN.to_vec())
+}
+
+/// Returns a strategy that generates any step of the extended dice chain.
+pub fn dice_chain() -> impl Strategy<Value = DiceChain> {
+    proptest::sample::select(DiceChain::CHAIN.to_vec())
+}
+
+/// Returns a strategy that generates a pool of dice, of a size within the given range.
+pub fn pool<S>(die: S, size: impl Into<SizeRange>) -> impl Strategy<Value = Vec<S::Value>>
+where
+    S: Strategy,
+{
+    vec(die, size)
+}
+
+/// Returns a strategy that generates a check of a die, with a modifier within the given range.
+pub fn check<S>(die: S, modifier: RangeInclusive<i32>) -> impl Strategy<Value = Check<S::Value>>
+where
+    S: Strategy,
+    S::Value: Critical + Rotate,
+{
+    (die, modifier).prop_map(|(die, modifier)| Check::new(die, modifier))
+}
+
+/// Returns a strategy that generates the distribution of an expression, i.e. `3d6+2`.
+///
+/// Expressions have up to `8` dice of up to `20` sides, and a modifier between `-20..=20`.
+pub fn distribution() -> impl Strategy<Value = Distribution> {
+    (0..=8usize, 1..=20usize, -20..=20i64)
+        .prop_map(|(count, sides, modifier)| Distribution::dice(count, sides) + modifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{D20, D66};
+    use crate::stats::Statistics;
+
+    proptest! {
+        #[test]
+        fn strategies_numeric_die(die in numeric_die::<u8, 20>()) {
+            prop_assert!((1..=20).contains(&die.value()));
+        }
+
+        #[test]
+        fn strategies_composite_die(die in composite_die::<6>()) {
+            prop_assert!(die.tens() <= 6 && die.units() <= 6);
+            prop_assert!(D66::new() <= die);
+        }
+
+        #[test]
+        fn strategies_steps(step in die_step(), chain in dice_chain()) {
+            prop_assert!(DieStep::CHAIN.contains(&step));
+            prop_assert!(DiceChain::CHAIN.contains(&chain));
+        }
+
+        #[test]
+        fn strategies_pool(dice in pool(numeric_die::<u8, 6>(), 2..5)) {
+            prop_assert!((2..5).contains(&dice.len()));
+        }
+
+        #[test]
+        fn strategies_check(check in check(numeric_die::<u8, 20>(), -5..=5)) {
+            let die: &D20 = check.die();
+            prop_assert_eq!(check.total(), die.value() as i32 + check.modifier());
+        }
+
+        #[test]
+        fn strategies_distribution(dist in distribution()) {
+            let total: f64 = dist.iter().map(|(_, p)| p).sum();
+            prop_assert!((total - 1.0).abs() < 1e-9);
+            prop_assert!(dist.mean() >= dist.min() as f64 - 1e-9);
+            prop_assert!(Statistics::mean(&dist) <= dist.max() as f64 + 1e-9);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Contains random tables, which pick an entry (i.e. loot, or an encounter) by rolling.
+//!
+//! A [`RandomTable`] is a list of weighted entries, where each entry is as likely as its weight,
+//! and rolling returns both the entry and the roll that picked it, so the roll can be shown. A
+//! [`TableSet`] holds named tables whose entries may roll on other tables, or roll dice, and a
+//! [`RangeTable`] is keyed by ranges of a dice total (i.e. `01-05`), like most printed tables.
+//!
+//! # Examples
+//!
+//! ```
+//! use fastrand::Rng;
+//! use tomb::items::RngRoller;
+//! use tomb::tables::RandomTable;
+//!
+//! let table = RandomTable::new()
+//!     .with("goblins", 3)
+//!     .with("wolves", 2)
+//!     .with("a dragon", 1);
+//!
+//! let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+//! let result = table.roll(&roller).unwrap();
+//!
+//! assert_eq!(result.value(), &"goblins");
+//! assert_eq!(result.roll(), 3);
+//! ```
+
+mod nested;
+mod random;
+mod range;
+
+pub use nested::*;
+pub use random::*;
+pub use range::*;
@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+
+use super::RandomTable;
+use crate::mechanics::{Notation, NotationRoll};
+use crate::traits::{Roll, SideSelector};
+use crate::Error;
+
+/// An entry of a table in a [`TableSet`], which may refer to other tables or roll dice.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Outcome<T> {
+    /// A value, which resolves to itself.
+    Value(T),
+
+    /// Dice, i.e. `3d6` gold pieces, which resolve to a roll.
+    Dice(Notation),
+
+    /// Another table in the same set, by name, which resolves to a roll on that table.
+    Table(String),
+
+    /// Another outcome, resolved as many times as the total of the dice.
+    Repeat(Notation, Box<Outcome<T>>),
+}
+
+impl<T> Outcome<T> {
+    /// Returns an outcome that rolls on the table with the given name.
+    pub fn table(name: impl Into<String>) -> Self {
+        Self::Table(name.into())
+    }
+
+    /// Returns an outcome that resolves the given outcome a fixed number of times.
+    pub fn times(count: usize, outcome: Self) -> Self {
+        Self::Repeat(Notation::new(count, 1), Box::new(outcome))
+    }
+
+    /// Returns an outcome that resolves the given outcome as many times as the total of the dice.
+    ///
+    /// A total below `1` resolves the outcome no times.
+    pub fn repeat(notation: Notation, outcome: Self) -> Self {
+        Self::Repeat(notation, Box::new(outcome))
+    }
+
+    /// Calls the function with the name of every table this outcome refers to, directly.
+    fn visit_tables<'a>(&'a self, f: &mut impl FnMut(&'a str)) {
+        match self {
+            Self::Value(_) | Self::Dice(_) => {}
+            Self::Table(name) => f(name),
+            Self::Repeat(_, outcome) => outcome.visit_tables(f),
+        }
+    }
+}
+
+/// A resolved outcome of rolling on a [`TableSet`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Resolved<'a, T> {
+    /// A value of an entry.
+    Value(&'a T),
+
+    /// Dice that were rolled.
+    Dice(NotationRoll),
+}
+
+/// A set of named [`RandomTable`]s, where entries may roll on other tables, or roll dice.
+///
+/// Published tables often refer to each other (i.e. "roll twice on the treasure table"), which a
+/// set encodes directly with [`Outcome`]s, and resolves recursively in a single call. Before
+/// rolling, every table that could be reached is checked, so a set where a table refers back to
+/// itself (directly or not) is an error, instead of possibly never finishing.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::RngRoller;
+/// use tomb::mechanics::Notation;
+/// use tomb::tables::{Outcome, RandomTable, Resolved, TableSet};
+///
+/// let mut set = TableSet::new();
+/// set.insert(
+///     "treasure",
+///     RandomTable::new()
+///         .with(Outcome::Value("a ruby"), 1)
+///         .with(Outcome::Dice(Notation::new(3, 6)), 1),
+/// );
+/// set.insert(
+///     "hoard",
+///     RandomTable::new().with(Outcome::times(2, Outcome::table("treasure")), 1),
+/// );
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let results = set.roll("hoard", &roller).unwrap();
+///
+/// assert_eq!(results.len(), 2);
+/// assert!(matches!(&results[0], Resolved::Dice(gold) if gold.total() == 9));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableSet<T> {
+    tables: HashMap<String, RandomTable<Outcome<T>>>,
+}
+
+impl<T> TableSet<T> {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self {
+            tables: HashMap::new(),
+        }
+    }
+
+    /// Adds a table with the given name, returning the table it replaced, if any.
+    ///
+    /// Tables may refer to tables that are not added yet.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        table: RandomTable<Outcome<T>>,
+    ) -> Option<RandomTable<Outcome<T>>> {
+        self.tables.insert(name.into(), table)
+    }
+
+    /// Returns the table with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&RandomTable<Outcome<T>>> {
+        self.tables.get(name)
+    }
+
+    /// Returns how many tables are in the set.
+    pub fn len(&self) -> usize {
+        self.tables.len()
+    }
+
+    /// Returns whether the set has no tables.
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    /// Checks that the named table, and every table it could roll on, exists and does not refer
+    /// back to itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if a table does not exist, or [`Error::Cycle`] if a table could
+    /// roll on itself.
+    pub fn validate(&self, name: &str) -> Result<(), Error> {
+        self.visit(name, &mut Vec::new(), &mut Vec::new())
+    }
+
+    /// Rolls on the named table, resolving every outcome, in order.
+    ///
+    /// Entries that refer to other tables are replaced by the result of rolling on those tables,
+    /// and tables with a total weight of `0` resolve to nothing.
+    ///
+    /// # Errors
+    ///
+    /// See [`TableSet::validate`].
+    pub fn roll<R>(&self, name: &str, roller: &R) -> Result<Vec<Resolved<'_, T>>, Error>
+    where
+        R: Roll + SideSelector,
+    {
+        self.validate(name)?;
+        let mut results = Vec::new();
+        self.roll_table(name, roller, &mut results);
+        Ok(results)
+    }
+
+    /// Visits the named table depth-first, where `path` are the tables currently being visited.
+    fn visit<'a>(
+        &'a self,
+        name: &'a str,
+        path: &mut Vec<&'a str>,
+        done: &mut Vec<&'a str>,
+    ) -> Result<(), Error> {
+        if done.contains(&name) {
+            return Ok(());
+        }
+        if path.contains(&name) {
+            return Err(Error::Cycle);
+        }
+        let table = self.tables.get(name).ok_or(Error::NotFound)?;
+        path.push(name);
+        let mut references = Vec::new();
+        for entry in table.entries() {
+            entry
+                .value()
+                .visit_tables(&mut |name| references.push(name));
+        }
+        for reference in references {
+            self.visit(reference, path, done)?;
+        }
+        path.pop();
+        done.push(name);
+        Ok(())
+    }
+
+    fn roll_table<'a, R>(&'a self, name: &str, roller: &R, results: &mut Vec<Resolved<'a, T>>)
+    where
+        R: Roll + SideSelector,
+    {
+        if let Some(entry) = self.tables[name].roll(roller) {
+            self.resolve(entry.value(), roller, results);
+        }
+    }
+
+    fn resolve<'a, R>(
+        &'a self,
+        outcome: &'a Outcome<T>,
+        roller: &R,
+        results: &mut Vec<Resolved<'a, T>>,
+    ) where
+        R: Roll + SideSelector,
+    {
+        match outcome {
+            Outcome::Value(value) => results.push(Resolved::Value(value)),
+            Outcome::Dice(notation) => results.push(Resolved::Dice(notation.roll(roller))),
+            Outcome::Table(name) => self.roll_table(name, roller, results),
+            Outcome::Repeat(notation, outcome) => {
+                for _ in 0..notation.roll(roller).total().max(0) {
+                    self.resolve(outcome, roller, results);
+                }
+            }
+        }
+    }
+}
+
+impl<T> Default for TableSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::SequenceRoller;
+
+    fn single<T>(outcome: Outcome<T>) -> RandomTable<Outcome<T>> {
+        RandomTable::new().with(outcome, 1)
+    }
+
+    #[test]
+    fn table_set_missing() {
+        let mut set = TableSet::new();
+        set.insert("a", single(Outcome::<()>::table("b")));
+
+        assert_eq!(set.validate("a"), Err(Error::NotFound));
+        assert_eq!(set.validate("c"), Err(Error::NotFound));
+    }
+
+    #[test]
+    fn table_set_cycle() {
+        let mut set = TableSet::new();
+        set.insert("a", single(Outcome::<()>::table("b")));
+        set.insert(
+            "b",
+            RandomTable::new()
+                .with(Outcome::Value(()), 1)
+                .with(Outcome::times(1, Outcome::table("a")), 0),
+        );
+
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.roll("a", &SequenceRoller::new([0])), Err(Error::Cycle));
+    }
+
+    #[test]
+    fn table_set_shared_reference_is_not_a_cycle() {
+        let mut set = TableSet::new();
+        set.insert("leaf", single(Outcome::Value('x')));
+        set.insert("left", single(Outcome::table("leaf")));
+        set.insert(
+            "root",
+            RandomTable::new()
+                .with(Outcome::times(2, Outcome::table("left")), 1)
+                .with(Outcome::table("right"), 0),
+        );
+        set.insert("right", single(Outcome::table("leaf")));
+
+        assert_eq!(
+            set.roll("root", &SequenceRoller::new([0])),
+            Ok(vec![Resolved::Value(&'x'); 2])
+        );
+    }
+
+    #[test]
+    fn table_set_dice() {
+        let mut set = TableSet::<()>::default();
+        set.insert("gold", single(Outcome::Dice(Notation::new(2, 6))));
+        let results = set.roll("gold", &SequenceRoller::new([5])).unwrap();
+
+        assert_eq!(
+            results,
+            vec![Resolved::Dice(NotationRoll::new(
+                Notation::new(2, 6),
+                vec![6, 6]
+            ))]
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use crate::traits::SideSelector;
+#[cfg(feature = "serde")]
+use crate::Error;
+
+/// An entry of a [`RandomTable`], and how likely it is compared to other entries.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableEntry<T> {
+    value: T,
+    weight: usize,
+}
+
+impl<T> TableEntry<T> {
+    /// Returns the value of the entry.
+    pub const fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the weight of the entry.
+    pub const fn weight(&self) -> usize {
+        self.weight
+    }
+}
+
+/// The result of rolling on a [`RandomTable`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TableRoll<'a, T> {
+    value: &'a T,
+    index: usize,
+    roll: usize,
+}
+
+impl<'a, T> TableRoll<'a, T> {
+    /// Returns the value of the entry that was rolled.
+    pub const fn value(&self) -> &'a T {
+        self.value
+    }
+
+    /// Returns the index of the entry that was rolled.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the roll that picked the entry, between `1..=total_weight`.
+    ///
+    /// Entries cover consecutive rolls in order, i.e. the first entry with a weight of `3` covers
+    /// rolls `1` to `3`, so the roll can be shown as if a die with as many sides was rolled.
+    pub const fn roll(&self) -> usize {
+        self.roll
+    }
+}
+
+/// A table of weighted entries, where each entry is as likely to be rolled as its weight.
+///
+/// Tables are the core of loot, encounter, and rumor tables, and are rolled using any
+/// [`SideSelector`].
+/// Entries with a weight of `0` are never rolled.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::tables::RandomTable;
+///
+/// let mut table = RandomTable::new();
+/// table.push("copper", 5);
+/// table.push("silver", 0);
+///
+/// assert_eq!(table.total_weight(), 5);
+/// assert_eq!(table.get(3), Some(&"copper"));
+/// assert_eq!(table.get(6), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RandomTableRepr<T>")
+)]
+pub struct RandomTable<T> {
+    entries: Vec<TableEntry<T>>,
+    total_weight: usize,
+}
+
+impl<T> RandomTable<T> {
+    /// Creates an empty table.
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            total_weight: 0,
+        }
+    }
+
+    /// Returns the table with an entry added to the end.
+    ///
+    /// # Panics
+    ///
+    /// If the total weight of the table overflows a `usize`.
+    #[must_use]
+    pub fn with(mut self, value: T, weight: usize) -> Self {
+        self.push(value, weight);
+        self
+    }
+
+    /// Adds an entry to the end of the table.
+    ///
+    /// # Panics
+    ///
+    /// If the total weight of the table overflows a `usize`.
+    pub fn push(&mut self, value: T, weight: usize) {
+        self.total_weight = self
+            .total_weight
+            .checked_add(weight)
+            .expect("total weight overflowed");
+        self.entries.push(TableEntry { value, weight });
+    }
+
+    /// Returns every entry, in order.
+    pub fn entries(&self) -> &[TableEntry<T>] {
+        &self.entries
+    }
+
+    /// Returns how many entries are in the table.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the table has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the sum of the weights of every entry.
+    pub const fn total_weight(&self) -> usize {
+        self.total_weight
+    }
+
+    /// Returns the probability of rolling the entry at the given index, or `0.0` if out of bounds.
+    pub fn probability(&self, index: usize) -> f64 {
+        match self.entries.get(index) {
+            Some(entry) if self.total_weight > 0 => entry.weight as f64 / self.total_weight as f64,
+            _ => 0.0,
+        }
+    }
+
+    /// Returns the value of the entry that covers the given roll, if any; see [`TableRoll::roll`].
+    pub fn get(&self, roll: usize) -> Option<&T> {
+        self.lookup(roll)
+            .map(|(index, _)| &self.entries[index].value)
+    }
+
+    /// Rolls on the table, returning the entry and the roll, or `None` if the total weight is `0`.
+    ///
+    /// The roll is [selected](SideSelector::select) as if a die with a side per weight was rolled.
+    pub fn roll<S>(&self, selector: &S) -> Option<TableRoll<'_, T>>
+    where
+        S: SideSelector,
+    {
+        if self.total_weight == 0 {
+            return None;
+        }
+        let roll = selector.select(self.total_weight) + 1;
+        self.lookup(roll)
+            .map(|(index, value)| TableRoll { value, index, roll })
+    }
+
+    /// Returns the index and value of the entry that covers the given roll, if any.
+    fn lookup(&self, roll: usize) -> Option<(usize, &T)> {
+        if roll == 0 {
+            return None;
+        }
+        let mut remaining = roll - 1;
+        for (index, entry) in self.entries.iter().enumerate() {
+            if remaining < entry.weight {
+                return Some((index, &entry.value));
+            }
+            remaining -= entry.weight;
+        }
+        None
+    }
+}
+
+impl<T> Default for RandomTable<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(T, usize)> for RandomTable<T> {
+    /// Creates a table from `(value, weight)` pairs, in order.
+    fn from_iter<I: IntoIterator<Item = (T, usize)>>(iter: I) -> Self {
+        let mut table = Self::new();
+        for (value, weight) in iter {
+            table.push(value, weight);
+        }
+        table
+    }
+}
+
+/// The serialized form of a [`RandomTable`], where the total weight is recomputed when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RandomTableRepr<T> {
+    entries: Vec<TableEntry<T>>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<RandomTableRepr<T>> for RandomTable<T> {
+    type Error = Error;
+
+    /// Creates a table from the serialized entries, failing if the total weight overflows.
+    fn try_from(repr: RandomTableRepr<T>) -> Result<Self, Self::Error> {
+        let mut total_weight = 0usize;
+        for entry in &repr.entries {
+            total_weight = total_weight
+                .checked_add(entry.weight)
+                .ok_or(Error::OutOfRange {
+                    value: entry.weight,
+                    min: 0,
+                    max: usize::MAX - total_weight,
+                })?;
+        }
+        Ok(Self {
+            entries: repr.entries,
+            total_weight,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+    use crate::items::{RngRoller, SequenceRoller};
+
+    #[test]
+    fn random_table_lookup() {
+        let table: RandomTable<_> = [('a', 2), ('b', 0), ('c', 3)].into_iter().collect();
+
+        assert_eq!(table.get(0), None);
+        assert_eq!(table.get(2), Some(&'a'));
+        assert_eq!(table.get(3), Some(&'c'));
+        assert_eq!(table.get(5), Some(&'c'));
+        assert_eq!(table.get(6), None);
+        assert_eq!(table.probability(2), 0.6);
+        assert_eq!(table.probability(3), 0.0);
+    }
+
+    #[test]
+    fn random_table_empty() {
+        let table = RandomTable::<char>::default().with('a', 0);
+
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.roll(&SequenceRoller::new([0])), None);
+        assert_eq!(table.probability(0), 0.0);
+    }
+
+    #[test]
+    fn random_table_weights() {
+        let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+        let table = RandomTable::new().with("common", 9).with("rare", 1);
+        let mut counts = [0; 2];
+        for _ in 0..10_000 {
+            let result = table.roll(&roller).unwrap();
+            assert_eq!(table.get(result.roll()), Some(result.value()));
+            counts[result.index()] += 1;
+        }
+
+        assert!((850..1150).contains(&counts[1]), "{counts:?}");
+    }
+
+    #[test]
+    fn random_table_roll_selects_weight() {
+        let table = RandomTable::new().with('a', 2).with('b', 3);
+        let result = table.roll(&SequenceRoller::new([2])).unwrap();
+
+        assert_eq!(
+            (result.value(), result.index(), result.roll()),
+            (&'b', 1, 3)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn random_table_serde() {
+        let table = RandomTable::new().with('a', 2).with('b', 3);
+        let json = serde_json::to_string(&table).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<RandomTable<char>>(&json).unwrap(),
+            table
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn random_table_serde_recomputes_total() {
+        let json = r#"{"entries":[{"value":"a","weight":2}],"total_weight":100}"#;
+        let table: RandomTable<char> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(table.total_weight(), 2);
+        assert_eq!(table.get(3), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn random_table_serde_overflow() {
+        let json = format!(
+            r#"{{"entries":[{{"value":"a","weight":{}}},{{"value":"b","weight":1}}]}}"#,
+            usize::MAX
+        );
+
+        assert!(serde_json::from_str::<RandomTable<char>>(&json).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "total weight overflowed")]
+    fn random_table_overflow() {
+        let _ = RandomTable::new().with('a', usize::MAX).with('b', 1);
+    }
+}
@@ -0,0 +1,225 @@
+use std::ops::RangeInclusive;
+
+use crate::mechanics::{Notation, NotationRoll};
+use crate::traits::SideSelector;
+
+/// The result of rolling on a [`RangeTable`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RangeRoll<'a, T> {
+    roll: NotationRoll,
+    value: Option<&'a T>,
+}
+
+impl<'a, T> RangeRoll<'a, T> {
+    /// Returns the dice that were rolled.
+    pub const fn roll(&self) -> &NotationRoll {
+        &self.roll
+    }
+
+    /// Returns the value of the entry whose range covers the total, or `None` if no range does.
+    pub const fn value(&self) -> Option<&'a T> {
+        self.value
+    }
+}
+
+/// A table where each entry covers a range of totals of a dice expression, i.e. `01-05`.
+///
+/// Printed tables are usually keyed by ranges of a die (often a d100) instead of by weights, and
+/// a range table encodes them as written, checking that ranges are possible totals and that no
+/// ranges overlap, so mistakes in range math are caught when the table is built.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::RngRoller;
+/// use tomb::mechanics::Notation;
+/// use tomb::tables::RangeTable;
+///
+/// let table = RangeTable::new(Notation::new(1, 100))
+///     .with(1..=5, "goblin ambush")
+///     .with(6..=20, "wandering merchant")
+///     .with(21..=100, "nothing");
+///
+/// assert!(table.is_complete());
+/// assert_eq!(table.get(17), Some(&"wandering merchant"));
+/// assert!((table.probability(0) - 0.05).abs() < 1e-12);
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let result = table.roll(&roller);
+/// assert_eq!(result.roll().total(), 48);
+/// assert_eq!(result.value(), Some(&"nothing"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RangeTable<T> {
+    notation: Notation,
+    min: i64,
+    max: i64,
+    entries: Vec<(RangeInclusive<i64>, T)>,
+}
+
+impl<T> RangeTable<T> {
+    /// Creates an empty table, keyed by totals of the given dice.
+    pub fn new(notation: Notation) -> Self {
+        let distribution = notation.distribution();
+        Self {
+            notation,
+            min: distribution.min(),
+            max: distribution.max(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the table with an entry added, covering the given range of totals.
+    ///
+    /// # Panics
+    ///
+    /// See [`RangeTable::push`].
+    #[must_use]
+    pub fn with(mut self, range: RangeInclusive<i64>, value: T) -> Self {
+        self.push(range, value);
+        self
+    }
+
+    /// Adds an entry, covering the given range of totals.
+    ///
+    /// # Panics
+    ///
+    /// If the range is empty, includes a total that can't be rolled, or overlaps another entry.
+    pub fn push(&mut self, range: RangeInclusive<i64>, value: T) {
+        assert!(!range.is_empty(), "range {range:?} is empty");
+        assert!(
+            *range.start() >= self.min && *range.end() <= self.max,
+            "range {range:?} is outside of {}..={}",
+            self.min,
+            self.max
+        );
+        if let Some((other, _)) = self
+            .entries
+            .iter()
+            .find(|(other, _)| other.start() <= range.end() && range.start() <= other.end())
+        {
+            panic!("range {range:?} overlaps {other:?}");
+        }
+        self.entries.push((range, value));
+    }
+
+    /// Returns the dice the table is keyed by.
+    pub const fn notation(&self) -> Notation {
+        self.notation
+    }
+
+    /// Returns every entry, in the order added.
+    pub fn entries(&self) -> &[(RangeInclusive<i64>, T)] {
+        &self.entries
+    }
+
+    /// Returns the value of the entry whose range covers the total, if any.
+    pub fn get(&self, total: i64) -> Option<&T> {
+        self.entries
+            .iter()
+            .find(|(range, _)| range.contains(&total))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the ranges of possible totals that are not covered by any entry, in order.
+    pub fn gaps(&self) -> Vec<RangeInclusive<i64>> {
+        let mut ranges: Vec<_> = self.entries.iter().map(|(range, _)| range).collect();
+        ranges.sort_by_key(|range| range.start());
+        let mut gaps = Vec::new();
+        let mut next = self.min;
+        for range in ranges {
+            if *range.start() > next {
+                gaps.push(next..=range.start() - 1);
+            }
+            next = range.end() + 1;
+        }
+        if next <= self.max {
+            gaps.push(next..=self.max);
+        }
+        gaps
+    }
+
+    /// Returns whether every possible total is covered by an entry.
+    pub fn is_complete(&self) -> bool {
+        self.gaps().is_empty()
+    }
+
+    /// Returns the exact probability of rolling the entry at the given index, or `0.0` if out of
+    /// bounds.
+    pub fn probability(&self, index: usize) -> f64 {
+        let Some((range, _)) = self.entries.get(index) else {
+            return 0.0;
+        };
+        let distribution = self.notation.distribution();
+        range.clone().map(|total| distribution.p(total)).sum()
+    }
+
+    /// Rolls the dice, returning the roll and the value of the entry whose range covers the total.
+    pub fn roll<S>(&self, selector: &S) -> RangeRoll<'_, T>
+    where
+        S: SideSelector,
+    {
+        let roll = self.notation.roll(selector);
+        let value = self.get(roll.total());
+        RangeRoll { roll, value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::SequenceRoller;
+
+    #[test]
+    fn range_table_gaps() {
+        let table = RangeTable::new(Notation::new(2, 6))
+            .with(5..=6, 'a')
+            .with(2..=3, 'b')
+            .with(10..=11, 'c');
+
+        assert_eq!(table.gaps(), vec![4..=4, 7..=9, 12..=12]);
+        assert!(!table.is_complete());
+        assert_eq!(table.get(4), None);
+        assert_eq!(table.probability(3), 0.0);
+    }
+
+    #[test]
+    fn range_table_probability() {
+        let table = RangeTable::new(Notation::new(2, 6)).with(7..=7, 'a');
+
+        assert!((table.probability(0) - 1.0 / 6.0).abs() < 1e-12);
+        assert_eq!(table.notation(), Notation::new(2, 6));
+        assert_eq!(table.entries().len(), 1);
+    }
+
+    #[test]
+    fn range_table_roll_uncovered() {
+        let table = RangeTable::new(Notation::new(1, 6)).with(1..=3, 'a');
+        let result = table.roll(&SequenceRoller::new([4]));
+
+        assert_eq!(result.roll().total(), 5);
+        assert_eq!(result.value(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "range 5..=8 overlaps 1..=5")]
+    fn range_table_overlap() {
+        let _ = RangeTable::new(Notation::new(1, 20))
+            .with(1..=5, 'a')
+            .with(5..=8, 'b');
+    }
+
+    #[test]
+    #[should_panic(expected = "range 0..=5 is outside of 1..=20")]
+    fn range_table_out_of_range() {
+        let _ = RangeTable::new(Notation::new(1, 20)).with(0..=5, 'a');
+    }
+
+    #[test]
+    #[should_panic(expected = "is empty")]
+    fn range_table_empty_range() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let _ = RangeTable::new(Notation::new(1, 20)).with(5..=4, 'a');
+    }
+}
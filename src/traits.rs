@@ -2,18 +2,32 @@
 //!
 //! Some frequently used traits will include:
 //!
+//! - [`Critical`] reports whether an object landed on a critical or fumble face.
 //! - [`Numeric`] allows flexibility when defining _numeric_ die.
 //! - [`Polyhedral`] defines objects with a known number of sides.
 //! - [`Rotate`] and [`Roll`] create or mutate objects with multiple sides.
+//! - [`SideSelector`] and [`Positional`] pick a side of an object directly.
 //!
 //! For most users, the traits exposed in [`crate`] are sufficient.
 
+mod ascii;
+mod coin;
+mod critical;
 mod numeric;
+mod opposite;
 mod polyhedral;
 mod roll;
 mod rotate;
+mod selector;
+mod snapshot;
 
+pub use ascii::*;
+pub use coin::*;
+pub use critical::*;
 pub use numeric::*;
+pub use opposite::*;
 pub use polyhedral::*;
 pub use roll::*;
 pub use rotate::*;
+pub use selector::*;
+pub use snapshot::*;
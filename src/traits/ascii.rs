@@ -0,0 +1,33 @@
+/// A trait for elements that can draw their current face as multi-line ASCII art, i.e. for a CLI.
+///
+/// Every line returned should have the same width (in characters), so that elements can be drawn
+/// side by side, i.e. by [`crate::items::AsciiTray`].
+///
+/// # Examples
+///
+/// ```
+/// use tomb::traits::AsciiArt;
+///
+/// struct Skull;
+///
+/// impl AsciiArt for Skull {
+///     fn ascii_art(&self) -> Vec<String> {
+///         vec![" ___ ".into(), "(x x)".into(), " )^( ".into()]
+///     }
+/// }
+///
+/// assert_eq!(Skull.ascii_art().len(), 3);
+/// ```
+pub trait AsciiArt {
+    /// Returns the lines of art for the current face, from top to bottom.
+    fn ascii_art(&self) -> Vec<String>;
+}
+
+impl<A> AsciiArt for &A
+where
+    A: AsciiArt + ?Sized,
+{
+    fn ascii_art(&self) -> Vec<String> {
+        (**self).ascii_art()
+    }
+}
@@ -0,0 +1,79 @@
+use super::SideSelector;
+
+/// A trait for elements with two sides, heads and tails, i.e. a coin.
+///
+/// Two-sided dice are coins, where the first face is heads and the second face is tails, so a
+/// `NumericDie<_, 2>` or a two-element [`crate::items::SliceDie`] can be used as a coin, as well as
+/// [`crate::items::BiasedCoin`].
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{NumericDie, SliceDie};
+/// use tomb::traits::Coin;
+///
+/// let mut d2 = NumericDie::<u8, 2>::new();
+/// assert!(d2.is_heads());
+///
+/// d2.swap();
+/// assert!(d2.is_tails());
+/// assert_eq!(d2.value(), 2);
+///
+/// const SIDES: [&str; 2] = ["obverse", "reverse"];
+/// assert!(SliceDie::new(&SIDES).is_heads());
+/// ```
+///
+/// A die with any other number of sides is not a coin:
+///
+/// ```compile_fail
+/// use tomb::items::D6;
+/// use tomb::traits::Coin;
+///
+/// let _ = D6::new().is_heads();
+/// ```
+pub trait Coin {
+    /// Returns whether the coin shows heads.
+    fn is_heads(&self) -> bool;
+
+    /// Returns whether the coin shows tails.
+    fn is_tails(&self) -> bool {
+        !self.is_heads()
+    }
+
+    /// Turns the coin over, showing the other side.
+    fn swap(&mut self);
+
+    /// Tosses the coin using the selector.
+    fn toss_mut<S>(&mut self, selector: &S)
+    where
+        S: SideSelector;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{NumericDie, SequenceRoller, SliceDie};
+    use crate::traits::OppositeFaces;
+
+    #[test]
+    fn coin_slice_die() {
+        let mut coin = SliceDie::new(&['H', 'T']);
+        coin.toss_mut(&SequenceRoller::new([1]));
+
+        assert!(coin.is_tails());
+        assert_eq!(coin.value(), &'T');
+
+        coin.swap();
+        assert!(coin.is_heads());
+    }
+
+    #[test]
+    fn coin_and_opposite_faces() {
+        let mut coin = NumericDie::<u8, 2>::new();
+        coin.flip_mut();
+        assert!(coin.is_tails());
+
+        coin.toss_mut(&SequenceRoller::new([0]));
+        assert!(coin.is_heads());
+    }
+}
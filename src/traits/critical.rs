@@ -0,0 +1,72 @@
+use super::Polyhedral;
+
+/// A trait that reports whether an element landed on a _critical_ or a _fumble_ face.
+///
+/// By default, a critical is the highest face (i.e. a "natural 20" on a D20), and a fumble is the
+/// lowest face (i.e. a "natural 1"). For configurable thresholds, see [`crate::items::Criticals`].
+pub trait Critical
+where
+    Self: Polyhedral,
+{
+    /// Returns the _natural_ face, between `1..=Self::sides()`, regardless of the face value.
+    fn natural(&self) -> usize;
+
+    /// Returns whether the current face is a critical success, i.e. the highest face.
+    fn is_critical(&self) -> bool {
+        self.natural() == Self::sides()
+    }
+
+    /// Returns whether the current face is a critical failure, i.e. the lowest face.
+    fn is_fumble(&self) -> bool {
+        self.natural() == 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeDie(usize);
+
+    impl Polyhedral for FakeDie {
+        type Face = usize;
+
+        fn sides() -> usize {
+            20
+        }
+
+        fn value(&self) -> usize {
+            self.0
+        }
+    }
+
+    impl Critical for FakeDie {
+        fn natural(&self) -> usize {
+            self.0
+        }
+    }
+
+    #[test]
+    fn critical_impl_highest() {
+        let d = FakeDie(20);
+
+        assert!(d.is_critical());
+        assert!(!d.is_fumble());
+    }
+
+    #[test]
+    fn critical_impl_lowest() {
+        let d = FakeDie(1);
+
+        assert!(!d.is_critical());
+        assert!(d.is_fumble());
+    }
+
+    #[test]
+    fn critical_impl_neither() {
+        let d = FakeDie(10);
+
+        assert!(!d.is_critical());
+        assert!(!d.is_fumble());
+    }
+}
@@ -19,6 +19,9 @@ where
     /// What value, when added to an existing value, increases the value by `1` or equivalent.
     const STEPONE: Self;
 
+    /// The largest value that can be represented, as a `u128`, i.e. the most sides a die can have.
+    const CAPACITY: u128;
+
     /// Create a numeric value that is semantically equivalent to the provided number.
     fn from_usize(number: usize) -> Self;
 
@@ -31,6 +34,7 @@ macro_rules! numeric {
         impl Numeric for $name {
             const MINIMUM: Self = 1;
             const STEPONE: Self = 1;
+            const CAPACITY: u128 = $name::MAX as u128;
 
             fn from_usize(number: usize) -> Self {
                 number as Self
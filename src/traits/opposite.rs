@@ -0,0 +1,96 @@
+use super::Positional;
+
+/// A trait for elements where every face has a face on the opposite side.
+///
+/// By default, opposite faces are mirrored by position, so the values of opposite faces of a
+/// standard die add up to one more than the number of sides (i.e. `7` on a D6, and `21` on a D20).
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::{D20, D6};
+/// use tomb::traits::OppositeFaces;
+///
+/// assert_eq!(D6::from(2).opposite().value(), 5);
+/// assert_eq!(D20::from(20).opposite().value(), 1);
+///
+/// let mut d6 = D6::from(1);
+/// d6.flip_mut();
+/// assert_eq!(d6.value(), 6);
+/// ```
+pub trait OppositeFaces
+where
+    Self: Positional,
+{
+    /// Returns the position of the face opposite the face at the given position.
+    fn opposite_position(position: usize) -> usize {
+        Self::sides() - 1 - position
+    }
+
+    /// Returns a copy of the element, showing the face opposite the current face.
+    #[must_use]
+    fn opposite(&self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut opposite = self.clone();
+        opposite.flip_mut();
+        opposite
+    }
+
+    /// Changes the element to show the face opposite the current face.
+    fn flip_mut(&mut self) {
+        self.set_position(Self::opposite_position(self.position()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::Polyhedral;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct FakeDie(usize);
+
+    impl Polyhedral for FakeDie {
+        type Face = usize;
+
+        fn sides() -> usize {
+            8
+        }
+
+        fn value(&self) -> usize {
+            self.0 + 1
+        }
+    }
+
+    impl Positional for FakeDie {
+        fn position(&self) -> usize {
+            self.0
+        }
+
+        fn set_position(&mut self, position: usize) {
+            self.0 = position;
+        }
+    }
+
+    impl OppositeFaces for FakeDie {}
+
+    #[test]
+    fn opposite_faces_sum() {
+        for position in 0..8 {
+            let die = FakeDie(position);
+
+            assert_eq!(die.value() + die.opposite().value(), 9);
+        }
+    }
+
+    #[test]
+    fn opposite_faces_flip_twice() {
+        let mut die = FakeDie(2);
+        die.flip_mut();
+        die.flip_mut();
+
+        assert_eq!(die, FakeDie(2));
+    }
+}
@@ -1,5 +1,104 @@
-/// A trait that provides a known number of sides for a multi-sided element.
+/// A trait that provides a known number of sides for a multi-sided element, and its current face.
+///
+/// # Examples
+///
+/// Reporting the face of any die, without knowing the concrete type:
+///
+/// ```
+/// use std::fmt::Debug;
+/// use tomb::items::{SliceDie, D66, D6};
+/// use tomb::traits::Polyhedral;
+///
+/// fn report<D>(die: &D) -> String
+/// where
+///     D: Polyhedral,
+///     D::Face: Debug,
+/// {
+///     format!("{:?} of {}", die.value(), D::sides())
+/// }
+///
+/// assert_eq!(report(&D6::from(4)), "4 of 6");
+/// assert_eq!(report(&D66::with_faces(3, 5)), "35 of 36");
+/// assert_eq!(report(&SliceDie::new(&["heads", "tails"])), "\"heads\" of 2");
+/// ```
 pub trait Polyhedral {
+    /// The type of value shown on a face, i.e. a number, or a reference to a symbol.
+    type Face;
+
     /// Returns the number of sides present.
     fn sides() -> usize;
+
+    /// Returns the value of the current face.
+    fn value(&self) -> Self::Face;
 }
+
+/// A trait for elements where the current face can be read and changed by its position.
+///
+/// The position of the lowest face is `0`, and of the highest face is `Self::sides() - 1`.
+pub trait Positional
+where
+    Self: Polyhedral,
+{
+    /// Returns the position of the current face, between `0..Self::sides()`.
+    fn position(&self) -> usize;
+
+    /// Changes the current face to the face at the given position.
+    ///
+    /// # Panics
+    ///
+    /// If the position is not within `0..Self::sides()`.
+    fn set_position(&mut self, position: usize);
+
+    /// Returns an iterator over the value of every face, from the lowest to highest position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::items::{SliceDie, D4};
+    /// use tomb::traits::Positional;
+    ///
+    /// assert_eq!(D4::new().faces().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    ///
+    /// let coin = SliceDie::new(&["heads", "tails"]);
+    /// assert_eq!(coin.faces().collect::<Vec<_>>(), vec![&"heads", &"tails"]);
+    /// ```
+    fn faces(&self) -> Faces<Self>
+    where
+        Self: Clone + Sized,
+    {
+        Faces {
+            element: self.clone(),
+            next: 0,
+        }
+    }
+}
+
+/// An iterator over the value of every face of an element; see [`Positional::faces`].
+#[derive(Clone, Debug)]
+pub struct Faces<T> {
+    element: T,
+    next: usize,
+}
+
+impl<T> Iterator for Faces<T>
+where
+    T: Positional,
+{
+    type Item = T::Face;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= T::sides() {
+            return None;
+        }
+        self.element.set_position(self.next);
+        self.next += 1;
+        Some(self.element.value())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = T::sides().saturating_sub(self.next);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Faces<T> where T: Positional {}
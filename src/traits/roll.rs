@@ -1,19 +1,208 @@
-use super::{Polyhedral, Rotate, RotateMut};
+use super::{Critical, Positional, Rotate, RotateMut};
 
-/// A trait that creates new elements based off ones which [`Rotate`] and are [`Polyhedral`].
+/// A trait that creates new elements based off ones which [`Rotate`] and are [`Positional`].
 ///
 /// In practice, this is used to allow a die _roller_ in order to create a new (immutable) die
 /// by picking a new side, randomly, of one of the possible dies. For example, for a D6, picking
 /// between the values `1..=6`.
+///
+/// Rollers typically implement [`SideSelector`](super::SideSelector) as well, and roll by
+/// [picking](super::SideSelector::pick) a position, so the result never depends on the face the
+/// element was showing before it was rolled.
 pub trait Roll {
     /// Rotates an entity randomly, returning the result, where random is defined elsewhere.
     #[must_use]
     fn roll<T>(&self, rotate: &T) -> T
     where
-        T: Rotate + Polyhedral;
+        T: Rotate + Positional;
+
+    /// Rolls an entity repeatedly until the predicate is satisfied, or `limit` attempts were made.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastrand::Rng;
+    /// use tomb::items::{D20, RngRoller};
+    /// use tomb::traits::Roll;
+    ///
+    /// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+    /// let attempts = roller.roll_until(&D20::new(), 1000, |d| d.value() == 20);
+    ///
+    /// assert!(attempts.is_satisfied());
+    /// assert_eq!(attempts.last().map(|d| d.value()), Some(20));
+    /// assert_eq!(attempts.len(), 34);
+    /// ```
+    #[must_use]
+    fn roll_until<T, F>(&self, rotate: &T, limit: usize, mut predicate: F) -> Attempts<T>
+    where
+        T: Rotate + Positional,
+        F: FnMut(&T) -> bool,
+    {
+        let mut rolls = Vec::new();
+        while rolls.len() < limit {
+            let roll = self.roll(rotate);
+            let satisfied = predicate(&roll);
+            rolls.push(roll);
+            if satisfied {
+                return Attempts {
+                    rolls,
+                    satisfied: true,
+                };
+            }
+        }
+        Attempts {
+            rolls,
+            satisfied: false,
+        }
+    }
+
+    /// Rolls an entity `n` times, returning every roll in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastrand::Rng;
+    /// use tomb::items::{D6, RngRoller};
+    /// use tomb::traits::Roll;
+    ///
+    /// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+    /// let rolls = roller.roll_n(&D6::new(), 3);
+    ///
+    /// assert_eq!(rolls.iter().map(|d| d.value()).collect::<Vec<_>>(), vec![3, 4, 2]);
+    /// ```
+    #[must_use]
+    fn roll_n<T>(&self, rotate: &T, n: usize) -> Vec<T>
+    where
+        T: Rotate + Positional,
+    {
+        (0..n).map(|_| self.roll(rotate)).collect()
+    }
+
+    /// Rolls an entity `N` times, returning every roll in order as an array.
+    ///
+    /// Unlike [`Roll::roll_n`], no allocation is made, for pools where the size is known upfront.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastrand::Rng;
+    /// use tomb::items::{PoolDisplay, D6, RngRoller};
+    /// use tomb::traits::Roll;
+    ///
+    /// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+    /// let pool: [D6; 3] = roller.roll_array(&D6::new());
+    ///
+    /// assert_eq!(PoolDisplay::new(&pool).to_string(), "3d6: [3, 4, 2] = 9");
+    /// ```
+    #[must_use]
+    fn roll_array<T, const N: usize>(&self, rotate: &T) -> [T; N]
+    where
+        T: Rotate + Positional,
+    {
+        std::array::from_fn(|_| self.roll(rotate))
+    }
+
+    /// Rolls an entity `n` times, returning the sum of the natural faces (see [`Critical::natural`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastrand::Rng;
+    /// use tomb::items::{D6, RngRoller};
+    /// use tomb::traits::Roll;
+    ///
+    /// // 5d6
+    /// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+    /// let total = roller.sum_n(&D6::new(), 5);
+    ///
+    /// assert_eq!(total, 15);
+    /// ```
+    #[must_use]
+    fn sum_n<T>(&self, rotate: &T, n: usize) -> usize
+    where
+        T: Rotate + Positional + Critical,
+    {
+        (0..n).map(|_| self.roll(rotate).natural()).sum()
+    }
+
+    /// Rolls an entity once for every element of `out`, overwriting each element with a roll.
+    ///
+    /// Unlike [`Roll::roll_n`], no allocation is made, so a buffer can be reused between batches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastrand::Rng;
+    /// use tomb::items::{D6, RngRoller};
+    /// use tomb::traits::Roll;
+    ///
+    /// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+    /// let mut rolls = [D6::new(); 3];
+    /// roller.roll_into(&D6::new(), &mut rolls);
+    ///
+    /// assert_eq!(rolls.map(|d| d.value()), [3, 4, 2]);
+    /// ```
+    fn roll_into<T>(&self, rotate: &T, out: &mut [T])
+    where
+        T: Rotate + Positional,
+    {
+        for slot in out {
+            *slot = self.roll(rotate);
+        }
+    }
+
+    /// Rolls an entity once for every element of `out`, overwriting each element with the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastrand::Rng;
+    /// use tomb::items::{D6, RngRoller};
+    /// use tomb::traits::Roll;
+    ///
+    /// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+    /// let mut values = [0u8; 3];
+    /// roller.roll_values_into(&D6::new(), &mut values);
+    ///
+    /// assert_eq!(values, [3, 4, 2]);
+    /// ```
+    fn roll_values_into<T>(&self, rotate: &T, out: &mut [T::Face])
+    where
+        T: Rotate + Positional,
+    {
+        for slot in out {
+            *slot = self.roll(rotate).value();
+        }
+    }
+
+    /// Returns an infinite iterator that rolls the entity each time it is advanced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastrand::Rng;
+    /// use tomb::items::{D20, RngRoller};
+    /// use tomb::traits::Roll;
+    ///
+    /// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+    /// let d20 = D20::new();
+    /// let twenties = roller.iter(&d20).take(1000).filter(|d| d.value() == 20).count();
+    ///
+    /// assert_eq!(twenties, 52);
+    /// ```
+    fn iter<'a, T>(&'a self, rotate: &'a T) -> Rolls<'a, Self, T>
+    where
+        Self: Sized,
+        T: Rotate + Positional,
+    {
+        Rolls {
+            roller: self,
+            rotate,
+        }
+    }
 }
 
-/// A trait that mutates existing elements which [`RotateMut`] and are [`Polyhedral`].
+/// A trait that mutates existing elements which [`RotateMut`] and are [`Positional`].
 ///
 /// In practice, this is used to allow a die _roller_ in order to create a new (immutable) die
 /// by picking a new side, randomly, of one of the possible dies. For example, for a D6, picking
@@ -22,5 +211,430 @@ pub trait RollMut {
     /// Rotates an entity randomly, mutating the entity, where random is defined elsewhere.
     fn roll_mut<T>(&self, rotate: &mut T)
     where
-        T: RotateMut + Polyhedral;
+        T: RotateMut + Positional;
+
+    /// Returns an infinite iterator that rolls the entity in place each time it is advanced.
+    ///
+    /// Each item is a copy of the entity after being rolled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastrand::Rng;
+    /// use tomb::items::{D6, RngRoller};
+    /// use tomb::traits::RollMut;
+    ///
+    /// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+    /// let mut d6 = D6::new();
+    /// let last = roller.iter_mut(&mut d6).take(10).last().unwrap();
+    ///
+    /// assert_eq!(d6, last);
+    /// ```
+    fn iter_mut<'a, T>(&'a self, rotate: &'a mut T) -> RollsMut<'a, Self, T>
+    where
+        Self: Sized,
+        T: RotateMut + Positional + Clone,
+    {
+        RollsMut {
+            roller: self,
+            rotate,
+        }
+    }
+}
+
+/// A trait for rollers that can both create new elements ([`Roll`]) and mutate them ([`RollMut`]).
+///
+/// Implemented for every type that implements both traits, so it is only ever used as a bound.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{RngRoller, D6};
+/// use tomb::traits::Roller;
+///
+/// fn roll_both<R: Roller>(roller: &R, die: &mut D6) -> D6 {
+///     roller.roll_mut(die);
+///     roller.roll(die)
+/// }
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let mut d6 = D6::new();
+/// let rolled = roll_both(&roller, &mut d6);
+///
+/// assert_eq!((d6.value(), rolled.value()), (3, 4));
+/// ```
+pub trait Roller: Roll + RollMut {}
+
+impl<R> Roller for R where R: Roll + RollMut + ?Sized {}
+
+macro_rules! delegate_roll {
+    ($($pointer:ty),*) => {
+        $(
+            impl<R> Roll for $pointer
+            where
+                R: Roll + ?Sized,
+            {
+                fn roll<T>(&self, rotate: &T) -> T
+                where
+                    T: Rotate + Positional,
+                {
+                    (**self).roll(rotate)
+                }
+            }
+
+            impl<R> RollMut for $pointer
+            where
+                R: RollMut + ?Sized,
+            {
+                fn roll_mut<T>(&self, rotate: &mut T)
+                where
+                    T: RotateMut + Positional,
+                {
+                    (**self).roll_mut(rotate)
+                }
+            }
+        )*
+    };
+}
+
+delegate_roll!(&R, &mut R, Box<R>, std::rc::Rc<R>, std::sync::Arc<R>);
+
+/// An infinite iterator of rolls, created by [`Roll::iter`].
+#[derive(Debug)]
+pub struct Rolls<'a, R, T> {
+    roller: &'a R,
+    rotate: &'a T,
+}
+
+impl<R, T> Iterator for Rolls<'_, R, T>
+where
+    R: Roll,
+    T: Rotate + Positional,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.roller.roll(self.rotate))
+    }
+}
+
+/// An infinite iterator that rolls an entity in place, created by [`RollMut::iter_mut`].
+#[derive(Debug)]
+pub struct RollsMut<'a, R, T> {
+    roller: &'a R,
+    rotate: &'a mut T,
+}
+
+impl<R, T> Iterator for RollsMut<'_, R, T>
+where
+    R: RollMut,
+    T: RotateMut + Positional + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.roller.roll_mut(self.rotate);
+        Some(self.rotate.clone())
+    }
+}
+
+/// The sequence of rolls made by [`Roll::roll_until`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "AttemptsRepr<T>")
+)]
+pub struct Attempts<T> {
+    rolls: Vec<T>,
+    satisfied: bool,
+}
+
+impl<T> Attempts<T> {
+    /// Returns every roll made, in order.
+    pub fn rolls(&self) -> &[T] {
+        &self.rolls
+    }
+
+    /// Returns the last roll made, which satisfied the predicate if [`Attempts::is_satisfied`].
+    pub fn last(&self) -> Option<&T> {
+        self.rolls.last()
+    }
+
+    /// Returns how many attempts were made.
+    pub fn len(&self) -> usize {
+        self.rolls.len()
+    }
+
+    /// Returns whether no attempts were made, which only occurs if the limit was `0`.
+    pub fn is_empty(&self) -> bool {
+        self.rolls.is_empty()
+    }
+
+    /// Returns whether the predicate was satisfied before running out of attempts.
+    pub const fn is_satisfied(&self) -> bool {
+        self.satisfied
+    }
+
+    /// Returns every roll made, in order.
+    pub fn into_rolls(self) -> Vec<T> {
+        self.rolls
+    }
+}
+
+/// The serialized form of [`Attempts`], which is validated when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct AttemptsRepr<T> {
+    rolls: Vec<T>,
+    satisfied: bool,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<AttemptsRepr<T>> for Attempts<T> {
+    type Error = crate::Error;
+
+    /// Creates attempts from the serialized fields, failing if the predicate was satisfied
+    /// without a roll.
+    fn try_from(repr: AttemptsRepr<T>) -> Result<Self, Self::Error> {
+        if repr.satisfied && repr.rolls.is_empty() {
+            return Err(crate::Error::OutOfRange {
+                value: 0,
+                min: 1,
+                max: usize::MAX,
+            });
+        }
+        Ok(Self {
+            rolls: repr.rolls,
+            satisfied: repr.satisfied,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::NopRoller;
+    use crate::traits::{Polyhedral, Step, StepMut};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct FakeDie(i8);
+
+    impl Step for FakeDie {
+        fn next(&self) -> Self {
+            FakeDie(self.0 + 1)
+        }
+
+        fn back(&self) -> Self {
+            FakeDie(self.0 - 1)
+        }
+    }
+
+    impl StepMut for FakeDie {
+        fn next_mut(&mut self) {
+            self.0 += 1
+        }
+
+        fn back_mut(&mut self) {
+            self.0 -= 1
+        }
+    }
+
+    impl Rotate for FakeDie {}
+
+    impl RotateMut for FakeDie {}
+
+    impl Polyhedral for FakeDie {
+        type Face = i8;
+
+        fn sides() -> usize {
+            6
+        }
+
+        fn value(&self) -> i8 {
+            self.0
+        }
+    }
+
+    impl Positional for FakeDie {
+        fn position(&self) -> usize {
+            self.0 as usize
+        }
+
+        fn set_position(&mut self, position: usize) {
+            self.0 = position as i8;
+        }
+    }
+
+    /// Rotates forward by one more than the previous roll, starting at one.
+    struct CountingRoller(std::cell::Cell<isize>);
+
+    impl Roll for CountingRoller {
+        fn roll<T>(&self, rotate: &T) -> T
+        where
+            T: Rotate + Positional,
+        {
+            self.0.set(self.0.get() + 1);
+            rotate.rotate(self.0.get())
+        }
+    }
+
+    impl RollMut for CountingRoller {
+        fn roll_mut<T>(&self, rotate: &mut T)
+        where
+            T: RotateMut + Positional,
+        {
+            self.0.set(self.0.get() + 1);
+            rotate.rotate_mut(self.0.get())
+        }
+    }
+
+    impl Critical for FakeDie {
+        fn natural(&self) -> usize {
+            self.0 as usize
+        }
+    }
+
+    #[test]
+    fn roll_n() {
+        let roller = CountingRoller(Default::default());
+
+        assert_eq!(
+            roller.roll_n(&FakeDie(0), 3),
+            vec![FakeDie(1), FakeDie(2), FakeDie(3)]
+        );
+        assert_eq!(roller.roll_n(&FakeDie(0), 0), vec![]);
+    }
+
+    #[test]
+    fn sum_n() {
+        let roller = CountingRoller(Default::default());
+
+        assert_eq!(roller.sum_n(&FakeDie(0), 4), 1 + 2 + 3 + 4);
+    }
+
+    #[test]
+    fn roll_delegates_through_pointers() {
+        fn roll<R: Roll>(roller: R) -> FakeDie {
+            roller.roll(&FakeDie(0))
+        }
+
+        let mut roller = CountingRoller(Default::default());
+        assert_eq!(roll(&roller), FakeDie(1));
+        assert_eq!(roll(&mut roller), FakeDie(2));
+        assert_eq!(roll(std::rc::Rc::new(roller)), FakeDie(3));
+        assert_eq!(roll(Box::new(NopRoller)), FakeDie(0));
+        assert_eq!(roll(std::sync::Arc::new(NopRoller)), FakeDie(0));
+    }
+
+    #[test]
+    fn roll_mut_delegates_through_pointers() {
+        fn roll_mut<R: RollMut>(roller: R) -> FakeDie {
+            let mut die = FakeDie(0);
+            roller.roll_mut(&mut die);
+            die
+        }
+
+        let roller = CountingRoller(Default::default());
+        assert_eq!(roll_mut(&roller), FakeDie(1));
+        assert_eq!(roll_mut(Box::new(roller)), FakeDie(2));
+    }
+
+    #[test]
+    fn roller_blanket() {
+        fn roller<R: Roller>(_: &R) {}
+
+        roller(&CountingRoller(Default::default()));
+        roller(&NopRoller);
+    }
+
+    #[test]
+    fn roll_until_satisfied() {
+        let roller = CountingRoller(Default::default());
+        let attempts = roller.roll_until(&FakeDie(0), 10, |d| d.0 == 3);
+
+        assert!(attempts.is_satisfied());
+        assert_eq!(attempts.len(), 3);
+        assert_eq!(attempts.rolls(), &[FakeDie(1), FakeDie(2), FakeDie(3)]);
+        assert_eq!(attempts.last(), Some(&FakeDie(3)));
+    }
+
+    #[test]
+    fn roll_until_exhausted() {
+        let roller = CountingRoller(Default::default());
+        let attempts = roller.roll_until(&FakeDie(0), 4, |d| d.0 == 10);
+
+        assert!(!attempts.is_satisfied());
+        assert_eq!(attempts.len(), 4);
+        assert_eq!(attempts.last(), Some(&FakeDie(4)));
+    }
+
+    #[test]
+    fn roll_until_no_limit() {
+        let roller = CountingRoller(Default::default());
+        let attempts = roller.roll_until(&FakeDie(0), 0, |_| true);
+
+        assert!(!attempts.is_satisfied());
+        assert!(attempts.is_empty());
+        assert_eq!(attempts.into_rolls(), vec![]);
+    }
+
+    #[test]
+    fn roll_iter() {
+        let roller = CountingRoller(Default::default());
+        let die = FakeDie(0);
+        let rolls: Vec<_> = roller.iter(&die).take(3).collect();
+
+        assert_eq!(rolls, vec![FakeDie(1), FakeDie(2), FakeDie(3)]);
+        assert_eq!(die, FakeDie(0));
+    }
+
+    #[test]
+    fn roll_iter_mut() {
+        let roller = CountingRoller(Default::default());
+        let mut die = FakeDie(0);
+        let rolls: Vec<_> = roller.iter_mut(&mut die).take(3).collect();
+
+        assert_eq!(rolls, vec![FakeDie(1), FakeDie(3), FakeDie(6)]);
+        assert_eq!(die, FakeDie(6));
+    }
+
+    #[test]
+    fn roll_into() {
+        let roller = CountingRoller(Default::default());
+        let mut rolls = vec![FakeDie(0); 3];
+        roller.roll_into(&FakeDie(0), &mut rolls);
+
+        assert_eq!(rolls, vec![FakeDie(1), FakeDie(2), FakeDie(3)]);
+    }
+
+    #[test]
+    fn roll_values_into() {
+        let roller = CountingRoller(Default::default());
+        let mut values = [0; 3];
+        roller.roll_values_into(&FakeDie(10), &mut values);
+
+        assert_eq!(values, [11, 12, 13]);
+    }
+
+    #[test]
+    fn roll_array() {
+        let roller = CountingRoller(Default::default());
+
+        assert_eq!(
+            roller.roll_array(&FakeDie(0)),
+            [FakeDie(1), FakeDie(2), FakeDie(3)]
+        );
+        assert_eq!(roller.roll_array::<_, 0>(&FakeDie(0)), []);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn attempts_serde_invalid() {
+        let json = r#"{"rolls":[],"satisfied":true}"#;
+
+        assert!(serde_json::from_str::<Attempts<u8>>(json).is_err());
+        assert!(serde_json::from_str::<Attempts<u8>>(r#"{"rolls":[],"satisfied":false}"#).is_ok());
+    }
 }
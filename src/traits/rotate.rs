@@ -1,3 +1,5 @@
+use super::Positional;
+
 /// A trait that creates elements by use of _step_ functions, i.e. seeking forward or backward.
 pub trait Step {
     /// Steps _forward_ logically, for whatever that means, returning rotated by 1.
@@ -18,6 +20,32 @@ pub trait StepMut {
     fn back_mut(&mut self);
 }
 
+/// A direction to rotate an element in, used by [`Rotate::rotate_by`] and [`RotateMut::rotate_by_mut`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Rotates forwards, as if stepping with `next`.
+    #[default]
+    Forward,
+
+    /// Rotates backwards, as if stepping with `back`.
+    Backward,
+}
+
+impl Direction {
+    /// Returns the signed amounts, each at most `isize::MAX`, that together rotate by `amount`.
+    fn amounts(self, amount: usize) -> impl Iterator<Item = isize> {
+        let chunks = amount / isize::MAX as usize;
+        let remainder = amount % isize::MAX as usize;
+        std::iter::repeat_n(isize::MAX, chunks)
+            .chain(std::iter::once(remainder as isize))
+            .filter(|&amount| amount != 0)
+            .map(move |amount| match self {
+                Self::Forward => amount,
+                Self::Backward => -amount,
+            })
+    }
+}
+
 /// A trait that can create rotated (forwards or backwards) elements given a number.
 pub trait Rotate
 where
@@ -29,10 +57,10 @@ where
     ///
     /// The default implementation is naive, and uses a loop combined with `next` and `back`, or
     /// `O(n)` where n is the `amount`. Where possible, replace this method with one that can seek
-    /// directly and has better runtime and memory performance.
+    /// directly and has better runtime and memory performance, i.e. with [`RotateFast`].
     #[allow(clippy::comparison_chain)]
     #[must_use]
-    fn rotate(&self, amount: i8) -> Self {
+    fn rotate(&self, amount: isize) -> Self {
         let mut next = self.clone();
         if amount == 0 {
             return next;
@@ -45,6 +73,30 @@ where
         }
         next
     }
+
+    /// Rotates by an unsigned amount in the given direction.
+    ///
+    /// Unlike [`Rotate::rotate`], any `usize` amount can be used without casting, i.e. the output of
+    /// an RNG.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tomb::items::D6;
+    /// use tomb::traits::{Direction, Rotate};
+    ///
+    /// let d6 = D6::from(2);
+    ///
+    /// assert_eq!(d6.rotate_by(3, Direction::Forward).value(), 5);
+    /// assert_eq!(d6.rotate_by(3, Direction::Backward).value(), 5);
+    /// assert_eq!(d6.rotate_by(usize::MAX, Direction::Forward).value(), 5);
+    /// ```
+    #[must_use]
+    fn rotate_by(&self, amount: usize, direction: Direction) -> Self {
+        direction
+            .amounts(amount)
+            .fold(self.clone(), |next, amount| next.rotate(amount))
+    }
 }
 
 /// A trait that can rotate (mutating; forwards or backwards) elements given a number.
@@ -58,9 +110,9 @@ where
     ///
     /// The default implementation is naive, and uses a loop combined with `next` and `back`, or
     /// `O(n)` where n is the `amount`. Where possible, replace this method with one that can seek
-    /// directly and has better runtime and memory performance.
+    /// directly and has better runtime and memory performance, i.e. with [`RotateFast`].
     #[allow(clippy::comparison_chain)]
-    fn rotate_mut(&mut self, amount: i8) {
+    fn rotate_mut(&mut self, amount: isize) {
         if amount == 0 {
             return;
         }
@@ -75,8 +127,94 @@ where
             amount -= 1;
         }
     }
+
+    /// Rotates by an unsigned amount in the given direction; see [`Rotate::rotate_by`].
+    fn rotate_by_mut(&mut self, amount: usize, direction: Direction) {
+        for amount in direction.amounts(amount) {
+            self.rotate_mut(amount);
+        }
+    }
+}
+
+/// A trait that rotates [`Positional`] elements in `O(1)`, by seeking directly to a position.
+///
+/// Rotating past the highest face continues from the lowest face (and vice-versa), like a standard
+/// die. This is implemented for every [`Positional`] element, and is intended to replace the naive
+/// default implementations of [`Rotate`] and [`RotateMut`] for custom elements.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::traits::{Polyhedral, Positional, Rotate, RotateFast, Step};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Compass(usize);
+///
+/// impl Polyhedral for Compass {
+///     type Face = char;
+///
+///     fn sides() -> usize {
+///         4
+///     }
+///
+///     fn value(&self) -> char {
+///         ['N', 'E', 'S', 'W'][self.0]
+///     }
+/// }
+///
+/// impl Positional for Compass {
+///     fn position(&self) -> usize {
+///         self.0
+///     }
+///
+///     fn set_position(&mut self, position: usize) {
+///         assert!(position < 4);
+///         self.0 = position;
+///     }
+/// }
+///
+/// impl Step for Compass {
+///     fn next(&self) -> Self {
+///         self.rotate_fast(1)
+///     }
+///
+///     fn back(&self) -> Self {
+///         self.rotate_fast(-1)
+///     }
+/// }
+///
+/// impl Rotate for Compass {
+///     fn rotate(&self, amount: isize) -> Self {
+///         self.rotate_fast(amount)
+///     }
+/// }
+///
+/// assert_eq!(Compass(0).rotate(isize::MAX).value(), 'W');
+/// ```
+pub trait RotateFast
+where
+    Self: Positional,
+{
+    /// Rotates either forwards or backwards, based on the given amount, returning the result.
+    #[must_use]
+    fn rotate_fast(&self, amount: isize) -> Self
+    where
+        Self: Clone,
+    {
+        let mut next = self.clone();
+        next.rotate_fast_mut(amount);
+        next
+    }
+
+    /// Rotates either forwards or backwards, based on the given amount.
+    fn rotate_fast_mut(&mut self, amount: isize) {
+        let position = self.position() as i128 + amount as i128;
+        self.set_position(position.rem_euclid(Self::sides() as i128) as usize);
+    }
 }
 
+impl<T> RotateFast for T where T: Positional {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -154,4 +292,49 @@ mod tests {
 
         assert_eq!(d.0, -2);
     }
+
+    #[test]
+    fn rotate_by_directions() {
+        let d = FakeDie(0);
+
+        assert_eq!(d.rotate_by(0, Direction::Forward).0, 0);
+        assert_eq!(d.rotate_by(3, Direction::Forward).0, 3);
+        assert_eq!(d.rotate_by(3, Direction::Backward).0, -3);
+    }
+
+    #[test]
+    fn rotate_by_mut_directions() {
+        let mut d = FakeDie(0);
+        d.rotate_by_mut(3, Direction::Backward);
+
+        assert_eq!(d.0, -3);
+    }
+
+    #[test]
+    fn direction_amounts() {
+        let max = isize::MAX as usize;
+
+        assert_eq!(Direction::Forward.amounts(0).count(), 0);
+        assert_eq!(
+            Direction::Backward.amounts(max + 2).collect::<Vec<_>>(),
+            vec![-isize::MAX, -2]
+        );
+        assert_eq!(
+            Direction::Forward.amounts(usize::MAX).collect::<Vec<_>>(),
+            vec![isize::MAX, isize::MAX, 1]
+        );
+    }
+
+    #[test]
+    fn rotate_fast_matches_rotate() {
+        use crate::items::D6;
+
+        for amount in [-13, -6, -1, 0, 1, 5, 6, 7, 100, isize::MIN, isize::MAX] {
+            assert_eq!(D6::from(4).rotate_fast(amount), D6::from(4).rotate(amount));
+
+            let mut d6 = D6::from(4);
+            d6.rotate_fast_mut(amount);
+            assert_eq!(d6, D6::from(4).rotate(amount));
+        }
+    }
 }
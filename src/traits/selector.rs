@@ -0,0 +1,108 @@
+use super::Positional;
+
+/// A trait that selects one of a known number of sides, independent of the current face.
+///
+/// A selector picks a position directly, so a selector can weight or bias some positions over
+/// others (i.e. a loaded die), and the result does not depend on the previous face. Rollers
+/// implement [`Roll`](super::Roll) on top of a selector, by [picking](SideSelector::pick) a
+/// position.
+///
+/// # Examples
+///
+/// ```
+/// use tomb::items::D6;
+/// use tomb::traits::SideSelector;
+///
+/// /// A loaded selector, which always selects the highest face.
+/// struct Loaded;
+///
+/// impl SideSelector for Loaded {
+///     fn select(&self, sides: usize) -> usize {
+///         sides - 1
+///     }
+/// }
+///
+/// let mut d6 = D6::new();
+/// Loaded.pick_mut(&mut d6);
+///
+/// assert_eq!(d6.value(), 6);
+/// ```
+pub trait SideSelector {
+    /// Returns a position between `0..sides`, where how the position is selected is defined elsewhere.
+    fn select(&self, sides: usize) -> usize;
+
+    /// Returns a copy of the element, showing the face at a selected position.
+    #[must_use]
+    fn pick<T>(&self, element: &T) -> T
+    where
+        T: Positional + Clone,
+    {
+        let mut element = element.clone();
+        self.pick_mut(&mut element);
+        element
+    }
+
+    /// Changes the element to show the face at a selected position.
+    fn pick_mut<T>(&self, element: &mut T)
+    where
+        T: Positional,
+    {
+        element.set_position(self.select(T::sides()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::Polyhedral;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct FakeDie(usize);
+
+    impl Polyhedral for FakeDie {
+        type Face = usize;
+
+        fn sides() -> usize {
+            4
+        }
+
+        fn value(&self) -> usize {
+            self.0 + 1
+        }
+    }
+
+    impl Positional for FakeDie {
+        fn position(&self) -> usize {
+            self.0
+        }
+
+        fn set_position(&mut self, position: usize) {
+            self.0 = position;
+        }
+    }
+
+    /// Selects the second to last side.
+    struct FakeSelector;
+
+    impl SideSelector for FakeSelector {
+        fn select(&self, sides: usize) -> usize {
+            sides - 2
+        }
+    }
+
+    #[test]
+    fn side_selector_pick() {
+        let die = FakeDie(0);
+
+        assert_eq!(FakeSelector.pick(&die), FakeDie(2));
+        assert_eq!(die, FakeDie(0));
+    }
+
+    #[test]
+    fn side_selector_pick_mut() {
+        let mut die = FakeDie(0);
+        FakeSelector.pick_mut(&mut die);
+
+        assert_eq!(die.value(), 3);
+    }
+}
@@ -0,0 +1,194 @@
+/// A trait for elements that can capture their current faces, and later restore them.
+///
+/// A snapshot (or _memento_) is a lightweight copy of only what can change by rolling, i.e. the
+/// position of a die, or the position of every die in a pool, so game engines can implement save
+/// points (or speculative search) without cloning entire structures.
+///
+/// Every die is a snapshot of its position, and of any other state that changes as it is used,
+/// i.e. the direction of a [`crate::items::Bounded`] die, or the stickers of a
+/// [`crate::items::StickerDie`]. Slices, arrays, and vectors of dice are snapshots of every die,
+/// in order, and a _tray_ of different kinds of dice (or pools) is a tuple, which is a snapshot of
+/// every element of the tuple.
+///
+/// # Examples
+///
+/// ```
+/// use fastrand::Rng;
+/// use tomb::items::{RngRoller, D20, D6};
+/// use tomb::traits::{RollMut, Snapshot};
+///
+/// let roller = RngRoller::from(Rng::with_seed(7194422452970863838));
+/// let mut pool = [D6::new(); 3];
+/// let saved = pool.snapshot();
+///
+/// for die in &mut pool {
+///     roller.roll_mut(die);
+/// }
+/// assert_eq!(pool.map(|d| d.value()), [3, 4, 2]);
+///
+/// pool.restore(&saved);
+/// assert_eq!(pool.map(|d| d.value()), [1, 1, 1]);
+///
+/// // A tray of a D20 and a pool of D6s.
+/// let mut tray = (D20::from(17), vec![D6::from(2), D6::from(5)]);
+/// let saved = tray.snapshot();
+///
+/// roller.roll_mut(&mut tray.0);
+/// tray.1.clear();
+/// tray.1.extend([D6::new(); 2]);
+///
+/// tray.restore(&saved);
+/// assert_eq!(tray, (D20::from(17), vec![D6::from(2), D6::from(5)]));
+/// ```
+pub trait Snapshot {
+    /// The captured state, which is independent of the element.
+    type Memento: Clone;
+
+    /// Returns a snapshot of the current faces.
+    #[must_use]
+    fn snapshot(&self) -> Self::Memento;
+
+    /// Changes the element to show the faces in the snapshot.
+    ///
+    /// # Panics
+    ///
+    /// If the snapshot was taken from an element with a different shape, i.e. fewer dice.
+    fn restore(&mut self, memento: &Self::Memento);
+}
+
+impl<T> Snapshot for [T]
+where
+    T: Snapshot,
+{
+    type Memento = Vec<T::Memento>;
+
+    fn snapshot(&self) -> Vec<T::Memento> {
+        self.iter().map(Snapshot::snapshot).collect()
+    }
+
+    fn restore(&mut self, memento: &Vec<T::Memento>) {
+        assert_eq!(self.len(), memento.len(), "snapshot has a different length");
+        for (element, memento) in self.iter_mut().zip(memento) {
+            element.restore(memento);
+        }
+    }
+}
+
+impl<T, const N: usize> Snapshot for [T; N]
+where
+    T: Snapshot,
+{
+    type Memento = [T::Memento; N];
+
+    fn snapshot(&self) -> [T::Memento; N] {
+        std::array::from_fn(|i| self[i].snapshot())
+    }
+
+    fn restore(&mut self, memento: &[T::Memento; N]) {
+        for (element, memento) in self.iter_mut().zip(memento) {
+            element.restore(memento);
+        }
+    }
+}
+
+impl<T> Snapshot for Vec<T>
+where
+    T: Snapshot,
+{
+    type Memento = Vec<T::Memento>;
+
+    fn snapshot(&self) -> Vec<T::Memento> {
+        self.as_slice().snapshot()
+    }
+
+    fn restore(&mut self, memento: &Vec<T::Memento>) {
+        self.as_mut_slice().restore(memento);
+    }
+}
+
+macro_rules! snapshot_tuple {
+    ($(($($name:ident $index:tt),+)),*) => {
+        $(
+            impl<$($name),+> Snapshot for ($($name,)+)
+            where
+                $($name: Snapshot,)+
+            {
+                type Memento = ($($name::Memento,)+);
+
+                fn snapshot(&self) -> Self::Memento {
+                    ($(self.$index.snapshot(),)+)
+                }
+
+                fn restore(&mut self, memento: &Self::Memento) {
+                    $(self.$index.restore(&memento.$index);)+
+                }
+            }
+        )*
+    };
+}
+
+// A tray rarely has more than a few kinds of dice, i.e. a D20, a pool of D6s, and a D100.
+snapshot_tuple!(
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{Bounded, RotationMode, D20, D4, D6};
+    use crate::traits::{Positional, RotateMut};
+
+    #[test]
+    fn snapshot_die() {
+        let mut die = D20::from(17);
+        let saved = die.snapshot();
+        die.set_position(0);
+        die.restore(&saved);
+
+        assert_eq!(saved, 16);
+        assert_eq!(die.value(), 17);
+    }
+
+    #[test]
+    fn snapshot_vec() {
+        let mut pool = vec![D6::from(2), D6::from(5)];
+        let saved = pool.snapshot();
+        pool[0].set_position(5);
+        pool.restore(&saved);
+
+        assert_eq!(pool, vec![D6::from(2), D6::from(5)]);
+    }
+
+    #[test]
+    fn snapshot_tray() {
+        let mut tray = (
+            D20::from(17),
+            [D6::from(3); 2],
+            Bounded::new(D4::from(4), RotationMode::Bounce),
+        );
+        tray.2.rotate_mut(1);
+        let saved = tray.snapshot();
+
+        tray.0.set_position(0);
+        tray.1[1].set_position(0);
+        tray.2.rotate_mut(2);
+        tray.restore(&saved);
+
+        assert_eq!(tray.0, D20::from(17));
+        assert_eq!(tray.1, [D6::from(3); 2]);
+        assert_eq!(tray.2.die(), &D4::from(3));
+        assert!(tray.2.is_reversed());
+    }
+
+    #[test]
+    #[should_panic(expected = "snapshot has a different length")]
+    fn snapshot_vec_different_length() {
+        let mut pool = vec![D6::new(); 2];
+        pool.restore(&vec![0]);
+    }
+}